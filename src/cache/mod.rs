@@ -8,10 +8,18 @@ use tokio::fs;
 use crate::config::CacheConfig;
 use crate::i18n::TargetLanguage;
 use crate::llm::client::types::TokenUsage;
+use crate::llm::pricing::PricingTable;
 
 pub mod performance_monitor;
 pub use performance_monitor::{CachePerformanceMonitor, CachePerformanceReport};
 
+/// MD5 hash of a prompt, the key of cache entries and recorded LLM responses
+pub fn prompt_hash(prompt: &str) -> String {
+    let mut hasher = Md5::new();
+    hasher.update(prompt.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
 /// Cache manager
 pub struct CacheManager {
     config: CacheConfig,
@@ -29,21 +37,22 @@ pub struct CacheEntry<T> {
     pub token_usage: Option<TokenUsage>,
     /// Model name used (optional)
     pub model_name: Option<String>,
+    /// Provider that answered (optional)
+    #[serde(default)]
+    pub provider_name: Option<String>,
 }
 
 impl CacheManager {
-    pub fn new(config: CacheConfig, target_language: TargetLanguage) -> Self {
+    pub fn new(config: CacheConfig, target_language: TargetLanguage, pricing: PricingTable) -> Self {
         Self {
             config,
-            performance_monitor: CachePerformanceMonitor::new(target_language),
+            performance_monitor: CachePerformanceMonitor::new(target_language, pricing),
         }
     }
 
     /// Generate MD5 hash of the prompt
     pub fn hash_prompt(&self, prompt: &str) -> String {
-        let mut hasher = Md5::new();
-        hasher.update(prompt.as_bytes());
-        format!("{:x}", hasher.finalize())
+        prompt_hash(prompt)
     }
 
     /// Get cache file path
@@ -101,7 +110,8 @@ impl CacheManager {
                                 category,
                                 estimated_inference_time,
                                 token_usage.clone(),
-                                "",
+                                entry.provider_name.as_deref().unwrap_or_default(),
+                                entry.model_name.as_deref().unwrap_or_default(),
                             );
                         }
                         Ok(Some(entry.data))
@@ -121,13 +131,15 @@ impl CacheManager {
         }
     }
 
-    /// Set cache (with token usage information)
+    /// Set cache (with the token usage reported by the provider, and the provider and model that answered)
     pub async fn set_with_tokens<T>(
         &self,
         category: &str,
         prompt: &str,
         data: T,
         token_usage: TokenUsage,
+        model_name: &str,
+        provider_name: &str,
     ) -> Result<()>
     where
         T: Serialize,
@@ -154,7 +166,8 @@ impl CacheManager {
             timestamp,
             prompt_hash: hash,
             token_usage: Some(token_usage),
-            model_name: None,
+            model_name: Some(model_name.to_string()),
+            provider_name: Some(provider_name.to_string()),
         };
 
         match serde_json::to_string_pretty(&entry) {
@@ -220,6 +233,7 @@ impl CacheManager {
             prompt_hash: hash,
             token_usage: None,
             model_name: None,
+            provider_name: None,
         };
 
         match serde_json::to_string_pretty(&entry) {
@@ -6,12 +6,16 @@ use std::time::Duration;
 
 use crate::i18n::TargetLanguage;
 use crate::llm::client::types::TokenUsage;
+use crate::llm::pricing::PricingTable;
+
+const NANO_DOLLARS_PER_DOLLAR: f64 = 1_000_000_000.0;
 
 /// Cache performance monitor
 #[derive(Clone)]
 pub struct CachePerformanceMonitor {
     metrics: Arc<CacheMetrics>,
     target_language: TargetLanguage,
+    pricing: PricingTable,
 }
 
 /// Cache metrics
@@ -27,8 +31,8 @@ pub struct CacheMetrics {
     pub cache_errors: AtomicUsize,
     /// Total inference time saved (seconds)
     pub total_inference_time_saved: AtomicU64,
-    /// Total cost saved (estimated)
-    pub total_cost_saved: AtomicUsize,
+    /// Total cost saved (estimated, nano-dollars, a cache hit saving fractions of a cent)
+    pub total_cost_saved: AtomicU64,
     /// Total input tokens saved
     pub total_input_tokens_saved: AtomicUsize,
     /// Total output tokens saved
@@ -75,10 +79,11 @@ pub struct CategoryPerformanceStats {
 }
 
 impl CachePerformanceMonitor {
-    pub fn new(target_language: TargetLanguage) -> Self {
+    pub fn new(target_language: TargetLanguage, pricing: PricingTable) -> Self {
         Self {
             metrics: Arc::new(CacheMetrics::default()),
             target_language,
+            pricing,
         }
     }
 
@@ -88,6 +93,7 @@ impl CachePerformanceMonitor {
         category: &str,
         inference_time_saved: Duration,
         token_usage: TokenUsage,
+        provider_name: &str,
         model_name: &str,
     ) {
         self.metrics.cache_hits.fetch_add(1, Ordering::Relaxed);
@@ -103,10 +109,13 @@ impl CachePerformanceMonitor {
             .total_output_tokens_saved
             .fetch_add(token_usage.output_tokens, Ordering::Relaxed);
 
-        // Calculate saved cost based on actual token usage
-        let estimated_cost_saved = token_usage.estimate_cost(model_name);
+        // Price the recorded token usage with the provider and model that produced it, unpriced models saving nothing
+        let estimated_cost_saved = self
+            .pricing
+            .cost(provider_name, model_name, &token_usage)
+            .unwrap_or_default();
         self.metrics.total_cost_saved.fetch_add(
-            (estimated_cost_saved * 1000.0) as usize, // Store as milli-dollars
+            (estimated_cost_saved * NANO_DOLLARS_PER_DOLLAR).round() as u64,
             Ordering::Relaxed,
         );
 
@@ -166,7 +175,8 @@ impl CachePerformanceMonitor {
             .total_inference_time_saved
             .load(Ordering::Relaxed) as f64
             / 1000.0; // Convert to seconds
-        let cost_saved = self.metrics.total_cost_saved.load(Ordering::Relaxed) as f64 / 1000.0; // Convert to dollars
+        let cost_saved =
+            self.metrics.total_cost_saved.load(Ordering::Relaxed) as f64 / NANO_DOLLARS_PER_DOLLAR;
 
         let input_tokens_saved = self
             .metrics
@@ -202,6 +212,33 @@ impl CachePerformanceMonitor {
 
 impl Default for CachePerformanceMonitor {
     fn default() -> Self {
-        Self::new(TargetLanguage::default())
+        Self::new(
+            TargetLanguage::default(),
+            PricingTable::new(&crate::config::LLMConfig::default()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_cent_savings_accumulate() {
+        let monitor = CachePerformanceMonitor::default();
+        // 1200 input + 300 output tokens of gpt-4o-mini: $0.00036 per hit
+        for _ in 0..10 {
+            monitor.record_cache_hit(
+                "ai_code_insight",
+                Duration::from_millis(1500),
+                TokenUsage::new(1200, 300),
+                "openai",
+                "gpt-4o-mini",
+            );
+        }
+
+        let report = monitor.generate_report();
+        assert!((report.cost_saved - 0.0036).abs() < 1e-9, "{}", report.cost_saved);
+        assert_eq!(report.input_tokens_saved, 12000);
     }
 }
@@ -72,7 +72,19 @@ pub struct Args {
     #[arg(long)]
     pub max_parallels: Option<usize>,
 
-    /// LLM Provider (openai, mistral, openrouter, anthropic, deepseek)
+    /// Requests per minute allowed by the model service
+    #[arg(long)]
+    pub llm_requests_per_minute: Option<u32>,
+
+    /// Tokens per minute allowed by the model service
+    #[arg(long)]
+    pub llm_tokens_per_minute: Option<u32>,
+
+    /// Maximum number of LLM requests in flight across all agents
+    #[arg(long)]
+    pub llm_max_concurrent_requests: Option<usize>,
+
+    /// LLM Provider (openai, mistral, openrouter, anthropic, deepseek, gemini, ollama, openai-compatible, azure-openai, replay)
     #[arg(long)]
     pub llm_provider: Option<String>,
 
@@ -91,6 +103,38 @@ pub struct Args {
     /// Force regeneration (clear cache)
     #[arg(long)]
     pub force_regenerate: bool,
+
+    /// Exit with a non-zero status when import cycles or layer violations are found
+    #[arg(long)]
+    pub fail_on_architecture_violations: bool,
+
+    /// Do not use git history (commit frequency, churn, authors) when scoring file importance
+    #[arg(long)]
+    pub no_git_history: bool,
+
+    /// Map test files to the code they exercise and document test presence per module
+    #[arg(long)]
+    pub analyze_tests: bool,
+
+    /// Build a vector index of code chunks and retrieve the most relevant ones for each agent
+    #[arg(long)]
+    pub enable_retrieval: bool,
+
+    /// Embedding model of the configured provider used for retrieval (local hashing embeddings when unset)
+    #[arg(long)]
+    pub embedding_model: Option<String>,
+
+    /// Architecture meta description file (YAML, TOML or JSON) declaring system name, containers, domains and layers
+    #[arg(long)]
+    pub architecture_meta: Option<PathBuf>,
+
+    /// Directory of recorded LLM responses served by the replay provider
+    #[arg(long)]
+    pub llm_fixtures_dir: Option<PathBuf>,
+
+    /// Record responses of this live provider into the fixtures directory (use with --llm-provider replay)
+    #[arg(long)]
+    pub record_llm_fixtures: Option<String>,
 }
 
 impl Args {
@@ -169,6 +213,15 @@ impl Args {
             config.llm.max_parallels = max_parallels;
         }
         config.llm.disable_preset_tools = self.disable_preset_tools;
+        if let Some(requests_per_minute) = self.llm_requests_per_minute {
+            config.llm.rate_limit.requests_per_minute = Some(requests_per_minute);
+        }
+        if let Some(tokens_per_minute) = self.llm_tokens_per_minute {
+            config.llm.rate_limit.tokens_per_minute = Some(tokens_per_minute);
+        }
+        if let Some(max_concurrent_requests) = self.llm_max_concurrent_requests {
+            config.llm.rate_limit.max_concurrent_requests = Some(max_concurrent_requests);
+        }
 
         // Target language configuration
         if let Some(target_language_str) = self.target_language {
@@ -185,6 +238,38 @@ impl Args {
             config.cache.enabled = false;
         }
 
+        if self.fail_on_architecture_violations {
+            config.architecture_rules.fail_on_violation = true;
+        }
+
+        if self.no_git_history {
+            config.git_history.enabled = false;
+        }
+
+        if self.analyze_tests {
+            config.analyze_tests = true;
+        }
+
+        if self.enable_retrieval {
+            config.retrieval.enabled = true;
+        }
+        if let Some(embedding_model) = self.embedding_model {
+            config.retrieval.embedding_model = Some(embedding_model);
+        }
+        if let Some(architecture_meta) = self.architecture_meta {
+            config.architecture_meta_path = Some(architecture_meta);
+        }
+
+        if let Some(fixtures_dir) = self.llm_fixtures_dir {
+            config.llm.replay.fixtures_dir = fixtures_dir;
+        }
+        if let Some(provider_str) = self.record_llm_fixtures {
+            match provider_str.parse::<LLMProvider>() {
+                Ok(provider) => config.llm.replay.record_with = Some(provider),
+                Err(e) => eprintln!("{}", e),
+            }
+        }
+
         config
     }
 }
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
@@ -25,6 +26,15 @@ pub enum LLMProvider {
     Gemini,
     #[serde(rename = "ollama")]
     Ollama,
+    /// Gateway or local server speaking the OpenAI API, see `OpenAICompatibleConfig`
+    #[serde(rename = "openai-compatible")]
+    OpenAICompatible,
+    /// Azure OpenAI deployments, see `AzureOpenAIConfig`
+    #[serde(rename = "azure-openai")]
+    AzureOpenAI,
+    /// Offline provider serving recorded responses, see `ReplayConfig`
+    #[serde(rename = "replay")]
+    Replay,
 }
 
 impl Default for LLMProvider {
@@ -44,6 +54,9 @@ impl std::fmt::Display for LLMProvider {
             LLMProvider::Anthropic => write!(f, "anthropic"),
             LLMProvider::Gemini => write!(f, "gemini"),
             LLMProvider::Ollama => write!(f, "ollama"),
+            LLMProvider::OpenAICompatible => write!(f, "openai-compatible"),
+            LLMProvider::AzureOpenAI => write!(f, "azure-openai"),
+            LLMProvider::Replay => write!(f, "replay"),
         }
     }
 }
@@ -61,11 +74,59 @@ impl std::str::FromStr for LLMProvider {
             "anthropic" => Ok(LLMProvider::Anthropic),
             "gemini" => Ok(LLMProvider::Gemini),
             "ollama" => Ok(LLMProvider::Ollama),
+            "openai-compatible" | "openai_compatible" => Ok(LLMProvider::OpenAICompatible),
+            "azure-openai" | "azure_openai" | "azure" => Ok(LLMProvider::AzureOpenAI),
+            "replay" => Ok(LLMProvider::Replay),
             _ => Err(format!("Unknown provider: {}", s)),
         }
     }
 }
 
+impl LLMProvider {
+    /// Public endpoint of the provider, none for the providers hosted by the user
+    pub fn default_api_base_url(&self) -> Option<&'static str> {
+        match self {
+            LLMProvider::OpenAI => Some("https://api.openai.com/v1"),
+            LLMProvider::Moonshot => Some("https://api.moonshot.cn/v1"),
+            LLMProvider::DeepSeek => Some("https://api.deepseek.com"),
+            LLMProvider::Mistral => Some("https://api.mistral.ai"),
+            LLMProvider::OpenRouter => Some("https://openrouter.ai/api/v1"),
+            LLMProvider::Anthropic => Some("https://api.anthropic.com"),
+            LLMProvider::Gemini => Some("https://generativelanguage.googleapis.com"),
+            LLMProvider::Ollama => Some("http://localhost:11434"),
+            LLMProvider::OpenAICompatible | LLMProvider::AzureOpenAI | LLMProvider::Replay => None,
+        }
+    }
+
+    /// Environment variable holding the API key of the provider, for the providers requiring one
+    pub fn api_key_env(&self) -> Option<&'static str> {
+        match self {
+            LLMProvider::OpenAI => Some("OPENAI_API_KEY"),
+            LLMProvider::Moonshot => Some("MOONSHOT_API_KEY"),
+            LLMProvider::DeepSeek => Some("DEEPSEEK_API_KEY"),
+            LLMProvider::Mistral => Some("MISTRAL_API_KEY"),
+            LLMProvider::OpenRouter => Some("OPENROUTER_API_KEY"),
+            LLMProvider::Anthropic => Some("ANTHROPIC_API_KEY"),
+            LLMProvider::Gemini => Some("GEMINI_API_KEY"),
+            LLMProvider::AzureOpenAI => Some("AZURE_OPENAI_API_KEY"),
+            LLMProvider::Ollama | LLMProvider::OpenAICompatible | LLMProvider::Replay => None,
+        }
+    }
+
+    /// Whether requests go to `api_base_url`, the other providers using their public endpoint
+    pub fn uses_api_base_url(&self) -> bool {
+        matches!(
+            self,
+            LLMProvider::OpenAI
+                | LLMProvider::Moonshot
+                | LLMProvider::DeepSeek
+                | LLMProvider::Ollama
+                | LLMProvider::OpenAICompatible
+                | LLMProvider::AzureOpenAI
+        )
+    }
+}
+
 /// Application configuration
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Config {
@@ -87,13 +148,13 @@ pub struct Config {
     /// Whether to analyze dependencies
     pub analyze_dependencies: bool,
 
-    /// Whether to identify core components
+    /// Whether to identify core components by ranking files with import graph centrality
     pub identify_components: bool,
 
     /// Maximum recursion depth
     pub max_depth: u8,
 
-    /// Core component percentage
+    /// Core component percentage, share of candidate files selected as core files for LLM analysis
     pub core_component_percentage: f64,
 
     /// Maximum file size limit (bytes)
@@ -102,6 +163,14 @@ pub struct Config {
     /// Whether to include test files
     pub include_tests: bool,
 
+    /// Whether to map test files to the code they exercise and document test presence per module
+    #[serde(default)]
+    pub analyze_tests: bool,
+
+    /// Number of analyzed core files above which code insights are rolled up into per-directory and per-package summaries
+    #[serde(default = "default_hierarchical_summary_threshold")]
+    pub hierarchical_summary_threshold: usize,
+
     /// Whether to include hidden files
     pub include_hidden: bool,
 
@@ -123,8 +192,21 @@ pub struct Config {
     /// Cache configuration
     pub cache: CacheConfig,
 
-    /// Architecture meta description file path
+    /// Architecture meta description file path (YAML, TOML or JSON), relative paths resolved against the project path first
+    #[serde(default)]
     pub architecture_meta_path: Option<PathBuf>,
+
+    /// Architecture rules (import cycles and layering constraints)
+    #[serde(default)]
+    pub architecture_rules: ArchitectureRulesConfig,
+
+    /// Git history signal for file importance scoring
+    #[serde(default)]
+    pub git_history: GitHistoryConfig,
+
+    /// Embedding-based retrieval of relevant code for agent prompts
+    #[serde(default)]
+    pub retrieval: RetrievalConfig,
 }
 
 /// LLM model configuration
@@ -164,6 +246,246 @@ pub struct LLMConfig {
     pub disable_preset_tools: bool,
 
     pub max_parallels: usize,
+
+    /// Headers, authentication and API flavour of the `openai-compatible` provider
+    #[serde(default)]
+    pub openai_compatible: OpenAICompatibleConfig,
+
+    /// API version and deployment names of the `azure-openai` provider
+    #[serde(default)]
+    pub azure_openai: AzureOpenAIConfig,
+
+    /// Recorded responses used by the `replay` provider
+    #[serde(default)]
+    pub replay: ReplayConfig,
+
+    /// Model name to price, overriding and extending the built-in pricing table
+    #[serde(default)]
+    pub pricing: BTreeMap<String, ModelPricing>,
+
+    /// Request, token and concurrency budgets shared by all LLM calls
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
+    /// Agent key (`code_analysis`, `architecture`, `overview_editor`, `compression`...) to the model and parameters
+    /// of its calls, the other agents choosing between the efficient and powerful models by prompt size
+    #[serde(default)]
+    pub routes: BTreeMap<String, ModelRoute>,
+
+    /// Providers and models tried in order when the calls to this provider keep failing
+    #[serde(default)]
+    pub fallbacks: Vec<FallbackEntry>,
+
+    /// How structured data is extracted from the model replies
+    #[serde(default)]
+    pub extraction: ExtractionMode,
+}
+
+/// Structured data extraction method
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtractionMode {
+    /// Tool calling, JSON replies for Ollama
+    #[default]
+    Auto,
+    /// The provider's tool calling, the model submitting the data as tool arguments
+    Tool,
+    /// Output schema in the prompt, the JSON reply being repaired, validated and re-prompted with the validation errors
+    Json,
+}
+
+impl ExtractionMode {
+    /// Whether to extract from JSON replies, `reliable_tool_calling` being false for providers whose models often
+    /// lack tool calling
+    pub fn uses_json_replies(self, reliable_tool_calling: bool) -> bool {
+        match self {
+            ExtractionMode::Auto => !reliable_tool_calling,
+            ExtractionMode::Tool => false,
+            ExtractionMode::Json => true,
+        }
+    }
+}
+
+/// Provider and model of the fallback chain
+///
+/// ```toml
+/// [[llm.fallbacks]]
+/// provider = "deepseek"
+/// model = "deepseek-chat"
+/// api_key = "sk-..."
+/// api_base_url = "https://api.deepseek.com"
+/// retry_attempts = 2
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct FallbackEntry {
+    pub provider: LLMProvider,
+
+    pub model: String,
+
+    /// API key, the `[llm]` one when unset and the provider is the same, else read from the provider's environment
+    /// variable such as `ANTHROPIC_API_KEY`
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// API base URL, the `[llm]` one when unset and the provider is the same, else the provider's public endpoint
+    /// (the local server for Ollama)
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+
+    /// Retry attempts on this entry before moving to the next one, the `[llm]` ones when unset
+    #[serde(default)]
+    pub retry_attempts: Option<u32>,
+}
+
+/// Model and parameters of the calls of one agent, unset values keeping the `[llm]` ones
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ModelRoute {
+    pub model: Option<String>,
+
+    pub temperature: Option<f64>,
+
+    pub max_tokens: Option<u32>,
+}
+
+impl LLMConfig {
+    /// Configuration of the fallback entry, the other settings being the `[llm]` ones
+    pub fn for_fallback(&self, entry: &FallbackEntry) -> LLMConfig {
+        let same_provider = entry.provider == self.provider;
+        let mut config = self.clone();
+        config.provider = entry.provider.clone();
+        config.model_efficient = entry.model.clone();
+        config.model_powerful = entry.model.clone();
+        config.api_key = match &entry.api_key {
+            Some(api_key) => api_key.clone(),
+            None if same_provider => self.api_key.clone(),
+            None => entry
+                .provider
+                .api_key_env()
+                .and_then(|name| std::env::var(name).ok())
+                .unwrap_or_default(),
+        };
+        config.api_base_url = match &entry.api_base_url {
+            Some(api_base_url) => api_base_url.clone(),
+            None if same_provider => self.api_base_url.clone(),
+            None => entry.provider.default_api_base_url().unwrap_or_default().to_string(),
+        };
+        if let Some(retry_attempts) = entry.retry_attempts {
+            config.retry_attempts = retry_attempts;
+        }
+        config.fallbacks.clear();
+        config
+    }
+
+    /// Configuration of the calls of the agent, with its route applied
+    pub fn routed(&self, model_route: &str) -> Option<LLMConfig> {
+        let route = self.routes.get(model_route)?;
+        let mut config = self.clone();
+        if let Some(model) = &route.model {
+            config.model_efficient = model.clone();
+            config.model_powerful = model.clone();
+        }
+        if route.temperature.is_some() {
+            config.temperature = route.temperature;
+        }
+        if let Some(max_tokens) = route.max_tokens {
+            config.max_tokens = max_tokens;
+        }
+        Some(config)
+    }
+}
+
+/// Client-side rate limits of the provider, unlimited when unset
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Requests started per minute
+    pub requests_per_minute: Option<u32>,
+
+    /// Input and output tokens per minute, requests being reserved at their estimated prompt size
+    pub tokens_per_minute: Option<u32>,
+
+    /// Requests in flight at the same time, across all agents
+    pub max_concurrent_requests: Option<usize>,
+}
+
+/// Model prices in USD per million tokens
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ModelPricing {
+    /// Input (prompt) tokens
+    pub input: f64,
+
+    /// Output (completion) tokens
+    pub output: f64,
+
+    /// Input tokens served from the provider's prompt cache, billed at the input price when unset
+    #[serde(default)]
+    pub cached_input: Option<f64>,
+}
+
+/// OpenAI API flavour
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub enum OpenAIApi {
+    #[default]
+    #[serde(rename = "chat_completions")]
+    ChatCompletions,
+    #[serde(rename = "responses")]
+    Responses,
+}
+
+/// OpenAI-compatible provider configuration
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct OpenAICompatibleConfig {
+    /// Extra HTTP headers sent with every request
+    pub headers: BTreeMap<String, String>,
+
+    /// Header carrying the API key instead of `Authorization: Bearer <key>`, e.g. `api-key` or `X-API-Key`
+    pub auth_header: Option<String>,
+
+    /// Scheme written before the API key in `auth_header`, e.g. `Token`, the bare key when unset
+    pub auth_scheme: Option<String>,
+
+    /// Path appended to the API base URL, e.g. `/openai/v1`
+    pub path_prefix: String,
+
+    /// Whether to call the chat completions or the responses endpoint
+    pub api: OpenAIApi,
+
+    /// Parameters merged into every request body, e.g. `top_k` or `chat_template_kwargs`
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Azure OpenAI provider configuration, the resource endpoint being `api_base_url`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct AzureOpenAIConfig {
+    /// Value of the `api-version` query parameter
+    pub api_version: String,
+
+    /// Model name (`model_efficient`, `model_powerful`, embedding model) to deployment name, models without an entry are used as deployment names
+    pub deployments: BTreeMap<String, String>,
+}
+
+impl AzureOpenAIConfig {
+    /// Deployment serving the model
+    pub fn deployment<'a>(&'a self, model: &'a str) -> &'a str {
+        self.deployments.get(model).map(String::as_str).unwrap_or(model)
+    }
+}
+
+/// Replay provider configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct ReplayConfig {
+    /// Directory of recorded responses, one JSON file per prompt hash
+    pub fixtures_dir: PathBuf,
+
+    /// Live provider whose responses are recorded into `fixtures_dir` for prompts without a fixture
+    pub record_with: Option<LLMProvider>,
+
+    /// Answer prompts without a fixture with placeholder text and schema-conforming placeholder data instead of failing
+    pub synthesize_missing: bool,
 }
 
 /// Cache configuration
@@ -179,6 +501,81 @@ pub struct CacheConfig {
     pub expire_hours: u64,
 }
 
+/// Architecture rules configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ArchitectureRulesConfig {
+    /// Whether to detect circular imports between project files
+    #[serde(default = "default_true")]
+    pub detect_cycles: bool,
+
+    /// Layer definitions with their forbidden dependencies
+    #[serde(default)]
+    pub layers: Vec<LayerRule>,
+
+    /// Whether to exit with a non-zero status when cycles or layer violations are found
+    #[serde(default)]
+    pub fail_on_violation: bool,
+}
+
+/// Git history scoring configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct GitHistoryConfig {
+    /// Whether to read the local git history to score file importance
+    pub enabled: bool,
+
+    /// Maximum number of most recent commits to analyze
+    pub max_commits: usize,
+
+    /// Window (days before the latest commit) in which changes count as recent churn
+    pub recent_days: u64,
+
+    /// Share of the final importance score taken from the git history signal (0.0 - 1.0)
+    pub weight: f64,
+}
+
+/// Embedding retrieval configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct RetrievalConfig {
+    /// Whether to build a vector index of code chunks and code insights and retrieve from it for agent prompts
+    pub enabled: bool,
+
+    /// Embedding model of the configured provider, the local hashing embedder is used when unset or unsupported
+    pub embedding_model: Option<String>,
+
+    /// Number of lines per code chunk
+    pub chunk_lines: usize,
+
+    /// Maximum number of chunks indexed, core files and insights are indexed first
+    pub max_chunks: usize,
+
+    /// Number of chunks retrieved per query
+    pub top_k: usize,
+}
+
+/// Layer rule, e.g. `domain` must not depend on `infrastructure`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LayerRule {
+    /// Layer name
+    pub name: String,
+
+    /// Glob patterns (relative to project root) of files belonging to this layer
+    pub paths: Vec<String>,
+
+    /// Names of layers this layer must not import from
+    #[serde(default)]
+    pub must_not_depend_on: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_hierarchical_summary_threshold() -> usize {
+    50
+}
+
 impl Config {
     /// Load configuration from file
     pub fn from_file(path: &PathBuf) -> Result<Self> {
@@ -192,6 +589,18 @@ impl Config {
         Ok(config)
     }
 
+    /// Resolved architecture meta description file: absolute paths as is, relative paths
+    /// against the project path when the file exists there, otherwise against the working directory
+    pub fn architecture_meta_file(&self) -> Option<PathBuf> {
+        let path = self.architecture_meta_path.as_ref()?;
+        let in_project = self.project_path.join(path);
+        if path.is_relative() && in_project.exists() {
+            Some(in_project)
+        } else {
+            Some(path.clone())
+        }
+    }
+
     /// Get project name, prioritize configured project_name, otherwise auto-infer
     pub fn get_project_name(&self) -> String {
         // Prioritize configured project name
@@ -460,6 +869,8 @@ impl Default for Config {
             core_component_percentage: 20.0,
             max_file_size: 64 * 1024, // 64KB
             include_tests: false,
+            analyze_tests: false,
+            hierarchical_summary_threshold: default_hierarchical_summary_threshold(),
             include_hidden: false,
             excluded_dirs: vec![
                 ".litho".to_string(),
@@ -513,6 +924,9 @@ impl Default for Config {
             ],
             included_extensions: vec![],
             architecture_meta_path: None,
+            architecture_rules: ArchitectureRulesConfig::default(),
+            git_history: GitHistoryConfig::default(),
+            retrieval: RetrievalConfig::default(),
             llm: LLMConfig::default(),
             cache: CacheConfig::default(),
         }
@@ -534,6 +948,73 @@ impl Default for LLMConfig {
             timeout_seconds: 300,
             disable_preset_tools: false,
             max_parallels: 3,
+            openai_compatible: OpenAICompatibleConfig::default(),
+            azure_openai: AzureOpenAIConfig::default(),
+            replay: ReplayConfig::default(),
+            pricing: BTreeMap::new(),
+            rate_limit: RateLimitConfig::default(),
+            routes: BTreeMap::new(),
+            fallbacks: Vec::new(),
+            extraction: ExtractionMode::default(),
+        }
+    }
+}
+
+impl Default for AzureOpenAIConfig {
+    fn default() -> Self {
+        Self {
+            api_version: String::from("2024-10-21"),
+            deployments: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            fixtures_dir: PathBuf::from("tests/fixtures/llm"),
+            record_with: None,
+            synthesize_missing: false,
+        }
+    }
+}
+
+impl Default for ArchitectureRulesConfig {
+    fn default() -> Self {
+        Self {
+            detect_cycles: true,
+            layers: vec![],
+            fail_on_violation: false,
+        }
+    }
+}
+
+impl ArchitectureRulesConfig {
+    /// Whether the rules need the import graph even when dependency analysis is disabled
+    pub fn requires_import_graph(&self) -> bool {
+        !self.layers.is_empty() || self.fail_on_violation
+    }
+}
+
+impl Default for GitHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_commits: 2000,
+            recent_days: 90,
+            weight: 0.3,
+        }
+    }
+}
+
+impl Default for RetrievalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            embedding_model: None,
+            chunk_lines: 60,
+            max_chunks: 4000,
+            top_k: 8,
         }
     }
 }
@@ -1,24 +1,31 @@
 use anyhow::Result;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+use crate::config::LLMConfig;
 use crate::generator::context::GeneratorContext;
-use crate::llm::client::utils::estimate_token_usage;
+use crate::llm::client::LLMErrorKind;
+use crate::utils::prompt_compressor::{CompressionConfig, PromptCompressor};
+
+/// Cache scope prefix of the compression requests, which are not compressed again
+pub const COMPRESSION_SCOPE_PREFIX: &str = "prompt_compression_";
 
 pub struct AgentExecuteParams {
     pub prompt_sys: String,
     pub prompt_user: String,
     pub cache_scope: String,
     pub log_tag: String,
+    /// Key of the agent in the `[llm.routes]` model routing
+    pub model_route: String,
 }
 
 pub async fn prompt(context: &GeneratorContext, params: AgentExecuteParams) -> Result<String> {
     let prompt_sys = &params.prompt_sys;
-    let prompt_user = &params.prompt_user;
     let cache_scope = &params.cache_scope;
     let log_tag = &params.log_tag;
 
-    let prompt_key = format!("{}|{}|reply-prompt", prompt_sys, prompt_user);
+    let prompt_key = prompt_key(&context.config.llm, &params, "|reply-prompt");
     // Try to get from cache - Use prompt directly as key, CacheManager will automatically calculate hash
     if let Some(cached_reply) = context
         .cache_manager
@@ -35,22 +42,27 @@ pub async fn prompt(context: &GeneratorContext, params: AgentExecuteParams) -> R
     let msg = context.config.target_language.msg_ai_analyzing().replace("{}", log_tag);
     println!("{}", msg);
 
-    let reply = context
-        .llm_client
-        .prompt_without_react(prompt_sys, prompt_user)
-        .await
-        .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let llm_client = &context.llm_client.routed(&params.model_route);
+    let response = shrink_on_context_overflow(context, &params, |prompt_user| async move {
+        llm_client.prompt_without_react(prompt_sys, &prompt_user).await
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let reply = response.output;
 
-    // Estimate token usage
-    let input_text = format!("{} {}", prompt_sys, prompt_user);
-    let token_usage = estimate_token_usage(&input_text, &reply);
+    // Record the token usage reported by the provider
+    let token_usage = response.usage;
+    context
+        .llm_client
+        .usage_tracker()
+        .record(cache_scope, &response.provider, &response.model, &token_usage);
 
     // Cache result - Use method with token information
     context
         .cache_manager
         .write()
         .await
-        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage)
+        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage, &response.model, &response.provider)
         .await?;
 
     Ok(reply)
@@ -61,11 +73,10 @@ pub async fn prompt_with_tools(
     params: AgentExecuteParams,
 ) -> Result<String> {
     let prompt_sys = &params.prompt_sys;
-    let prompt_user = &params.prompt_user;
     let cache_scope = &params.cache_scope;
     let log_tag = &params.log_tag;
 
-    let prompt_key = format!("{}|{}|reply-prompt+tool", prompt_sys, prompt_user);
+    let prompt_key = prompt_key(&context.config.llm, &params, "|reply-prompt+tool");
     // Try to get from cache - Use prompt directly as key, CacheManager will automatically calculate hash
     if let Some(cached_reply) = context
         .cache_manager
@@ -82,23 +93,27 @@ pub async fn prompt_with_tools(
     let msg = context.config.target_language.msg_ai_analyzing().replace("{}", log_tag);
     println!("{}", msg);
 
-    let reply = context
-        .llm_client
-        .prompt(prompt_sys, prompt_user)
-        .await
-        .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let llm_client = &context.llm_client.routed(&params.model_route);
+    let response = shrink_on_context_overflow(context, &params, |prompt_user| async move {
+        llm_client.prompt(prompt_sys, &prompt_user).await
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let reply = response.output;
 
-    // Estimate token usage
-    let input_text = format!("{} {}", prompt_sys, prompt_user);
-    let output_text = serde_json::to_string(&reply).unwrap_or_default();
-    let token_usage = estimate_token_usage(&input_text, &output_text);
+    // Record the token usage reported by the provider
+    let token_usage = response.usage;
+    context
+        .llm_client
+        .usage_tracker()
+        .record(cache_scope, &response.provider, &response.model, &token_usage);
 
     // Cache result - Use method with token information
     context
         .cache_manager
         .write()
         .await
-        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage)
+        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage, &response.model, &response.provider)
         .await?;
 
     Ok(reply)
@@ -109,11 +124,10 @@ where
     T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
 {
     let prompt_sys = &params.prompt_sys;
-    let prompt_user = &params.prompt_user;
     let cache_scope = &params.cache_scope;
     let log_tag = &params.log_tag;
 
-    let prompt_key = format!("{}|{}", prompt_sys, prompt_user);
+    let prompt_key = prompt_key(&context.config.llm, &params, "");
     // Try to get from cache - Use prompt directly as key, CacheManager will automatically calculate hash
     if let Some(cached_reply) = context
         .cache_manager
@@ -130,24 +144,103 @@ where
     let msg = context.config.target_language.msg_ai_analyzing().replace("{}", log_tag);
     println!("{}", msg);
 
-    let reply = context
-        .llm_client
-        .extract::<T>(prompt_sys, prompt_user)
-        .await
-        .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let llm_client = &context.llm_client.routed(&params.model_route);
+    let response = shrink_on_context_overflow(context, &params, |prompt_user| async move {
+        llm_client.extract::<T>(prompt_sys, &prompt_user).await
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let reply = response.output;
 
-    // Estimate token usage
-    let input_text = format!("{} {}", prompt_sys, prompt_user);
-    let output_text = serde_json::to_string(&reply).unwrap_or_default();
-    let token_usage = estimate_token_usage(&input_text, &output_text);
+    // Record the token usage reported by the provider
+    let token_usage = response.usage;
+    context
+        .llm_client
+        .usage_tracker()
+        .record(cache_scope, &response.provider, &response.model, &token_usage);
 
     // Cache result - Use method with token information
     context
         .cache_manager
         .write()
         .await
-        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage)
+        .set_with_tokens(cache_scope, &prompt_key, &reply, token_usage, &response.model, &response.provider)
         .await?;
 
     Ok(reply)
 }
+
+/// Cache key of the prompt. The model and parameters of a route in `[llm.routes]` are part of it, so that changing
+/// the route does not serve the replies of the previous model; unrouted agents keep the plain key
+fn prompt_key(llm_config: &LLMConfig, params: &AgentExecuteParams, reply_kind: &str) -> String {
+    let mut key = format!("{}|{}{}", params.prompt_sys, params.prompt_user, reply_kind);
+    if let Some(route) = llm_config.routed(&params.model_route) {
+        key.push_str(&format!(
+            "|route:{}:{:?}:{}",
+            route.model_efficient, route.temperature, route.max_tokens
+        ));
+    }
+    key
+}
+
+/// Run the call with the user prompt, and once more with the prompt compressed when it overflows the context
+/// window of the model; the reply is cached under the original prompt
+async fn shrink_on_context_overflow<T, F, Fut>(
+    context: &GeneratorContext,
+    params: &AgentExecuteParams,
+    call: F,
+) -> Result<T>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    match call(params.prompt_user.clone()).await {
+        Err(e)
+            if LLMErrorKind::classify(&e) == LLMErrorKind::ContextOverflow
+                && !params.cache_scope.starts_with(COMPRESSION_SCOPE_PREFIX) =>
+        {
+            println!(
+                "   🗜️  Prompt of [{}] exceeds the context window of the model, compressing it and retrying...",
+                params.log_tag
+            );
+            let compressor = PromptCompressor::new(CompressionConfig::default());
+            let prompt_user =
+                Box::pin(compressor.shrink(context, &params.prompt_user, &params.cache_scope)).await?;
+            call(prompt_user).await
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ModelRoute;
+
+    #[test]
+    fn test_prompt_key_includes_the_route() {
+        let params = AgentExecuteParams {
+            prompt_sys: "system".to_string(),
+            prompt_user: "user".to_string(),
+            cache_scope: "scope".to_string(),
+            log_tag: "tag".to_string(),
+            model_route: "architecture".to_string(),
+        };
+        let mut llm_config = LLMConfig::default();
+        assert_eq!(prompt_key(&llm_config, &params, "|reply-prompt"), "system|user|reply-prompt");
+
+        llm_config.routes.insert(
+            "architecture".to_string(),
+            ModelRoute {
+                model: Some("gpt-4o".to_string()),
+                temperature: Some(0.7),
+                max_tokens: None,
+            },
+        );
+        let routed = prompt_key(&llm_config, &params, "|reply-prompt");
+        assert!(routed.ends_with("|route:gpt-4o:Some(0.7):131072"), "{}", routed);
+
+        llm_config.routes.get_mut("architecture").unwrap().temperature = Some(0.2);
+        assert_ne!(prompt_key(&llm_config, &params, "|reply-prompt"), routed);
+    }
+}
@@ -19,6 +19,10 @@ impl StepForwardAgent for ArchitectureEditor {
         MemoryScope::DOCUMENTATION.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "architecture_editor"
+    }
+
     fn should_include_timestamp(&self) -> bool {
         true
     }
@@ -31,7 +35,7 @@ impl StepForwardAgent for ArchitectureEditor {
                 DataSource::ResearchResult(ResearchAgentType::ArchitectureResearcher.to_string()),
                 DataSource::ResearchResult(ResearchAgentType::WorkflowResearcher.to_string()),
             ],
-            optional_sources: vec![],
+            optional_sources: vec![DataSource::WORKSPACE],
         }
     }
 
@@ -98,6 +102,7 @@ Please generate a high-quality C4 architecture document, ensuring:
 - System boundary definition
 
 ## 3. Container View
+- Workspace members as containers (for monorepos)
 - Domain module division
 - Domain module architecture
 - Storage design
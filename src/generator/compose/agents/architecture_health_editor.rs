@@ -0,0 +1,130 @@
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::code_releationship::{ArchitectureHealthReport, DependencyCycle, LayerViolation};
+use anyhow::Result;
+
+/// Architecture Health Documentation Editor - Renders statically detected cycles and layer violations
+#[derive(Default)]
+pub struct ArchitectureHealthEditor;
+
+impl ArchitectureHealthEditor {
+    /// Generates the document directly from the preprocessing report without using LLM
+    pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
+        let Some(report) = context
+            .get_from_memory::<ArchitectureHealthReport>(
+                PreprocessScope::PREPROCESS,
+                ScopedKeys::ARCHITECTURE_HEALTH,
+            )
+            .await
+        else {
+            return Ok(());
+        };
+
+        let content = self.generate_health_documentation(&report);
+
+        let agent_type = AgentType::ArchitectureHealth.to_string();
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &agent_type, &content)
+            .await?;
+        doc_tree.insert(
+            &agent_type,
+            &context
+                .config
+                .target_language
+                .get_doc_filename("architecture_health"),
+        );
+
+        Ok(())
+    }
+
+    fn generate_health_documentation(&self, report: &ArchitectureHealthReport) -> String {
+        let mut content = String::new();
+        content.push_str("# Architecture Health\n\n");
+        content.push_str(
+            "This document lists dependency problems detected statically from the project's import statements: circular dependencies between files and imports that break the configured layer rules.\n\n",
+        );
+
+        content.push_str("## Summary\n\n");
+        content.push_str("| Metric | Value |\n|---|---|\n");
+        content.push_str(&format!("| Analyzed files | {} |\n", report.analyzed_files));
+        content.push_str(&format!("| Resolved internal imports | {} |\n", report.resolved_imports));
+        content.push_str(&format!("| Circular dependencies | {} |\n", report.cycles.len()));
+        content.push_str(&format!("| Layer violations | {} |\n\n", report.layer_violations.len()));
+
+        if !report.has_violations() {
+            content.push_str("No circular dependencies or layer violations were found.\n");
+            return content;
+        }
+
+        content.push_str(&self.generate_cycles_documentation(&report.cycles));
+        content.push_str(&self.generate_violations_documentation(&report.layer_violations));
+
+        content
+    }
+
+    fn generate_cycles_documentation(&self, cycles: &[DependencyCycle]) -> String {
+        if cycles.is_empty() {
+            return String::new();
+        }
+
+        let mut content = String::new();
+        content.push_str("## Circular Dependencies\n\n");
+
+        for (i, cycle) in cycles.iter().enumerate() {
+            content.push_str(&format!("### Cycle {} ({} files)\n\n", i + 1, cycle.members.len()));
+
+            content.push_str("**Files**:\n\n");
+            for member in &cycle.members {
+                content.push_str(&format!("- `{}`\n", member));
+            }
+            content.push('\n');
+
+            content.push_str("**Example Path**:\n\n");
+            content.push_str("```mermaid\ngraph LR\n");
+            for pair in cycle.example_path.windows(2) {
+                content.push_str(&format!(
+                    "    {}[\"{}\"] --> {}[\"{}\"]\n",
+                    Self::node_id(&cycle.members, &pair[0]),
+                    pair[0],
+                    Self::node_id(&cycle.members, &pair[1]),
+                    pair[1]
+                ));
+            }
+            content.push_str("```\n\n");
+        }
+
+        content
+    }
+
+    fn node_id(members: &[String], file: &str) -> String {
+        let index = members.iter().position(|m| m == file).unwrap_or(0);
+        format!("F{}", index)
+    }
+
+    fn generate_violations_documentation(&self, violations: &[LayerViolation]) -> String {
+        if violations.is_empty() {
+            return String::new();
+        }
+
+        let mut content = String::new();
+        content.push_str("## Layer Violations\n\n");
+        content.push_str("| From Layer | To Layer | Import Site | Imported File |\n|---|---|---|---|\n");
+
+        for violation in violations {
+            let location = match violation.line_number {
+                Some(line) => format!("{}:{}", violation.from_file, line),
+                None => violation.from_file.clone(),
+            };
+            content.push_str(&format!(
+                "| {} | {} | `{}` | `{}` |\n",
+                violation.from_layer, violation.to_layer, location, violation.to_file
+            ));
+        }
+        content.push('\n');
+
+        content
+    }
+}
@@ -1,6 +1,8 @@
 use crate::generator::compose::memory::MemoryScope;
 use crate::generator::compose::types::AgentType;
 use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::extractors::route_extractor::ExtractedRoutes;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::generator::research::types::{
     APIBoundary, AgentType as ResearchAgentType, BoundaryAnalysisReport, CLIBoundary,
@@ -28,6 +30,10 @@ impl StepForwardAgent for BoundaryEditor {
         MemoryScope::DOCUMENTATION.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "boundary_editor"
+    }
+
     fn should_include_timestamp(&self) -> bool {
         true
     }
@@ -85,7 +91,23 @@ impl StepForwardAgent for BoundaryEditor {
             .ok_or_else(|| anyhow::anyhow!("BoundaryAnalyzer result not found"))?;
 
         // Parse as BoundaryAnalysisReport
-        let report: BoundaryAnalysisReport = serde_json::from_value(boundary_analysis)?;
+        let mut report: BoundaryAnalysisReport = serde_json::from_value(boundary_analysis)?;
+
+        // Statically extracted CLI definitions take precedence over the LLM's reading of the source
+        if let Some(cli_definitions) = context
+            .get_from_memory::<Vec<CLIBoundary>>(PreprocessScope::PREPROCESS, ScopedKeys::CLI_DEFINITIONS)
+            .await
+        {
+            report.cli_boundaries = Self::merge_cli_definitions(cli_definitions, report.cli_boundaries);
+        }
+
+        // Likewise for statically extracted HTTP routes
+        if let Some(routes) = context
+            .get_from_memory::<ExtractedRoutes>(PreprocessScope::PREPROCESS, ScopedKeys::ROUTES)
+            .await
+        {
+            Self::merge_routes(routes, &mut report);
+        }
 
         // Generate documentation content
         let content = self.generate_boundary_documentation(&report);
@@ -101,6 +123,139 @@ impl StepForwardAgent for BoundaryEditor {
 }
 
 impl BoundaryEditor {
+    /// Keep the exact static definitions, completing missing descriptions and examples from the LLM analysis.
+    /// LLM commands from files covered by static extraction are dropped as duplicates.
+    fn merge_cli_definitions(
+        mut static_definitions: Vec<CLIBoundary>,
+        llm_definitions: Vec<CLIBoundary>,
+    ) -> Vec<CLIBoundary> {
+        let source_file = |cli: &CLIBoundary| {
+            cli.source_location
+                .split(':')
+                .next()
+                .unwrap_or_default()
+                .replace('\\', "/")
+        };
+        let covered_files: Vec<String> = static_definitions.iter().map(source_file).collect();
+
+        let mut remaining = Vec::new();
+        for llm in llm_definitions {
+            let matched = static_definitions
+                .iter_mut()
+                .find(|s| s.command.eq_ignore_ascii_case(llm.command.trim()));
+            let Some(definition) = matched else {
+                if !covered_files.contains(&source_file(&llm)) {
+                    remaining.push(llm);
+                }
+                continue;
+            };
+
+            if definition.description.is_empty() {
+                definition.description = llm.description;
+            }
+            if definition.examples.is_empty() {
+                definition.examples = llm.examples;
+            }
+            for option in definition.options.iter_mut().filter(|o| o.description.is_empty()) {
+                if let Some(llm_option) = llm.options.iter().find(|o| o.name == option.name) {
+                    option.description = llm_option.description.clone();
+                }
+            }
+            for argument in definition.arguments.iter_mut().filter(|a| a.description.is_empty()) {
+                if let Some(llm_argument) = llm.arguments.iter().find(|a| a.name == argument.name) {
+                    argument.description = llm_argument.description.clone();
+                }
+            }
+        }
+
+        static_definitions.extend(remaining);
+        static_definitions
+    }
+
+    /// Keep the exact static routes, completing descriptions and formats from the LLM analysis.
+    /// LLM routes from files covered by static extraction are dropped as duplicates.
+    fn merge_routes(routes: ExtractedRoutes, report: &mut BoundaryAnalysisReport) {
+        let source_file = |location: &str| {
+            location
+                .split(':')
+                .next()
+                .unwrap_or_default()
+                .replace('\\', "/")
+        };
+        let covered_files: Vec<String> = routes
+            .api_boundaries
+            .iter()
+            .map(|api| source_file(&api.source_location))
+            .chain(routes.router_boundaries.iter().map(|router| source_file(&router.source_location)))
+            .collect();
+
+        let mut api_boundaries = routes.api_boundaries;
+        let mut remaining_apis = Vec::new();
+        for llm in std::mem::take(&mut report.api_boundaries) {
+            let matched = api_boundaries.iter_mut().find(|api| {
+                api.method.eq_ignore_ascii_case(llm.method.trim())
+                    && Self::route_key(&api.endpoint) == Self::route_key(&llm.endpoint)
+            });
+            let Some(api) = matched else {
+                if !covered_files.contains(&source_file(&llm.source_location)) {
+                    remaining_apis.push(llm);
+                }
+                continue;
+            };
+
+            if !llm.description.trim().is_empty() {
+                api.description = format!("{} ({})", llm.description.trim(), api.description);
+            }
+            api.request_format = llm.request_format.or(api.request_format.take());
+            api.response_format = llm.response_format;
+            api.authentication = llm.authentication;
+        }
+        api_boundaries.extend(remaining_apis);
+        report.api_boundaries = api_boundaries;
+
+        let mut router_boundaries = routes.router_boundaries;
+        let mut remaining_routers = Vec::new();
+        for llm in std::mem::take(&mut report.router_boundaries) {
+            let matched = router_boundaries
+                .iter_mut()
+                .find(|router| Self::route_key(&router.path) == Self::route_key(&llm.path));
+            let Some(router) = matched else {
+                if !covered_files.contains(&source_file(&llm.source_location)) {
+                    remaining_routers.push(llm);
+                }
+                continue;
+            };
+
+            if !llm.description.trim().is_empty() {
+                router.description = format!("{} ({})", llm.description.trim(), router.description);
+            }
+            for param in router.params.iter_mut().filter(|p| p.description.is_empty()) {
+                if let Some(llm_param) = llm.params.iter().find(|p| p.key == param.key) {
+                    param.description = llm_param.description.clone();
+                }
+            }
+        }
+        router_boundaries.extend(remaining_routers);
+        report.router_boundaries = router_boundaries;
+    }
+
+    /// Route path with parameters of any syntax (`{id}`, `<int:id>`, `:id`) collapsed, for matching
+    fn route_key(path: &str) -> String {
+        let segments: Vec<&str> = path
+            .trim()
+            .trim_matches('/')
+            .split('/')
+            .map(|segment| {
+                if segment.starts_with(':') || segment.starts_with('{') || segment.starts_with('<') {
+                    "*"
+                } else {
+                    segment
+                }
+            })
+            .collect();
+        format!("/{}", segments.join("/")).to_lowercase()
+    }
+
     /// Generate boundary interface documentation
     fn generate_boundary_documentation(&self, report: &BoundaryAnalysisReport) -> String {
         let mut content = String::new();
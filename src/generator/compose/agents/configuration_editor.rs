@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::configuration::{ConfigKey, ConfigurationInventory, EnvVariable, ReadSite};
+use anyhow::Result;
+
+/// Number of read sites listed per environment variable
+const MAX_LISTED_SITES: usize = 3;
+/// Number of keys listed per configuration file
+const MAX_LISTED_FILE_KEYS: usize = 30;
+
+/// Configuration Reference Documentation Editor - Renders the statically extracted configuration surface
+#[derive(Default)]
+pub struct ConfigurationEditor;
+
+impl ConfigurationEditor {
+    /// Generates the document directly from the preprocessing inventory without using LLM
+    pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
+        let Some(inventory) = context
+            .get_from_memory::<ConfigurationInventory>(
+                PreprocessScope::PREPROCESS,
+                ScopedKeys::CONFIGURATION,
+            )
+            .await
+        else {
+            return Ok(());
+        };
+
+        if inventory.is_empty() {
+            return Ok(());
+        }
+
+        let content = self.generate_configuration_documentation(&inventory);
+
+        let agent_type = AgentType::Configuration.to_string();
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &agent_type, &content)
+            .await?;
+        doc_tree.insert(
+            &agent_type,
+            &context.config.target_language.get_doc_filename("configuration"),
+        );
+
+        Ok(())
+    }
+
+    fn generate_configuration_documentation(&self, inventory: &ConfigurationInventory) -> String {
+        let mut content = String::new();
+        content.push_str("# Configuration Reference\n\n");
+        content.push_str(
+            "This document lists the environment variables the project reads, the keys of its typed configuration structures and the configuration files it ships, with their types, defaults and where they are read.\n\n",
+        );
+
+        if !inventory.env_variables.is_empty() {
+            content.push_str(&self.generate_env_table(&inventory.env_variables));
+        }
+        if !inventory.config_keys.is_empty() {
+            content.push_str(&self.generate_config_key_tables(&inventory.config_keys));
+        }
+        if !inventory.config_files.is_empty() {
+            content.push_str("## Configuration Files\n\n");
+            for file in &inventory.config_files {
+                content.push_str(&format!("### `{}` ({})\n\n", file.path, file.format));
+                if file.keys.is_empty() {
+                    content.push_str("No keys found.\n\n");
+                    continue;
+                }
+                let mut keys: Vec<String> = file
+                    .keys
+                    .iter()
+                    .take(MAX_LISTED_FILE_KEYS)
+                    .map(|key| format!("`{}`", key))
+                    .collect();
+                if file.keys.len() > MAX_LISTED_FILE_KEYS {
+                    keys.push(format!("and {} more", file.keys.len() - MAX_LISTED_FILE_KEYS));
+                }
+                content.push_str(&format!("**Keys**: {}\n\n", keys.join(", ")));
+            }
+        }
+
+        content
+    }
+
+    fn generate_env_table(&self, env_variables: &[EnvVariable]) -> String {
+        let mut content = String::new();
+        content.push_str("## Environment Variables\n\n");
+        content.push_str("| Variable | Type | Default | Required | Read At |\n|---|---|---|---|---|\n");
+
+        for variable in env_variables {
+            let read_at = if variable.read_sites.is_empty() {
+                format!("declared in {}", Self::code_list(&variable.declared_in))
+            } else {
+                Self::sites(&variable.read_sites)
+            };
+            content.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                variable.name,
+                variable.value_type,
+                Self::default_cell(variable.default_value.as_deref()),
+                if variable.required { "yes" } else { "no" },
+                read_at
+            ));
+        }
+        content.push('\n');
+
+        content
+    }
+
+    fn generate_config_key_tables(&self, config_keys: &[ConfigKey]) -> String {
+        let mut by_binding: BTreeMap<&str, Vec<&ConfigKey>> = BTreeMap::new();
+        for key in config_keys {
+            by_binding.entry(key.binding.as_str()).or_default().push(key);
+        }
+
+        let mut content = String::new();
+        content.push_str("## Configuration Keys\n\n");
+        for (binding, keys) in by_binding {
+            let with_env = keys.iter().any(|k| k.env_name.is_some());
+            content.push_str(&format!(
+                "### {} (`{}`)\n\n",
+                binding,
+                keys[0].source_location.file
+            ));
+            if with_env {
+                content.push_str("| Key | Type | Default | Env Variable | Line | Description |\n|---|---|---|---|---|---|\n");
+            } else {
+                content.push_str("| Key | Type | Default | Line | Description |\n|---|---|---|---|---|\n");
+            }
+
+            for key in keys {
+                let env_cell = if with_env {
+                    format!(
+                        " {} |",
+                        key.env_name.as_ref().map(|e| format!("`{}`", e)).unwrap_or_else(|| "-".to_string())
+                    )
+                } else {
+                    String::new()
+                };
+                content.push_str(&format!(
+                    "| `{}` | `{}` | {} |{} {} | {} |\n",
+                    key.key,
+                    key.value_type.replace('|', "\\|"),
+                    Self::default_cell(key.default_value.as_deref()),
+                    env_cell,
+                    key.source_location.line_number,
+                    key.description.as_deref().unwrap_or("-")
+                ));
+            }
+            content.push('\n');
+        }
+
+        content
+    }
+
+    fn default_cell(default_value: Option<&str>) -> String {
+        match default_value {
+            Some(value) => format!("`{}`", value.replace('|', "\\|")),
+            None => "-".to_string(),
+        }
+    }
+
+    fn sites(sites: &[ReadSite]) -> String {
+        let mut listed: Vec<String> = sites
+            .iter()
+            .take(MAX_LISTED_SITES)
+            .map(|site| format!("`{}`", site))
+            .collect();
+        if sites.len() > MAX_LISTED_SITES {
+            listed.push(format!("and {} more", sites.len() - MAX_LISTED_SITES));
+        }
+        listed.join(", ")
+    }
+
+    fn code_list(items: &[String]) -> String {
+        items
+            .iter()
+            .map(|item| format!("`{}`", item))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
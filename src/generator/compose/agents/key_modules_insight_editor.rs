@@ -1,11 +1,14 @@
+use crate::generator::compose::agents::test_strategy_editor::TestStrategyEditor;
 use crate::generator::compose::memory::MemoryScope;
 use crate::generator::context::GeneratorContext;
 use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::generator::research::types::{AgentType as ResearchAgentType, KeyModuleReport};
 use crate::generator::step_forward_agent::{
     AgentDataConfig, DataSource, FormatterConfig, LLMCallMode, PromptTemplate, StepForwardAgent,
 };
+use crate::types::test_mapping::TestMapping;
 use crate::utils::threads::do_parallel_with_limit;
 use anyhow::Result;
 
@@ -49,10 +52,31 @@ impl KeyModulesInsightEditor {
             // Use do_parallel_with_limit for concurrency control
             let analysis_results = do_parallel_with_limit(analysis_futures, max_parallels).await;
 
+            let test_mapping = context
+                .get_from_memory::<TestMapping>(PreprocessScope::PREPROCESS, ScopedKeys::TEST_MAPPING)
+                .await;
+            let domain_modules = match test_mapping {
+                Some(_) => TestStrategyEditor::domain_modules(context).await,
+                None => Vec::new(),
+            };
+
             // Process results and update doc_tree
             for (insight_key, domain_name, result) in analysis_results {
                 result?; // Check for errors
 
+                // Append the statically computed test presence of the module
+                if let Some(mapping) = &test_mapping
+                    && let Some(domain) = domain_modules.iter().find(|d| d.name == domain_name)
+                    && let Some(document) = context
+                        .get_from_memory::<String>(MemoryScope::DOCUMENTATION, &insight_key)
+                        .await
+                {
+                    let document = document + &TestStrategyEditor::generate_module_section(mapping, domain);
+                    context
+                        .store_to_memory(MemoryScope::DOCUMENTATION, &insight_key, &document)
+                        .await?;
+                }
+
                 doc_tree.insert(
                     &insight_key,
                     format!("{}/{}.md", context.config.target_language.get_directory_name("deep_exploration"), &domain_name).as_str(),
@@ -89,6 +113,10 @@ impl StepForwardAgent for KeyModuleInsightEditor {
         MemoryScope::DOCUMENTATION.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "key_modules_editor"
+    }
+
     fn should_include_timestamp(&self) -> bool {
         true
     }
@@ -1,5 +1,10 @@
 pub mod architecture_editor;
+pub mod architecture_health_editor;
 pub mod boundary_editor;
+pub mod configuration_editor;
 pub mod key_modules_insight_editor;
 pub mod overview_editor;
+pub mod tech_stack_editor;
+pub mod test_strategy_editor;
 pub mod workflow_editor;
+pub mod workspace_editor;
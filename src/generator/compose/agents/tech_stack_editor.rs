@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::dependency_inventory::{DependencyInventory, Ecosystem, ExternalDependency};
+use anyhow::Result;
+
+/// Number of import sites listed per dependency
+const MAX_LISTED_SITES: usize = 3;
+
+/// Technology Stack Documentation Editor - Renders the manifest-based dependency inventory
+#[derive(Default)]
+pub struct TechStackEditor;
+
+impl TechStackEditor {
+    /// Generates the document directly from the preprocessing inventory without using LLM
+    pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
+        let Some(inventory) = context
+            .get_from_memory::<DependencyInventory>(
+                PreprocessScope::PREPROCESS,
+                ScopedKeys::DEPENDENCY_INVENTORY,
+            )
+            .await
+        else {
+            return Ok(());
+        };
+
+        if inventory.dependencies.is_empty() {
+            return Ok(());
+        }
+
+        let content = self.generate_tech_stack_documentation(&inventory);
+
+        let agent_type = AgentType::TechStack.to_string();
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &agent_type, &content)
+            .await?;
+        doc_tree.insert(
+            &agent_type,
+            &context.config.target_language.get_doc_filename("tech_stack"),
+        );
+
+        Ok(())
+    }
+
+    fn generate_tech_stack_documentation(&self, inventory: &DependencyInventory) -> String {
+        let mut by_ecosystem: BTreeMap<Ecosystem, Vec<&ExternalDependency>> = BTreeMap::new();
+        for dependency in &inventory.dependencies {
+            by_ecosystem.entry(dependency.ecosystem).or_default().push(dependency);
+        }
+
+        let mut content = String::new();
+        content.push_str("# Technology Stack & Dependencies\n\n");
+        content.push_str(
+            "This document lists the third-party packages declared in the project's package manifests, with their versions and where they are imported in the source code.\n\n",
+        );
+
+        content.push_str("## Summary\n\n");
+        content.push_str("| Ecosystem | Runtime | Development | Imported in Source |\n|---|---|---|---|\n");
+        for (ecosystem, dependencies) in &by_ecosystem {
+            let dev_count = dependencies.iter().filter(|d| d.is_dev).count();
+            let imported_count = dependencies.iter().filter(|d| !d.import_sites.is_empty()).count();
+            content.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                ecosystem,
+                dependencies.len() - dev_count,
+                dev_count,
+                imported_count
+            ));
+        }
+        content.push('\n');
+
+        content.push_str("**Manifests**: ");
+        content.push_str(
+            &inventory
+                .manifests
+                .iter()
+                .map(|m| format!("`{}`", m))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        content.push_str("\n\n");
+
+        for (ecosystem, dependencies) in &by_ecosystem {
+            content.push_str(&format!("## {}\n\n", ecosystem));
+
+            let (dev, runtime): (Vec<&ExternalDependency>, Vec<&ExternalDependency>) =
+                dependencies.iter().partition(|d| d.is_dev);
+
+            if !runtime.is_empty() {
+                content.push_str("### Runtime Dependencies\n\n");
+                content.push_str(&self.generate_dependency_table(&runtime));
+            }
+            if !dev.is_empty() {
+                content.push_str("### Development Dependencies\n\n");
+                content.push_str(&self.generate_dependency_table(&dev));
+            }
+        }
+
+        content
+    }
+
+    fn generate_dependency_table(&self, dependencies: &[&ExternalDependency]) -> String {
+        let mut content = String::new();
+        content.push_str("| Package | Declared Version | Locked Version | Used In |\n|---|---|---|---|\n");
+
+        for dependency in dependencies {
+            let used_in = if dependency.import_sites.is_empty() {
+                "-".to_string()
+            } else {
+                let mut sites: Vec<String> = dependency
+                    .import_sites
+                    .iter()
+                    .take(MAX_LISTED_SITES)
+                    .map(|site| format!("`{}:{}`", site.file, site.line_number))
+                    .collect();
+                if dependency.import_sites.len() > MAX_LISTED_SITES {
+                    sites.push(format!(
+                        "and {} more",
+                        dependency.import_sites.len() - MAX_LISTED_SITES
+                    ));
+                }
+                sites.join(", ")
+            };
+
+            content.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                dependency.name,
+                dependency.version.as_deref().unwrap_or("-"),
+                dependency.resolved_version.as_deref().unwrap_or("-"),
+                used_in
+            ));
+        }
+        content.push('\n');
+
+        content
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::generator::research::memory::MemoryRetriever;
+use crate::generator::research::types::{AgentType as ResearchAgentType, DomainModule, DomainModulesReport};
+use crate::types::test_mapping::{TestCoverage, TestMapping};
+use anyhow::Result;
+
+/// Number of untested files listed per module
+const MAX_LISTED_UNTESTED: usize = 10;
+/// Number of test files listed per module
+const MAX_LISTED_TESTS: usize = 10;
+
+/// Test Strategy Documentation Editor - Renders the test-to-code mapping as per-module test presence
+#[derive(Default)]
+pub struct TestStrategyEditor;
+
+impl TestStrategyEditor {
+    /// Generates the document directly from the test mapping without using LLM
+    pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
+        let Some(mapping) = context
+            .get_from_memory::<TestMapping>(PreprocessScope::PREPROCESS, ScopedKeys::TEST_MAPPING)
+            .await
+        else {
+            return Ok(());
+        };
+
+        let domain_modules = Self::domain_modules(context).await;
+        let content = self.generate_test_strategy_documentation(&mapping, &domain_modules);
+
+        let agent_type = AgentType::TestStrategy.to_string();
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &agent_type, &content)
+            .await?;
+        doc_tree.insert(
+            &agent_type,
+            &context.config.target_language.get_doc_filename("test_strategy"),
+        );
+
+        Ok(())
+    }
+
+    /// Domain modules identified during research, empty when the research result is unavailable
+    pub async fn domain_modules(context: &GeneratorContext) -> Vec<DomainModule> {
+        context
+            .get_research(&ResearchAgentType::DomainModulesDetector.to_string())
+            .await
+            .and_then(|value| serde_json::from_value::<DomainModulesReport>(value).ok())
+            .map(|report| report.domain_modules)
+            .unwrap_or_default()
+    }
+
+    /// Code paths of a domain module and its sub-modules
+    pub fn domain_code_paths(domain: &DomainModule) -> Vec<String> {
+        domain
+            .code_paths
+            .iter()
+            .chain(domain.sub_modules.iter().flat_map(|sub| &sub.code_paths))
+            .cloned()
+            .collect()
+    }
+
+    /// "Test Coverage" section appended to a key module document
+    pub fn generate_module_section(mapping: &TestMapping, domain: &DomainModule) -> String {
+        let coverage = mapping.coverage_of_paths(&Self::domain_code_paths(domain));
+        let mut content = String::from("\n\n## Test Coverage\n\n");
+        if coverage.source_files == 0 {
+            content.push_str("No source files of this module could be matched against the project files.\n");
+            return content;
+        }
+
+        content.push_str(&format!(
+            "**{}**: {} of {} source files ({:.0}%) are exercised by tests.\n\n",
+            coverage.level(),
+            coverage.tested_files,
+            coverage.source_files,
+            coverage.ratio() * 100.0
+        ));
+
+        let mut sub_rows = String::new();
+        for sub in &domain.sub_modules {
+            let sub_coverage = mapping.coverage_of_paths(&sub.code_paths);
+            if sub_coverage.source_files > 0 {
+                sub_rows.push_str(&Self::coverage_row(&sub.name, &sub_coverage));
+            }
+        }
+        if !sub_rows.is_empty() {
+            content.push_str(Self::COVERAGE_TABLE_HEADER);
+            content.push_str(&sub_rows);
+            content.push('\n');
+        }
+
+        content.push_str(&Self::file_lists(&coverage));
+        content
+    }
+
+    const COVERAGE_TABLE_HEADER: &'static str =
+        "| Module | Source Files | Tested Files | Coverage | Level |\n|---|---|---|---|---|\n";
+
+    fn generate_test_strategy_documentation(
+        &self,
+        mapping: &TestMapping,
+        domain_modules: &[DomainModule],
+    ) -> String {
+        let overall = mapping.coverage(mapping.source_files.iter().map(String::as_str));
+
+        let mut content = String::new();
+        content.push_str("# Test Strategy\n\n");
+        content.push_str(
+            "This document maps the project's tests to the source files they exercise, based on their imports and naming conventions, so that readers can see which parts of the system are well tested. It reports test presence per file, not line coverage.\n\n",
+        );
+
+        content.push_str("## Summary\n\n");
+        content.push_str(&format!(
+            "- **Test files**: {}\n- **Source files with inline tests**: {}\n- **Test cases**: {}\n- **Source files exercised by tests**: {} of {} ({:.0}%)\n\n",
+            mapping.test_files.len(),
+            mapping.inline_tests.len(),
+            mapping.total_test_count(),
+            overall.tested_files,
+            overall.source_files,
+            overall.ratio() * 100.0
+        ));
+
+        if !domain_modules.is_empty() {
+            content.push_str("## Test Presence by Domain Module\n\n");
+            content.push_str(Self::COVERAGE_TABLE_HEADER);
+            for domain in domain_modules {
+                let coverage = mapping.coverage_of_paths(&Self::domain_code_paths(domain));
+                content.push_str(&Self::coverage_row(&domain.name, &coverage));
+            }
+            content.push('\n');
+        } else {
+            content.push_str("## Test Presence by Directory\n\n");
+            content.push_str(Self::COVERAGE_TABLE_HEADER);
+            let mut by_directory: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+            for file in &mapping.source_files {
+                let directory = file.rsplit_once('/').map(|(dir, _)| dir).unwrap_or(".");
+                by_directory.entry(directory).or_default().push(file);
+            }
+            for (directory, files) in by_directory {
+                content.push_str(&Self::coverage_row(directory, &mapping.coverage(files)));
+            }
+            content.push('\n');
+        }
+
+        content.push_str("## Test Suites\n\n");
+        if !mapping.test_files.is_empty() {
+            content.push_str("| Test File | Test Cases | Exercises |\n|---|---|---|\n");
+            for test_file in &mapping.test_files {
+                let targets = if test_file.targets.is_empty() {
+                    "-".to_string()
+                } else {
+                    test_file.targets.iter().map(|t| format!("`{}`", t)).collect::<Vec<_>>().join(", ")
+                };
+                content.push_str(&format!("| `{}` | {} | {} |\n", test_file.path, test_file.test_count, targets));
+            }
+            content.push('\n');
+        }
+        if !mapping.inline_tests.is_empty() {
+            content.push_str("### Inline Test Modules\n\n");
+            content.push_str("| Source File | Test Cases |\n|---|---|\n");
+            for (file, count) in &mapping.inline_tests {
+                content.push_str(&format!("| `{}` | {} |\n", file, count));
+            }
+            content.push('\n');
+        }
+        if mapping.is_empty() {
+            content.push_str("No tests were found in the project.\n\n");
+        }
+
+        content
+    }
+
+    fn coverage_row(name: &str, coverage: &TestCoverage) -> String {
+        format!(
+            "| {} | {} | {} | {:.0}% | {} |\n",
+            name,
+            coverage.source_files,
+            coverage.tested_files,
+            coverage.ratio() * 100.0,
+            coverage.level()
+        )
+    }
+
+    fn file_lists(coverage: &TestCoverage) -> String {
+        let mut content = String::new();
+        if !coverage.test_files.is_empty() {
+            content.push_str("**Tests**: ");
+            content.push_str(&Self::listed(coverage.test_files.iter().map(String::as_str), MAX_LISTED_TESTS));
+            content.push_str("\n\n");
+        }
+        if !coverage.untested_files.is_empty() {
+            content.push_str("**Untested files**: ");
+            content.push_str(&Self::listed(
+                coverage.untested_files.iter().map(String::as_str),
+                MAX_LISTED_UNTESTED,
+            ));
+            content.push_str("\n\n");
+        }
+        content
+    }
+
+    fn listed<'a>(items: impl ExactSizeIterator<Item = &'a str>, limit: usize) -> String {
+        let total = items.len();
+        let mut listed: Vec<String> = items.take(limit).map(|item| format!("`{}`", item)).collect();
+        if total > limit {
+            listed.push(format!("and {} more", total - limit));
+        }
+        listed.join(", ")
+    }
+}
@@ -19,6 +19,10 @@ impl StepForwardAgent for WorkflowEditor {
         MemoryScope::DOCUMENTATION.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "workflow_editor"
+    }
+
     fn should_include_timestamp(&self) -> bool {
         true
     }
@@ -0,0 +1,192 @@
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::code::CodeInsight;
+use crate::types::dependency_inventory::DependencyInventory;
+use crate::types::workspace::{WorkspaceInfo, WorkspaceMember};
+use anyhow::Result;
+
+/// Workspace Documentation Editor - Renders monorepo members as containers with one sub-document per member
+#[derive(Default)]
+pub struct WorkspaceEditor;
+
+impl WorkspaceEditor {
+    /// Generates the documents directly from preprocessing results without using LLM
+    pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
+        let Some(workspace) = context
+            .get_from_memory::<WorkspaceInfo>(PreprocessScope::PREPROCESS, ScopedKeys::WORKSPACE)
+            .await
+        else {
+            return Ok(());
+        };
+
+        let inventory = context
+            .get_from_memory::<DependencyInventory>(
+                PreprocessScope::PREPROCESS,
+                ScopedKeys::DEPENDENCY_INVENTORY,
+            )
+            .await
+            .unwrap_or_default();
+        let code_insights = context
+            .get_from_memory::<Vec<CodeInsight>>(PreprocessScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .await
+            .unwrap_or_default();
+
+        let target_language = &context.config.target_language;
+        let members_dir = target_language.get_directory_name("workspace_members");
+
+        // Overview document with the inter-member dependency diagram
+        let agent_type = AgentType::Workspace.to_string();
+        let content = self.generate_workspace_documentation(&workspace, &members_dir);
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &agent_type, &content)
+            .await?;
+        doc_tree.insert(&agent_type, &target_language.get_doc_filename("workspace"));
+
+        // One sub-document per member
+        for member in &workspace.members {
+            let member_key = format!("{}_{}", agent_type, member.name);
+            let content =
+                self.generate_member_documentation(member, &workspace, &inventory, &code_insights);
+            context
+                .store_to_memory(MemoryScope::DOCUMENTATION, &member_key, &content)
+                .await?;
+            doc_tree.insert(
+                &member_key,
+                &format!("{}/{}.md", members_dir, Self::file_name(&member.name)),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn generate_workspace_documentation(&self, workspace: &WorkspaceInfo, members_dir: &str) -> String {
+        let mut content = String::new();
+        content.push_str("# Workspace Members\n\n");
+        content.push_str(&format!(
+            "The repository is a monorepo declared by {}. Each member below is built independently and forms a container of the system.\n\n",
+            workspace
+                .root_manifests
+                .iter()
+                .map(|m| format!("`{}`", m))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        content.push_str("## Members\n\n");
+        content.push_str("| Member | Path | Tool | Files | Description |\n|---|---|---|---|---|\n");
+        for member in &workspace.members {
+            content.push_str(&format!(
+                "| [{}]({}/{}.md) | `{}` | {} | {} | {} |\n",
+                member.name,
+                members_dir,
+                Self::file_name(&member.name),
+                member.path,
+                member.tool,
+                member.file_count,
+                member.description.as_deref().unwrap_or("-")
+            ));
+        }
+        content.push('\n');
+
+        content.push_str("## Inter-Member Dependencies\n\n");
+        content.push_str("```mermaid\ngraph TD\n");
+        for (i, member) in workspace.members.iter().enumerate() {
+            content.push_str(&format!("    M{}[\"{}\"]\n", i, member.name));
+        }
+        for (i, member) in workspace.members.iter().enumerate() {
+            for dependency in &member.depends_on {
+                if let Some(j) = workspace.members.iter().position(|m| &m.name == dependency) {
+                    content.push_str(&format!("    M{} --> M{}\n", i, j));
+                }
+            }
+        }
+        content.push_str("```\n");
+
+        content
+    }
+
+    fn generate_member_documentation(
+        &self,
+        member: &WorkspaceMember,
+        workspace: &WorkspaceInfo,
+        inventory: &DependencyInventory,
+        code_insights: &[CodeInsight],
+    ) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# {}\n\n", member.name));
+        if let Some(description) = &member.description {
+            content.push_str(&format!("{}\n\n", description));
+        }
+
+        content.push_str(&format!("**Path**: `{}`\n\n", member.path));
+        content.push_str(&format!("**Manifest**: `{}`\n\n", member.manifest));
+        content.push_str(&format!("**Build Tool**: {}\n\n", member.tool));
+        content.push_str(&format!("**Analyzed Files**: {}\n\n", member.file_count));
+
+        if !member.depends_on.is_empty() {
+            content.push_str("## Depends On\n\n");
+            for dependency in &member.depends_on {
+                content.push_str(&format!("- {}\n", dependency));
+            }
+            content.push('\n');
+        }
+
+        let dependents = workspace.dependents_of(&member.name);
+        if !dependents.is_empty() {
+            content.push_str("## Used By\n\n");
+            for dependent in dependents {
+                content.push_str(&format!("- {}\n", dependent.name));
+            }
+            content.push('\n');
+        }
+
+        let member_insights: Vec<&CodeInsight> = code_insights
+            .iter()
+            .filter(|insight| {
+                workspace
+                    .member_of(&insight.code_dossier.file_path.to_string_lossy())
+                    .is_some_and(|m| m.name == member.name)
+            })
+            .collect();
+        if !member_insights.is_empty() {
+            content.push_str("## Core Files\n\n");
+            for insight in member_insights {
+                content.push_str(&format!(
+                    "- `{}`: {}\n",
+                    insight.code_dossier.file_path.to_string_lossy(),
+                    insight.detailed_description.lines().next().unwrap_or_default()
+                ));
+            }
+            content.push('\n');
+        }
+
+        let third_party: Vec<_> = inventory
+            .dependencies
+            .iter()
+            .filter(|dep| dep.declared_in.contains(&member.manifest))
+            .collect();
+        if !third_party.is_empty() {
+            content.push_str("## Third-Party Dependencies\n\n");
+            content.push_str("| Package | Version | Scope |\n|---|---|---|\n");
+            for dep in third_party {
+                content.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    dep.name,
+                    dep.effective_version().unwrap_or("-"),
+                    if dep.is_dev { "development" } else { "runtime" }
+                ));
+            }
+            content.push('\n');
+        }
+
+        content
+    }
+
+    /// Package names may contain characters that are not valid in file names, e.g. `@scope/name`
+    fn file_name(name: &str) -> String {
+        name.trim_start_matches('@').replace(['/', ':', '\\'], "-")
+    }
+}
@@ -1,8 +1,13 @@
 use crate::generator::compose::agents::architecture_editor::ArchitectureEditor;
+use crate::generator::compose::agents::architecture_health_editor::ArchitectureHealthEditor;
 use crate::generator::compose::agents::boundary_editor::BoundaryEditor;
+use crate::generator::compose::agents::configuration_editor::ConfigurationEditor;
 use crate::generator::compose::agents::key_modules_insight_editor::KeyModulesInsightEditor;
 use crate::generator::compose::agents::overview_editor::OverviewEditor;
+use crate::generator::compose::agents::tech_stack_editor::TechStackEditor;
+use crate::generator::compose::agents::test_strategy_editor::TestStrategyEditor;
 use crate::generator::compose::agents::workflow_editor::WorkflowEditor;
+use crate::generator::compose::agents::workspace_editor::WorkspaceEditor;
 use crate::generator::context::GeneratorContext;
 use crate::generator::outlet::DocTree;
 use crate::generator::step_forward_agent::StepForwardAgent;
@@ -38,6 +43,21 @@ impl DocumentationComposer {
         let boundary_editor = BoundaryEditor::default();
         boundary_editor.execute(context).await?;
 
+        let architecture_health_editor = ArchitectureHealthEditor;
+        architecture_health_editor.execute(context, doc_tree).await?;
+
+        let tech_stack_editor = TechStackEditor;
+        tech_stack_editor.execute(context, doc_tree).await?;
+
+        let workspace_editor = WorkspaceEditor;
+        workspace_editor.execute(context, doc_tree).await?;
+
+        let configuration_editor = ConfigurationEditor;
+        configuration_editor.execute(context, doc_tree).await?;
+
+        let test_strategy_editor = TestStrategyEditor;
+        test_strategy_editor.execute(context, doc_tree).await?;
+
         Ok(())
     }
 }
@@ -7,7 +7,12 @@ pub enum AgentType {
     Overview,
     Architecture,
     Workflow,
-    Boundary
+    Boundary,
+    ArchitectureHealth,
+    TechStack,
+    Workspace,
+    Configuration,
+    TestStrategy,
 }
 
 impl Display for AgentType {
@@ -17,6 +22,11 @@ impl Display for AgentType {
             AgentType::Architecture => "Architecture Description",
             AgentType::Workflow => "Core Workflows",
             AgentType::Boundary => "Boundary Interfaces",
+            AgentType::ArchitectureHealth => "Architecture Health",
+            AgentType::TechStack => "Technology Stack",
+            AgentType::Workspace => "Workspace Members",
+            AgentType::Configuration => "Configuration Reference",
+            AgentType::TestStrategy => "Test Strategy",
         };
         write!(f, "{}", str)
     }
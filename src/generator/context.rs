@@ -5,7 +5,10 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
-use crate::{cache::CacheManager, config::Config, llm::client::LLMClient, memory::Memory};
+use crate::{
+    cache::CacheManager, config::Config, llm::client::LLMClient, memory::Memory,
+    utils::ignore_rules::IgnoreRules,
+};
 
 #[derive(Clone)]
 pub struct GeneratorContext {
@@ -17,6 +20,8 @@ pub struct GeneratorContext {
     pub cache_manager: Arc<RwLock<CacheManager>>,
     /// Generator memory
     pub memory: Arc<RwLock<Memory>>,
+    /// Ignore rules of the project, loaded once and shared with the LLM file tools
+    pub ignore_rules: Arc<IgnoreRules>,
 }
 
 impl GeneratorContext {
@@ -1,6 +1,8 @@
 use crate::generator::compose::types::AgentType;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::generator::{compose::memory::MemoryScope, context::GeneratorContext};
 use crate::i18n::TargetLanguage;
+use crate::types::symbol_index::SymbolIndex;
 use anyhow::Result;
 use std::collections::HashMap;
 use std::fs;
@@ -8,9 +10,11 @@ use std::fs;
 pub mod summary_generator;
 pub mod summary_outlet;
 pub mod fixer;
+pub mod symbol_linker;
 
 pub use summary_outlet::SummaryOutlet;
 pub use fixer::MermaidFixer;
+pub use symbol_linker::SymbolLinker;
 
 pub trait Outlet {
     async fn save(&self, context: &GeneratorContext) -> Result<()>;
@@ -77,6 +81,13 @@ impl Outlet for DiskOutlet {
         }
         fs::create_dir_all(output_dir)?;
 
+        // Link symbol mentions to their source locations
+        let symbol_index = context
+            .get_from_memory::<SymbolIndex>(PreprocessScope::PREPROCESS, ScopedKeys::SYMBOL_INDEX)
+            .await
+            .unwrap_or_default();
+        let symbol_linker = SymbolLinker::new(&symbol_index, &context.config.project_path, output_dir);
+
         // Iterate through document tree structure and save each document
         for (scoped_key, relative_path) in &self.doc_tree.structure {
             // Get document content from memory
@@ -94,6 +105,11 @@ impl Outlet for DiskOutlet {
                     }
                 }
 
+                let doc_markdown = match &symbol_linker {
+                    Some(linker) => linker.link(&doc_markdown, relative_path),
+                    None => doc_markdown,
+                };
+
                 // Write document content to file
                 fs::write(&output_file_path, doc_markdown)?;
 
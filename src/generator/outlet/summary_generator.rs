@@ -10,6 +10,7 @@ use crate::generator::preprocess::memory::{MemoryScope as PreprocessMemoryScope,
 use crate::generator::research::memory::MemoryScope as ResearchMemoryScope;
 use crate::generator::research::types::AgentType as ResearchAgentType;
 use crate::generator::workflow::{TimingKeys, TimingScope};
+use crate::llm::client::types::{UsageRecord, UsageReport};
 
 /// Summary data collector - responsible for extracting four types of research materials from context
 pub struct SummaryDataCollector;
@@ -41,6 +42,8 @@ pub struct SummaryData {
     pub memory_stats: HashMap<String, usize>,
     /// Cache performance statistics
     pub cache_stats: CacheStatsData,
+    /// Token usage reported by the providers, per agent and per model
+    pub token_usage: UsageReport,
     /// Generated documents list
     pub generated_docs: Vec<String>,
     /// Timing statistics
@@ -136,6 +139,8 @@ impl SummaryDataCollector {
             output_tokens_saved: cache_report.output_tokens_saved,
         };
 
+        let token_usage = context.llm_client.usage_tracker().report();
+
         // Collect generated documents list
         let generated_docs = context
             .list_memory_keys(ComposeMemoryScope::DOCUMENTATION)
@@ -155,6 +160,7 @@ impl SummaryDataCollector {
             code_insights,
             memory_stats,
             cache_stats,
+            token_usage,
             generated_docs,
             timing_stats,
         })
@@ -325,7 +331,10 @@ impl SummaryContentGenerator {
         }
         content.push_str("\n");
 
-        // 4. Core research data summary
+        // 4. LLM token usage
+        content.push_str(&Self::generate_token_usage_section(&data.token_usage));
+
+        // 5. Core research data summary
         content.push_str("## Core Research Data Summary\n\n");
         content.push_str("Complete content of four types of research materials according to Prompt template data integration rules:\n\n");
 
@@ -369,7 +378,7 @@ impl SummaryContentGenerator {
             ));
         }
 
-        // 5. Memory storage statistics
+        // 6. Memory storage statistics
         content.push_str("## Memory Storage Statistics\n\n");
         if data.memory_stats.is_empty() {
             content.push_str("No Memory storage data available.\n\n");
@@ -386,7 +395,7 @@ impl SummaryContentGenerator {
             content.push_str("\n");
         }
 
-        // 6. Generated documents statistics
+        // 7. Generated documents statistics
         content.push_str("## Generated Documents Statistics\n\n");
         content.push_str(&format!(
             "Number of Generated Documents: {}\n\n",
@@ -399,6 +408,74 @@ impl SummaryContentGenerator {
         content
     }
 
+    /// Token usage section with the per-agent and per-model breakdowns
+    fn generate_token_usage_section(report: &UsageReport) -> String {
+        let mut content = String::new();
+        content.push_str("## LLM Token Usage and Cost\n\n");
+        content.push_str("Tokens reported by the model providers for the calls of this run, cache hits excluded, priced with the model pricing table.\n\n");
+        content.push_str(&format!("- **LLM Calls**: {}\n", report.total.calls));
+        content.push_str(&format!(
+            "- **Tokens Used**: {} input ({} cached) + {} output = {} total\n",
+            report.total.usage.input_tokens,
+            report.total.usage.cached_input_tokens,
+            report.total.usage.output_tokens,
+            report.total.usage.total_tokens
+        ));
+        content.push_str(&format!("- **Cost**: ${:.4}\n", report.total.cost));
+        if !report.unpriced_models.is_empty() {
+            content.push_str(&format!(
+                "- **Unpriced Models** (excluded from costs, add them to `[llm.pricing]`): {}\n",
+                report
+                    .unpriced_models
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        content.push('\n');
+        if report.total.calls == 0 {
+            return content;
+        }
+
+        let table = |title: &str, column: &str, records: &std::collections::BTreeMap<String, UsageRecord>| {
+            let mut rows: Vec<_> = records.iter().collect();
+            rows.sort_by_key(|(_, record)| std::cmp::Reverse(record.usage.total_tokens));
+
+            let mut table = format!("### {}\n\n", title);
+            table.push_str(&format!(
+                "| {} | Calls | Input Tokens | Output Tokens | Total Tokens | Cost (USD) | Served By |\n",
+                column
+            ));
+            table.push_str("|---|---|---|---|---|---|---|\n");
+            for (name, record) in rows {
+                let served_by = record
+                    .providers
+                    .iter()
+                    .map(|(provider, calls)| format!("{} ×{}", provider, calls))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                table.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {:.4} | {} |\n",
+                    name,
+                    record.calls,
+                    record.usage.input_tokens,
+                    record.usage.output_tokens,
+                    record.usage.total_tokens,
+                    record.cost,
+                    served_by
+                ));
+            }
+            table.push('\n');
+            table
+        };
+        content.push_str(&table("Usage by Stage", "Stage", &report.by_stage));
+        content.push_str(&table("Usage by Agent", "Agent", &report.by_agent));
+        content.push_str(&table("Usage by Model", "Model", &report.by_model));
+        content.push_str(&table("Usage by Provider", "Provider", &report.by_provider));
+        content
+    }
+
     /// Generate brief version of summary content
     fn generate_brief_content(data: &SummaryData) -> String {
         let mut content = String::new();
@@ -477,7 +554,29 @@ impl SummaryContentGenerator {
         }
         content.push_str("\n");
 
-        // 4. Research data overview
+        // 4. Token usage overview
+        let usage = &data.token_usage.total;
+        content.push_str("## Token Usage Overview\n\n");
+        content.push_str(&format!("**LLM Calls**: {}\n", usage.calls));
+        content.push_str(&format!(
+            "**Tokens Used**: {} input + {} output = {} total\n",
+            usage.usage.input_tokens, usage.usage.output_tokens, usage.usage.total_tokens
+        ));
+        content.push_str(&format!("**LLM Cost**: ${:.4}\n", usage.cost));
+        if let Some((agent, record)) = data
+            .token_usage
+            .by_agent
+            .iter()
+            .max_by_key(|(_, record)| record.usage.total_tokens)
+        {
+            content.push_str(&format!(
+                "**Largest Consumer**: {} ({} tokens, ${:.4})\n",
+                agent, record.usage.total_tokens, record.cost
+            ));
+        }
+        content.push('\n');
+
+        // 5. Research data overview
         content.push_str("## Research Data Overview\n\n");
         content.push_str("Successfully collected four types of research materials according to Prompt template data integration rules:\n\n");
 
@@ -518,7 +617,7 @@ impl SummaryContentGenerator {
             (collected_count as f64 / 4.0) * 100.0
         ));
 
-        // 5. Memory storage overview
+        // 6. Memory storage overview
         content.push_str("## Memory Storage Overview\n\n");
         if data.memory_stats.is_empty() {
             content.push_str("No Memory storage data available.\n\n");
@@ -545,7 +644,7 @@ impl SummaryContentGenerator {
             content.push_str("\n");
         }
 
-        // 6. Document generation overview
+        // 7. Document generation overview
         content.push_str("## Document Generation Overview\n\n");
         content.push_str(&format!(
             "**Number of Generated Documents**: {}\n",
@@ -559,7 +658,7 @@ impl SummaryContentGenerator {
         }
         content.push_str("\n");
 
-        // 7. Overall assessment
+        // 8. Overall assessment
         content.push_str("## Overall Assessment\n\n");
 
         // Data completeness assessment
@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Component, Path};
+
+use crate::types::symbol_index::{SymbolDefinition, SymbolIndex};
+
+/// Links symbol names written as inline code in the documents to their source locations
+pub struct SymbolLinker<'a> {
+    symbols: BTreeMap<&'a str, &'a SymbolDefinition>,
+    /// Path from the output directory back to the project root, `/` separated with a trailing `/`
+    output_to_root: String,
+}
+
+impl<'a> SymbolLinker<'a> {
+    /// Linker for documents written to `output_dir`, `None` when the output directory is outside the project
+    pub fn new(index: &'a SymbolIndex, project_root: &Path, output_dir: &Path) -> Option<Self> {
+        if index.is_empty() {
+            return None;
+        }
+        let project_root = std::path::absolute(project_root).ok()?;
+        let output_dir = std::path::absolute(output_dir).ok()?;
+        let relative = Self::lexical_normalize(&output_dir)
+            .strip_prefix(Self::lexical_normalize(&project_root))
+            .ok()?
+            .to_path_buf();
+
+        Some(Self {
+            symbols: index.unique_symbols(),
+            output_to_root: "../".repeat(relative.components().count()),
+        })
+    }
+
+    /// Link the first mention of each unique symbol in the document, code blocks and existing links excluded
+    pub fn link(&self, markdown: &str, doc_relative_path: &str) -> String {
+        let doc_to_root = format!(
+            "{}{}",
+            "../".repeat(doc_relative_path.matches('/').count()),
+            self.output_to_root
+        );
+        let mut linked: HashSet<&str> = HashSet::new();
+        let mut in_code_block = false;
+        let mut output = Vec::new();
+
+        for line in markdown.split('\n') {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block || !line.contains('`') {
+                output.push(line.to_string());
+                continue;
+            }
+            output.push(self.link_line(line, &doc_to_root, &mut linked));
+        }
+
+        output.join("\n")
+    }
+
+    fn link_line(&self, line: &str, doc_to_root: &str, linked: &mut HashSet<&'a str>) -> String {
+        let mut result = String::new();
+        let mut rest = line;
+        while let Some(start) = rest.find('`') {
+            let Some(length) = rest[start + 1..].find('`') else {
+                break;
+            };
+            let code = &rest[start + 1..start + 1 + length];
+            let name = code.strip_suffix("()").unwrap_or(code);
+            let inside_link = rest[..start].ends_with('[') || result.ends_with('[');
+            result.push_str(&rest[..start]);
+
+            match self.symbols.get_key_value(name) {
+                Some((&key, symbol)) if !inside_link && linked.insert(key) => {
+                    result.push_str(&format!("[`{}`]({}{})", code, doc_to_root, symbol.location()));
+                }
+                _ => result.push_str(&rest[start..start + length + 2]),
+            }
+            rest = &rest[start + length + 2..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    fn lexical_normalize(path: &Path) -> std::path::PathBuf {
+        let mut normalized = std::path::PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    normalized.pop();
+                }
+                other => normalized.push(other),
+            }
+        }
+        normalized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, file: &str, start_line: usize, end_line: usize) -> SymbolDefinition {
+        SymbolDefinition {
+            name: name.to_string(),
+            kind: "struct".to_string(),
+            file: file.to_string(),
+            start_line,
+            end_line,
+            visibility: "public".to_string(),
+            references: Vec::new(),
+            reference_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_link_symbols() {
+        let index = SymbolIndex {
+            files: BTreeMap::new(),
+            symbols: vec![
+                symbol("OrderStore", "src/store.rs", 2, 4),
+                symbol("new", "src/a.rs", 1, 3),
+                symbol("new", "src/b.rs", 1, 3),
+                symbol("place_order", "src/service.rs", 10, 10),
+            ],
+        };
+        let linker = SymbolLinker::new(&index, Path::new("/repo"), Path::new("/repo/./litho.docs")).unwrap();
+        let markdown = "`OrderStore` keeps orders, see `OrderStore` and `new`.\n```rust\nlet s = `OrderStore`;\n```\nCall `place_order()` or [`OrderStore`](x).";
+
+        assert_eq!(
+            linker.link(markdown, "4.Deep-Exploration/Orders.md"),
+            "[`OrderStore`](../../src/store.rs#L2-L4) keeps orders, see `OrderStore` and `new`.\n```rust\nlet s = `OrderStore`;\n```\nCall [`place_order()`](../../src/service.rs#L10) or [`OrderStore`](x)."
+        );
+        assert!(SymbolLinker::new(&index, Path::new("/repo"), Path::new("/elsewhere/docs")).is_none());
+    }
+}
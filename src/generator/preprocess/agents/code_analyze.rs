@@ -96,6 +96,7 @@ impl CodeAnalyze {
             prompt_user,
             cache_scope: "ai_code_insight".to_string(),
             log_tag: codes.name.to_string(),
+            model_route: "code_analysis".to_string(),
         })
     }
 }
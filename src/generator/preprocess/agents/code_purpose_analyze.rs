@@ -52,6 +52,7 @@ impl CodePurposeEnhancer {
             prompt_user,
             cache_scope: "ai_code_purpose".to_string(),
             log_tag: file_name.to_string(),
+            model_route: "code_purpose".to_string(),
         }).await;
 
         return match analyze_result {
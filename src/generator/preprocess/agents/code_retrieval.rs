@@ -0,0 +1,249 @@
+use anyhow::Result;
+use md5::{Digest, Md5};
+
+use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
+use crate::types::code::CodeInsight;
+use crate::types::project_structure::ProjectStructure;
+use crate::types::vector_index::{ChunkKind, CodeChunk, VectorIndex};
+use crate::utils::threads::do_parallel_with_limit;
+
+/// Number of chunks embedded per request
+const EMBEDDING_BATCH_SIZE: usize = 64;
+
+/// Embedding-based retrieval over code chunks and code insight descriptions
+pub struct CodeRetrieval {
+    language_processor: LanguageProcessorManager,
+}
+
+impl CodeRetrieval {
+    pub fn new() -> Self {
+        Self {
+            language_processor: LanguageProcessorManager::new(),
+        }
+    }
+
+    /// Build the vector index, reusing the vectors of unchanged chunks from `previous`
+    pub async fn build_index(
+        &self,
+        context: &GeneratorContext,
+        project_structure: &ProjectStructure,
+        code_insights: &[CodeInsight],
+        previous: Option<&VectorIndex>,
+    ) -> Result<VectorIndex> {
+        let retrieval = &context.config.retrieval;
+        let model = context.llm_client.embedding_model();
+
+        let mut chunks = Self::insight_chunks(code_insights);
+        chunks.extend(self.code_chunks(project_structure, retrieval.chunk_lines, retrieval.max_chunks));
+        chunks.truncate(retrieval.max_chunks);
+
+        let reusable = previous.map(|p| p.vectors_by_digest(&model)).unwrap_or_default();
+        let mut vectors: Vec<Option<Vec<f32>>> = chunks
+            .iter()
+            .map(|chunk| reusable.get(chunk.digest.as_str()).map(|v| (*v).clone()))
+            .collect();
+
+        let missing: Vec<usize> = (0..chunks.len()).filter(|&i| vectors[i].is_none()).collect();
+        println!(
+            "   Embedding {} chunks with {} ({} reused)",
+            missing.len(),
+            model,
+            chunks.len() - missing.len()
+        );
+
+        let embedding_futures: Vec<_> = missing
+            .chunks(EMBEDDING_BATCH_SIZE)
+            .map(|batch| {
+                let context = context.clone();
+                let indices = batch.to_vec();
+                let texts: Vec<String> = batch.iter().map(|&i| chunks[i].text.clone()).collect();
+                Box::pin(async move {
+                    let embedded = context.llm_client.embed(texts).await?;
+                    Result::<Vec<(usize, Vec<f32>)>>::Ok(indices.into_iter().zip(embedded).collect())
+                })
+            })
+            .collect();
+        for result in do_parallel_with_limit(embedding_futures, context.config.llm.max_parallels).await {
+            for (i, vector) in result? {
+                vectors[i] = Some(vector);
+            }
+        }
+
+        let (chunks, vectors): (Vec<CodeChunk>, Vec<Vec<f32>>) = chunks
+            .into_iter()
+            .zip(vectors)
+            .filter_map(|(chunk, vector)| vector.map(|v| (chunk, v)))
+            .unzip();
+        Ok(VectorIndex {
+            model,
+            chunks,
+            vectors,
+        })
+    }
+
+    /// The chunks most relevant to a topic, empty when retrieval is disabled
+    pub async fn retrieve(
+        context: &GeneratorContext,
+        topic: &str,
+        top_k: usize,
+    ) -> Result<Vec<(CodeChunk, f32)>> {
+        let Some(index) = context
+            .get_from_memory::<VectorIndex>(MemoryScope::PREPROCESS, ScopedKeys::VECTOR_INDEX)
+            .await
+        else {
+            return Ok(Vec::new());
+        };
+        if index.is_empty() || index.model != context.llm_client.embedding_model() {
+            return Ok(Vec::new());
+        }
+
+        let query = context.llm_client.embed(vec![topic.to_string()]).await?;
+        let Some(query) = query.first() else {
+            return Ok(Vec::new());
+        };
+        Ok(index
+            .search(query, top_k)
+            .into_iter()
+            .map(|(chunk, score)| (chunk.clone(), score))
+            .collect())
+    }
+
+    /// Format retrieved chunks as a prompt section
+    pub fn format_chunks(chunks: &[(CodeChunk, f32)]) -> String {
+        let mut content = String::from("### Relevant Code (retrieved by semantic similarity)\n");
+        for (chunk, score) in chunks {
+            let title = match chunk.kind {
+                ChunkKind::Code => format!("`{}` lines {}-{}", chunk.file, chunk.start_line, chunk.end_line),
+                ChunkKind::Insight => format!("`{}` code insight", chunk.file),
+            };
+            content.push_str(&format!(
+                "#### {} (similarity {:.2})\n```\n{}\n```\n",
+                title, score, chunk.text
+            ));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// One chunk per code insight, most important files first
+    fn insight_chunks(code_insights: &[CodeInsight]) -> Vec<CodeChunk> {
+        let mut sorted: Vec<&CodeInsight> = code_insights.iter().collect();
+        sorted.sort_by(|a, b| {
+            b.code_dossier
+                .importance_score
+                .partial_cmp(&a.code_dossier.importance_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        sorted
+            .into_iter()
+            .map(|insight| {
+                let file = normalize_path(&insight.code_dossier.file_path.to_string_lossy());
+                let interfaces: Vec<&str> = insight.interfaces.iter().map(|i| i.name.as_str()).collect();
+                let text = format!(
+                    "{} ({})\n{}\nResponsibilities: {}\nInterfaces: {}",
+                    insight.code_dossier.name,
+                    insight.code_dossier.code_purpose.display_name(),
+                    insight.detailed_description,
+                    insight.responsibilities.join("; "),
+                    interfaces.join(", ")
+                );
+                Self::chunk(ChunkKind::Insight, file, 1, 1, text)
+            })
+            .collect()
+    }
+
+    /// Source files split into windows of `chunk_lines` lines, most important files first
+    fn code_chunks(
+        &self,
+        project_structure: &ProjectStructure,
+        chunk_lines: usize,
+        max_chunks: usize,
+    ) -> Vec<CodeChunk> {
+        let mut files: Vec<_> = project_structure
+            .files
+            .iter()
+            .filter(|file| self.language_processor.get_processor(&file.path).is_some())
+            .collect();
+        files.sort_by(|a, b| {
+            b.importance_score
+                .partial_cmp(&a.importance_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut chunks = Vec::new();
+        for file in files {
+            if chunks.len() >= max_chunks {
+                break;
+            }
+            let path = normalize_path(&file.path.to_string_lossy());
+            let Ok(content) = std::fs::read_to_string(project_structure.root_path.join(&path)) else {
+                continue;
+            };
+            chunks.extend(Self::split_lines(&path, &content, chunk_lines.max(1)));
+        }
+        chunks
+    }
+
+    fn split_lines(path: &str, content: &str, chunk_lines: usize) -> Vec<CodeChunk> {
+        let lines: Vec<&str> = content.lines().collect();
+        lines
+            .chunks(chunk_lines)
+            .enumerate()
+            .filter(|(_, window)| window.iter().any(|line| !line.trim().is_empty()))
+            .map(|(i, window)| {
+                let start_line = i * chunk_lines + 1;
+                Self::chunk(
+                    ChunkKind::Code,
+                    path.to_string(),
+                    start_line,
+                    start_line + window.len() - 1,
+                    window.join("\n"),
+                )
+            })
+            .collect()
+    }
+
+    fn chunk(kind: ChunkKind, file: String, start_line: usize, end_line: usize, text: String) -> CodeChunk {
+        let mut hasher = Md5::new();
+        hasher.update(file.as_bytes());
+        hasher.update(text.as_bytes());
+        CodeChunk {
+            kind,
+            file,
+            start_line,
+            end_line,
+            text,
+            digest: format!("{:x}", hasher.finalize()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_lines_and_search() {
+        let content = "fn checkout_order() {}\n\n\n\n\n\n\nfn render_sidebar() {}\n";
+        let chunks = CodeRetrieval::split_lines("src/shop.rs", content, 4);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (1, 4));
+        assert_eq!((chunks[1].start_line, chunks[1].end_line), (5, 8));
+        assert_ne!(chunks[0].digest, chunks[1].digest);
+
+        let index = VectorIndex {
+            model: "test".to_string(),
+            vectors: vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+            chunks,
+        };
+        let found = index.search(&[0.2, 0.9], 1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.start_line, 5);
+        assert!(index.vectors_by_digest("other").is_empty());
+        assert_eq!(index.vectors_by_digest("test").len(), 2);
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use crate::generator::agent_executor::{AgentExecuteParams, extract};
+use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::types::code::CodeInsight;
+use crate::types::hierarchical_summary::{
+    DirectorySummary, HierarchicalSummary, PackageSummary, SummaryOutline,
+};
+use crate::types::workspace::WorkspaceInfo;
+use crate::utils::threads::do_parallel_with_limit;
+
+/// Number of files or directories summarized in one prompt, larger groups are reduced in several rounds
+const MAX_ITEMS_PER_PROMPT: usize = 40;
+
+/// Map-reduce summarization of code insights: files roll up into directory summaries, directories into package summaries
+pub struct HierarchicalSummarize;
+
+impl HierarchicalSummarize {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub async fn execute(
+        &self,
+        context: &GeneratorContext,
+        code_insights: &[CodeInsight],
+        workspace: Option<&WorkspaceInfo>,
+    ) -> Result<HierarchicalSummary> {
+        let max_parallels = context.config.llm.max_parallels;
+
+        // Map: summarize the file insights of each directory
+        let directory_futures: Vec<_> = Self::group_by_directory(code_insights)
+            .into_iter()
+            .map(|(path, insights)| {
+                let context = context.clone();
+                let items: Vec<String> = insights.iter().map(|i| Self::file_item(i)).collect();
+                let files: Vec<String> = insights.iter().map(|i| Self::file_path(i)).collect();
+                let max_importance = insights
+                    .iter()
+                    .map(|i| i.code_dossier.importance_score)
+                    .fold(0.0, f64::max);
+                let single = (insights.len() == 1).then(|| Self::file_outline(insights[0]));
+
+                Box::pin(async move {
+                    let outline = match single {
+                        Some(outline) => outline,
+                        None => Self::summarize(&context, &format!("directory `{}`", path), items).await?,
+                    };
+                    Result::<DirectorySummary>::Ok(DirectorySummary {
+                        path,
+                        files,
+                        max_importance,
+                        outline,
+                    })
+                })
+            })
+            .collect();
+
+        let mut directories = Vec::new();
+        for result in do_parallel_with_limit(directory_futures, max_parallels).await {
+            directories.push(result?);
+        }
+
+        // Reduce: summarize the directory summaries of each package
+        let package_futures: Vec<_> = Self::group_by_package(&directories, workspace)
+            .into_iter()
+            .map(|((name, path), members)| {
+                let context = context.clone();
+                let items: Vec<String> = members.iter().map(|d| d.outline.to_markdown(&d.path)).collect();
+                let directory_paths: Vec<String> = members.iter().map(|d| d.path.clone()).collect();
+                let file_count = members.iter().map(|d| d.files.len()).sum();
+                let single = (members.len() == 1).then(|| members[0].outline.clone());
+
+                Box::pin(async move {
+                    let outline = match single {
+                        Some(outline) => outline,
+                        None => Self::summarize(&context, &format!("package `{}`", name), items).await?,
+                    };
+                    Result::<PackageSummary>::Ok(PackageSummary {
+                        name,
+                        path,
+                        directories: directory_paths,
+                        file_count,
+                        outline,
+                    })
+                })
+            })
+            .collect();
+
+        let mut packages = Vec::new();
+        for result in do_parallel_with_limit(package_futures, max_parallels).await {
+            packages.push(result?);
+        }
+
+        Ok(HierarchicalSummary {
+            packages,
+            directories,
+        })
+    }
+
+    /// Summarize the items of a group, reducing them chunk by chunk until they fit into one prompt
+    async fn summarize(
+        context: &GeneratorContext,
+        subject: &str,
+        mut items: Vec<String>,
+    ) -> Result<SummaryOutline> {
+        while items.len() > MAX_ITEMS_PER_PROMPT {
+            let mut reduced = Vec::new();
+            for (index, chunk) in items.chunks(MAX_ITEMS_PER_PROMPT).enumerate() {
+                let part = format!("part {}", index + 1);
+                let outline =
+                    Self::summarize_chunk(context, &format!("{} ({})", subject, part), chunk).await?;
+                reduced.push(outline.to_markdown(&part));
+            }
+            items = reduced;
+        }
+        Self::summarize_chunk(context, subject, &items).await
+    }
+
+    async fn summarize_chunk(
+        context: &GeneratorContext,
+        subject: &str,
+        items: &[String],
+    ) -> Result<SummaryOutline> {
+        let prompt_sys = "You are a professional software architecture analyst. Summarize a part of a code base from the insights of its files or sub-directories, so that it can be understood without reading every file.".to_string();
+        let prompt_user = format!(
+            "Summarize the {} from the following insights:
+
+{}
+
+## Requirements:
+1. Describe what the {} is for and how its parts work together in 2-4 sentences
+2. List its main responsibilities
+3. Name its most important components",
+            subject,
+            items.join("\n"),
+            subject
+        );
+
+        extract::<SummaryOutline>(
+            context,
+            AgentExecuteParams {
+                prompt_sys,
+                prompt_user,
+                cache_scope: "ai_hierarchical_summary".to_string(),
+                log_tag: subject.to_string(),
+                model_route: "hierarchical_summary".to_string(),
+            },
+        )
+        .await
+    }
+
+    fn file_path(insight: &CodeInsight) -> String {
+        normalize_path(&insight.code_dossier.file_path.to_string_lossy())
+    }
+
+    /// Insights grouped by the directory of their file, `.` for root files
+    fn group_by_directory(code_insights: &[CodeInsight]) -> BTreeMap<String, Vec<&CodeInsight>> {
+        let mut groups: BTreeMap<String, Vec<&CodeInsight>> = BTreeMap::new();
+        for insight in code_insights {
+            let path = Self::file_path(insight);
+            let directory = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or(".");
+            groups.entry(directory.to_string()).or_default().push(insight);
+        }
+        groups
+    }
+
+    /// Directories grouped by (name, path) of their workspace member, or of their top-level directory
+    fn group_by_package<'a>(
+        directories: &'a [DirectorySummary],
+        workspace: Option<&WorkspaceInfo>,
+    ) -> BTreeMap<(String, String), Vec<&'a DirectorySummary>> {
+        let mut groups: BTreeMap<(String, String), Vec<&DirectorySummary>> = BTreeMap::new();
+        for directory in directories {
+            let member = workspace.and_then(|w| {
+                directory.files.first().and_then(|file| w.member_of(file))
+            });
+            let key = match member {
+                Some(member) if !member.path.is_empty() => (member.name.clone(), member.path.clone()),
+                _ => {
+                    let top = directory.path.split('/').next().unwrap_or(".");
+                    (top.to_string(), top.to_string())
+                }
+            };
+            groups.entry(key).or_default().push(directory);
+        }
+        groups
+    }
+
+    fn file_item(insight: &CodeInsight) -> String {
+        let description = if insight.detailed_description.is_empty() {
+            insight.code_dossier.description.clone().unwrap_or_default()
+        } else {
+            insight.detailed_description.clone()
+        };
+        format!(
+            "- `{}` ({}, importance: {:.2}): {}",
+            Self::file_path(insight),
+            insight.code_dossier.code_purpose.display_name(),
+            insight.code_dossier.importance_score,
+            description
+        )
+    }
+
+    /// Summary of a directory with a single analyzed file, taken from the file insight without an LLM call
+    fn file_outline(insight: &CodeInsight) -> SummaryOutline {
+        SummaryOutline {
+            summary: if insight.detailed_description.is_empty() {
+                insight.code_dossier.description.clone().unwrap_or_default()
+            } else {
+                insight.detailed_description.clone()
+            },
+            responsibilities: insight.responsibilities.clone(),
+            key_components: vec![insight.code_dossier.name.clone()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::workspace::{WorkspaceMember, WorkspaceTool};
+
+    fn directory(path: &str, files: &[&str]) -> DirectorySummary {
+        DirectorySummary {
+            path: path.to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+            max_importance: 0.5,
+            outline: SummaryOutline::default(),
+        }
+    }
+
+    #[test]
+    fn test_group_by_package() {
+        let directories = vec![
+            directory(".", &["build.rs"]),
+            directory("crates/core/src", &["crates/core/src/lib.rs"]),
+            directory("crates/core/src/model", &["crates/core/src/model/user.rs"]),
+            directory("tools/gen", &["tools/gen/main.rs"]),
+            directory("tools", &["tools/util.rs"]),
+        ];
+        let workspace = WorkspaceInfo {
+            root_manifests: vec!["Cargo.toml".to_string()],
+            members: vec![WorkspaceMember {
+                name: "acme-core".to_string(),
+                path: "crates/core".to_string(),
+                manifest: "crates/core/Cargo.toml".to_string(),
+                tool: WorkspaceTool::Cargo,
+                description: None,
+                depends_on: Vec::new(),
+                file_count: 2,
+            }],
+        };
+
+        let groups = HierarchicalSummarize::group_by_package(&directories, Some(&workspace));
+        let paths = |name: &str, path: &str| -> Vec<&str> {
+            groups[&(name.to_string(), path.to_string())].iter().map(|d| d.path.as_str()).collect()
+        };
+        assert_eq!(groups.len(), 3);
+        assert_eq!(paths("acme-core", "crates/core"), vec!["crates/core/src", "crates/core/src/model"]);
+        assert_eq!(paths("tools", "tools"), vec!["tools/gen", "tools"]);
+        assert_eq!(paths(".", "."), vec!["."]);
+
+        let covering = HierarchicalSummary {
+            packages: Vec::new(),
+            directories,
+        };
+        let covered = covering.directories_covering(&["tools/util.rs".to_string()]);
+        assert_eq!(covered.len(), 1);
+        assert_eq!(covered[0].path, "tools");
+    }
+}
@@ -1,3 +1,5 @@
 pub mod code_analyze;
+pub mod code_retrieval;
 pub mod code_purpose_analyze;
+pub mod hierarchical_summarize;
 pub mod relationships_analyze;
@@ -2,12 +2,17 @@ use anyhow::Result;
 
 use crate::generator::agent_executor::{AgentExecuteParams, extract};
 use crate::types::code::CodeInsight;
+use crate::types::hierarchical_summary::HierarchicalSummary;
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
 use crate::{
     generator::context::GeneratorContext,
     types::{code_releationship::RelationshipAnalysis, project_structure::ProjectStructure},
     utils::prompt_compressor::{CompressionConfig, PromptCompressor},
 };
 
+/// Number of code insights listed individually, the remaining ones are covered by directory summaries
+pub const MAX_DETAILED_INSIGHTS: usize = 150;
+
 pub struct RelationshipsAnalyze {
     prompt_compressor: PromptCompressor,
 }
@@ -24,9 +29,10 @@ impl RelationshipsAnalyze {
         context: &GeneratorContext,
         code_insights: &Vec<CodeInsight>,
         _project_structure: &ProjectStructure,
+        hierarchical_summary: Option<&HierarchicalSummary>,
     ) -> Result<RelationshipAnalysis> {
         let agent_params = self
-            .build_optimized_analysis_params(context, code_insights)
+            .build_optimized_analysis_params(context, code_insights, hierarchical_summary)
             .await?;
         extract::<RelationshipAnalysis>(context, agent_params).await
     }
@@ -36,6 +42,7 @@ impl RelationshipsAnalyze {
         &self,
         context: &GeneratorContext,
         code_insights: &[CodeInsight],
+        hierarchical_summary: Option<&HierarchicalSummary>,
     ) -> Result<AgentExecuteParams> {
         let prompt_sys = "You are a professional software architecture analyst specializing in analyzing project-level code dependency relationship graphs. Based on the provided code insights and dependencies, generate an overall architectural relationship analysis for the project.".to_string();
 
@@ -48,8 +55,10 @@ impl RelationshipsAnalyze {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        // Build code insights content
-        let insights_content = self.build_insights_content(&sorted_insights);
+        // Build code insights content, the insights left out are represented by their directory summaries
+        let (detailed_insights, omitted_insights) = Self::split_insights(&sorted_insights);
+        let mut insights_content = self.build_insights_content(&detailed_insights);
+        insights_content.push_str(&Self::build_omitted_content(&omitted_insights, hierarchical_summary));
 
         let compression_result = self
             .prompt_compressor
@@ -84,15 +93,63 @@ Generate a project-level dependency relationship graph, focusing on:
             prompt_user,
             cache_scope: "ai_relationships_insights".to_string(),
             log_tag: "Dependency Relationship Analysis".to_string(),
+            model_route: "relationships".to_string(),
         })
     }
 
+    /// Split sorted insights into the ones listed individually and the ones left out
+    fn split_insights<'a>(sorted_insights: &[&'a CodeInsight]) -> (Vec<&'a CodeInsight>, Vec<&'a CodeInsight>) {
+        let (mut detailed, mut omitted): (Vec<&CodeInsight>, Vec<&CodeInsight>) = sorted_insights
+            .iter()
+            .partition(|insight| insight.code_dossier.importance_score >= 0.6);
+        if detailed.len() > MAX_DETAILED_INSIGHTS {
+            omitted.splice(0..0, detailed.drain(MAX_DETAILED_INSIGHTS..));
+        }
+        (detailed, omitted)
+    }
+
+    /// Cover the insights left out: by the summaries of their directories when available, by name otherwise
+    fn build_omitted_content(
+        omitted_insights: &[&CodeInsight],
+        hierarchical_summary: Option<&HierarchicalSummary>,
+    ) -> String {
+        if omitted_insights.is_empty() {
+            return String::new();
+        }
+        let omitted_paths: Vec<String> = omitted_insights
+            .iter()
+            .map(|insight| normalize_path(&insight.code_dossier.file_path.to_string_lossy()))
+            .collect();
+
+        if let Some(summary) = hierarchical_summary {
+            let directories = summary.directories_covering(&omitted_paths);
+            if !directories.is_empty() {
+                let mut content = format!(
+                    "\n\n## Directory Summaries ({} further files)\n",
+                    omitted_paths.len()
+                );
+                for directory in directories {
+                    content.push_str(&directory.outline.to_markdown(&format!("`{}`", directory.path)));
+                }
+                return content;
+            }
+        }
+
+        format!(
+            "\n\n## Other Files\n{}",
+            omitted_insights
+                .iter()
+                .zip(&omitted_paths)
+                .map(|(insight, path)| format!("- `{}` ({})", path, insight.code_dossier.code_purpose.display_name()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+
     /// Build code insights content
     fn build_insights_content(&self, sorted_insights: &[&CodeInsight]) -> String {
         sorted_insights
             .iter()
-            .filter(|insight| insight.code_dossier.importance_score >= 0.6)
-            .take(150) // Increase quantity limit
             .map(|insight| {
                 let dependencies_introduce = insight
                     .dependencies
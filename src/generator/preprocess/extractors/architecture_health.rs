@@ -0,0 +1,176 @@
+use glob::Pattern;
+
+use crate::config::{ArchitectureRulesConfig, LayerRule};
+use crate::generator::preprocess::extractors::import_graph::ImportGraph;
+use crate::types::code_releationship::{
+    ArchitectureHealthReport, DependencyCycle, LayerViolation,
+};
+
+/// Checks the import graph against cycle detection and configured layer rules
+pub struct ArchitectureHealthChecker {
+    layers: Vec<(LayerRule, Vec<Pattern>)>,
+    detect_cycles: bool,
+}
+
+impl ArchitectureHealthChecker {
+    pub fn new(rules: &ArchitectureRulesConfig) -> Self {
+        let layers = rules
+            .layers
+            .iter()
+            .map(|layer| {
+                let patterns = layer
+                    .paths
+                    .iter()
+                    .filter_map(|path| match Pattern::new(path) {
+                        Ok(pattern) => Some(pattern),
+                        Err(e) => {
+                            eprintln!(
+                                "⚠️ Invalid path pattern '{}' in layer '{}': {}",
+                                path, layer.name, e
+                            );
+                            None
+                        }
+                    })
+                    .collect();
+                (layer.clone(), patterns)
+            })
+            .collect();
+
+        Self {
+            layers,
+            detect_cycles: rules.detect_cycles,
+        }
+    }
+
+    pub fn check(&self, graph: &ImportGraph) -> ArchitectureHealthReport {
+        let cycles = if self.detect_cycles {
+            graph
+                .find_cycles()
+                .into_iter()
+                .map(|(members, example_path)| DependencyCycle {
+                    members,
+                    example_path,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut layer_violations = Vec::new();
+        for edge in &graph.edges {
+            let (Some(from_layer), Some(to_layer)) =
+                (self.layer_of(&edge.from), self.layer_of(&edge.to))
+            else {
+                continue;
+            };
+
+            if from_layer.name != to_layer.name
+                && from_layer.must_not_depend_on.contains(&to_layer.name)
+            {
+                layer_violations.push(LayerViolation {
+                    from_layer: from_layer.name.clone(),
+                    to_layer: to_layer.name.clone(),
+                    from_file: edge.from.clone(),
+                    to_file: edge.to.clone(),
+                    line_number: edge.line_number,
+                });
+            }
+        }
+
+        ArchitectureHealthReport {
+            analyzed_files: graph.nodes.len(),
+            resolved_imports: graph.edges.len(),
+            cycles,
+            layer_violations,
+        }
+    }
+
+    /// First layer whose patterns match the file
+    fn layer_of(&self, file: &str) -> Option<&LayerRule> {
+        self.layers
+            .iter()
+            .find(|(_, patterns)| patterns.iter().any(|pattern| pattern.matches(file)))
+            .map(|(layer, _)| layer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::preprocess::extractors::import_graph::ImportEdge;
+
+    fn edge(from: &str, to: &str, line_number: usize) -> ImportEdge {
+        ImportEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            line_number: Some(line_number),
+        }
+    }
+
+    fn layer(name: &str, paths: &[&str], must_not_depend_on: &[&str]) -> LayerRule {
+        LayerRule {
+            name: name.to_string(),
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            must_not_depend_on: must_not_depend_on.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_layer_violations() {
+        let rules = ArchitectureRulesConfig {
+            detect_cycles: false,
+            layers: vec![
+                layer("domain", &["src/domain/**"], &["infra", "api"]),
+                layer("infra", &["src/infra/**"], &["api"]),
+                layer("api", &["src/api/**", "src/main.rs"], &[]),
+            ],
+            fail_on_violation: true,
+        };
+        let graph = ImportGraph {
+            nodes: Vec::new(),
+            edges: vec![
+                edge("src/domain/order.rs", "src/infra/db.rs", 3),
+                edge("src/domain/order.rs", "src/domain/money.rs", 4),
+                edge("src/infra/db.rs", "src/domain/order.rs", 1),
+                edge("src/api/routes.rs", "src/infra/db.rs", 2),
+                edge("src/infra/db.rs", "src/main.rs", 7),
+                edge("src/util.rs", "src/api/routes.rs", 1),
+            ],
+        };
+
+        let report = ArchitectureHealthChecker::new(&rules).check(&graph);
+
+        let violations: Vec<_> = report
+            .layer_violations
+            .iter()
+            .map(|v| (v.from_layer.as_str(), v.to_layer.as_str(), v.from_file.as_str(), v.line_number))
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                ("domain", "infra", "src/domain/order.rs", Some(3)),
+                ("infra", "api", "src/infra/db.rs", Some(7)),
+            ]
+        );
+        assert!(report.cycles.is_empty());
+        assert!(report.has_violations());
+    }
+
+    #[test]
+    fn test_cycles_reported_only_when_detected() {
+        let graph = ImportGraph {
+            nodes: vec!["src/a.rs".to_string(), "src/b.rs".to_string()],
+            edges: vec![edge("src/a.rs", "src/b.rs", 1), edge("src/b.rs", "src/a.rs", 1)],
+        };
+
+        let report = ArchitectureHealthChecker::new(&ArchitectureRulesConfig::default()).check(&graph);
+        assert_eq!(report.cycles.len(), 1);
+        assert!(report.layer_violations.is_empty());
+
+        let rules = ArchitectureRulesConfig {
+            detect_cycles: false,
+            ..Default::default()
+        };
+        assert!(!ArchitectureHealthChecker::new(&rules).check(&graph).has_violations());
+    }
+}
@@ -0,0 +1,1356 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::generator::research::types::{CLIArgument, CLIBoundary, CLIOption};
+use crate::types::project_structure::ProjectStructure;
+use crate::utils::source_scan::{Syntax, balanced, line_of, split_top_level, unquote};
+
+/// Maximum depth of nested subcommands followed when resolving clap definitions
+const MAX_SUBCOMMAND_DEPTH: usize = 5;
+
+static CLAP_ITEM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:pub(?:\([^)]*\))?\s+)?(?:struct|enum)\s+(\w+)[^{;]*\{").unwrap()
+});
+static CLAP_VARIANT_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\w+)\s*([({])?").unwrap());
+static CLAP_FIELD_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:pub(?:\([^)]*\))?\s+)?(\w+)\s*:\s*(.+)$").unwrap());
+static ARGPARSE_EVENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:(\w+)\s*=\s*)?(?:(\w+)\.)?(ArgumentParser|add_subparsers|add_parser|add_argument_group|add_mutually_exclusive_group|add_argument)\s*\(",
+    )
+    .unwrap()
+});
+static CLICK_COMMAND_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@(\w+(?:\.\w+)*)\.(command|group)\s*\(").unwrap());
+static CLICK_DEF_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*(?:async\s+)?def\s+(\w+)\s*\(").unwrap());
+static CLICK_PARAM_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@(?:click\.)?(option|argument)\s*\(").unwrap());
+static COBRA_EVENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:(\w+)\s*:?=\s*)?&cobra\.Command\s*\{|(\w+)\s*:=\s*(\w+)\.(?:Flags|PersistentFlags|LocalFlags)\(\)\s*\n|(\w+)(\.(?:Flags|PersistentFlags|LocalFlags)\(\))?\.(\w+)\s*\(",
+    )
+    .unwrap()
+});
+static COMMANDER_CALL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:(?:const|let|var)\s+(\w+)\s*=\s*)?(?:(\w+)\s*)?\.\s*(command|name|description|summary|option|requiredOption|argument)\s*\(",
+    )
+    .unwrap()
+});
+static YARGS_CALL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.\s*(scriptName|command|option|positional)\s*\(").unwrap());
+
+/// Statically extract CLI definitions (clap, argparse, click, cobra, commander, yargs) from the project sources
+pub fn extract(project_structure: &ProjectStructure) -> Vec<CLIBoundary> {
+    let program_name = project_structure.project_name.clone();
+    let mut boundaries = Vec::new();
+    let mut clap_items = HashMap::new();
+
+    for file in &project_structure.files {
+        let Some(extension) = file.extension.as_deref() else {
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(project_structure.root_path.join(&file.path)) else {
+            continue;
+        };
+        let path = normalize_path(&file.path.to_string_lossy());
+
+        match extension {
+            "rs" if content.contains("clap") || content.contains("Parser") => {
+                ClapParser::collect_items(&content, &path, &mut clap_items);
+            }
+            "py" => {
+                if content.contains("argparse") || content.contains("ArgumentParser") {
+                    boundaries.extend(extract_argparse(&content, &path, &program_name));
+                }
+                if content.contains("click") {
+                    boundaries.extend(extract_click(&content, &path));
+                }
+            }
+            "go" if content.contains("cobra") => {
+                boundaries.extend(extract_cobra(&content, &path));
+            }
+            "js" | "ts" | "mjs" | "cjs" => {
+                if content.contains("commander") {
+                    boundaries.extend(extract_commander(&content, &path, &program_name));
+                } else if content.contains("yargs") {
+                    boundaries.extend(extract_yargs(&content, &path, &program_name));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    boundaries.extend(ClapParser::resolve(&clap_items, &program_name));
+    boundaries
+}
+
+// ---------------------------------------------------------------------------
+// Shared helpers
+// ---------------------------------------------------------------------------
+
+/// String literal value, or the raw expression for non-literals
+fn literal_or_expression(text: &str) -> String {
+    unquote(text).unwrap_or_else(|| text.trim().to_string())
+}
+
+/// Value of `key=value`, `key = value` or `key: value` among split arguments
+fn keyword<'a>(args: &'a [String], key: &str) -> Option<&'a str> {
+    args.iter().find_map(|arg| {
+        let rest = arg.strip_prefix(key)?.trim_start();
+        let rest = rest.strip_prefix('=').or_else(|| rest.strip_prefix(':'))?;
+        // Exclude `==` comparisons
+        (!rest.starts_with('=')).then(|| rest.trim())
+    })
+}
+
+fn first_line(text: &str) -> String {
+    text.trim().lines().next().unwrap_or_default().trim().to_string()
+}
+
+fn to_kebab_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('-');
+            }
+            result.extend(c.to_lowercase());
+        } else if c == '_' {
+            result.push('-');
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn new_boundary(command: String, description: String, path: &str, line: usize) -> CLIBoundary {
+    CLIBoundary {
+        command,
+        description,
+        arguments: Vec::new(),
+        options: Vec::new(),
+        examples: Vec::new(),
+        source_location: format!("{}:{}", path, line),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rust: clap derive
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClapItemKind {
+    Parser,
+    Args,
+    Subcommand,
+}
+
+/// A documented member of a struct or enum: field or variant
+#[derive(Debug, Clone, Default)]
+struct ClapMember {
+    docs: Vec<String>,
+    attrs: Vec<String>,
+    declaration: String,
+}
+
+#[derive(Debug, Clone)]
+struct ClapItem {
+    kind: ClapItemKind,
+    path: String,
+    line: usize,
+    docs: Vec<String>,
+    attrs: Vec<String>,
+    members: Vec<ClapMember>,
+}
+
+/// Clap items keyed by file and type name, distinct crates of a workspace often reusing names such as `Cli`
+type ClapItems = HashMap<(String, String), ClapItem>;
+
+struct ClapParser;
+
+impl ClapParser {
+    /// Collect `#[derive(Parser | Args | Subcommand)]` structs and enums of a file
+    fn collect_items(content: &str, path: &str, items: &mut ClapItems) {
+        for captures in CLAP_ITEM_REGEX.captures_iter(content) {
+            let whole = captures.get(0).unwrap();
+            let name = captures[1].to_string();
+            let header = ClapMember::parse(&Self::preceding_header(content, whole.start()));
+
+            let Some(kind) = header.attrs.iter().find_map(|attr| Self::derive_kind(attr)) else {
+                continue;
+            };
+            let Some(body) = balanced(content, whole.end() - 1, Syntax::Rust) else {
+                continue;
+            };
+
+            items.insert(
+                (path.to_string(), name),
+                ClapItem {
+                    kind,
+                    path: path.to_string(),
+                    line: line_of(content, whole.start()),
+                    docs: header.docs,
+                    attrs: header.attrs,
+                    members: split_top_level(body, Syntax::Rust)
+                        .iter()
+                        .map(|member| ClapMember::parse(member))
+                        .filter(|member| !member.declaration.is_empty())
+                        .collect(),
+                },
+            );
+        }
+    }
+
+    /// Doc comments and attributes directly above an item
+    fn preceding_header(content: &str, item_start: usize) -> String {
+        let mut lines = Vec::new();
+        let mut in_attribute = false;
+        for line in content[..item_start].lines().rev() {
+            let trimmed = line.trim();
+            if in_attribute {
+                lines.push(trimmed);
+                if trimmed.starts_with("#[") {
+                    in_attribute = false;
+                }
+            } else if trimmed.starts_with("///") || trimmed.starts_with("#[") {
+                lines.push(trimmed);
+            } else if trimmed.ends_with(']') {
+                // End of a multi-line attribute
+                in_attribute = true;
+                lines.push(trimmed);
+            } else {
+                break;
+            }
+        }
+        lines.reverse();
+        lines.join("\n")
+    }
+
+    fn derive_kind(attr: &str) -> Option<ClapItemKind> {
+        if !attr.starts_with("derive") {
+            return None;
+        }
+        let derives = attr.trim_start_matches("derive");
+        let has = |name: &str| {
+            derives
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| word == name)
+        };
+        if has("Parser") {
+            Some(ClapItemKind::Parser)
+        } else if has("Subcommand") {
+            Some(ClapItemKind::Subcommand)
+        } else if has("Args") {
+            Some(ClapItemKind::Args)
+        } else {
+            None
+        }
+    }
+
+    /// Item named by a type referenced from `from_path`: the one of that file, else the first one in path order
+    fn lookup<'a>(items: &'a ClapItems, from_path: &str, name: &str) -> Option<&'a ClapItem> {
+        items
+            .get(&(from_path.to_string(), name.to_string()))
+            .or_else(|| {
+                items
+                    .iter()
+                    .filter(|((_, item_name), _)| item_name == name)
+                    .min_by(|((a, _), _), ((b, _), _)| a.cmp(b))
+                    .map(|(_, item)| item)
+            })
+    }
+
+    /// Build the CLI boundaries reachable from each `Parser` item
+    fn resolve(items: &ClapItems, program_name: &str) -> Vec<CLIBoundary> {
+        let mut parsers: Vec<&ClapItem> = items
+            .values()
+            .filter(|item| item.kind == ClapItemKind::Parser)
+            .collect();
+        parsers.sort_by(|a, b| a.path.cmp(&b.path).then(a.line.cmp(&b.line)));
+
+        let mut boundaries = Vec::new();
+        for parser in parsers {
+            let name = Self::command_attr(&parser.attrs, "name")
+                .or_else(|| Self::command_attr(&parser.attrs, "bin_name"))
+                .unwrap_or_else(|| program_name.to_string());
+            let description = Self::command_attr(&parser.attrs, "about")
+                .unwrap_or_else(|| first_line(&parser.docs.join("\n")));
+
+            let mut boundary = new_boundary(name.clone(), description, &parser.path, parser.line);
+            let subcommands = Self::apply_fields(items, &parser.path, &parser.members, &mut boundary, 0);
+            boundaries.push(boundary);
+
+            for subcommand in subcommands {
+                Self::resolve_subcommands(items, subcommand, &name, &mut boundaries, 1);
+            }
+        }
+        boundaries
+    }
+
+    fn resolve_subcommands(
+        items: &ClapItems,
+        item: &ClapItem,
+        parent: &str,
+        boundaries: &mut Vec<CLIBoundary>,
+        depth: usize,
+    ) {
+        if depth > MAX_SUBCOMMAND_DEPTH || item.kind != ClapItemKind::Subcommand {
+            return;
+        }
+
+        for variant in &item.members {
+            let Some(captures) = CLAP_VARIANT_REGEX.captures(&variant.declaration) else {
+                continue;
+            };
+            let variant_name = &captures[1];
+            let name = Self::command_attr(&variant.attrs, "name")
+                .unwrap_or_else(|| to_kebab_case(variant_name));
+            let command = format!("{} {}", parent, name);
+            let description = Self::command_attr(&variant.attrs, "about")
+                .unwrap_or_else(|| first_line(&variant.docs.join("\n")));
+
+            let mut boundary = new_boundary(command.clone(), description, &item.path, item.line);
+            let mut nested = Vec::new();
+
+            match captures.get(2).map(|m| m.as_str()) {
+                // Struct variant: fields declared inline
+                Some("{") => {
+                    let open = captures.get(2).unwrap().start();
+                    if let Some(body) = balanced(&variant.declaration, open, Syntax::Rust) {
+                        let members: Vec<ClapMember> = split_top_level(body, Syntax::Rust)
+                            .iter()
+                            .map(|member| ClapMember::parse(member))
+                            .collect();
+                        nested = Self::apply_fields(items, &item.path, &members, &mut boundary, depth);
+                    }
+                }
+                // Tuple variant: arguments of an `Args` struct or a nested `Subcommand` enum
+                Some("(") => {
+                    let open = captures.get(2).unwrap().start();
+                    if let Some(inner) = balanced(&variant.declaration, open, Syntax::Rust) {
+                        match Self::lookup(items, &item.path, &Self::type_name(inner)) {
+                            Some(nested_item) if nested_item.kind == ClapItemKind::Subcommand => {
+                                nested.push(nested_item)
+                            }
+                            Some(args) => {
+                                nested = Self::apply_fields(items, &args.path, &args.members, &mut boundary, depth);
+                            }
+                            None => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            boundaries.push(boundary);
+            for subcommand in nested {
+                Self::resolve_subcommands(items, subcommand, &command, boundaries, depth + 1);
+            }
+        }
+    }
+
+    /// Add the fields declared in `from_path` as arguments/options of the boundary, returning referenced subcommand enums
+    fn apply_fields<'a>(
+        items: &'a ClapItems,
+        from_path: &str,
+        members: &[ClapMember],
+        boundary: &mut CLIBoundary,
+        depth: usize,
+    ) -> Vec<&'a ClapItem> {
+        let mut subcommands = Vec::new();
+
+        for member in members {
+            let Some(captures) = CLAP_FIELD_REGEX.captures(member.declaration.trim()) else {
+                continue;
+            };
+            let field_name = captures[1].to_string();
+            let field_type = captures[2].trim().to_string();
+            let arg_attr = member
+                .attrs
+                .iter()
+                .find_map(|attr| {
+                    attr.strip_prefix("arg(")
+                        .or_else(|| attr.strip_prefix("clap("))
+                        .or_else(|| attr.strip_prefix("command("))
+                        .and_then(|a| a.strip_suffix(')'))
+                })
+                .map(|a| split_top_level(a, Syntax::Rust))
+                .unwrap_or_default();
+            let has_flag = |flag: &str| arg_attr.iter().any(|a| a == flag);
+
+            if has_flag("subcommand") {
+                subcommands.extend(Self::lookup(items, from_path, &Self::type_name(&field_type)));
+                continue;
+            }
+            if has_flag("flatten") {
+                if depth <= MAX_SUBCOMMAND_DEPTH
+                    && let Some(item) = Self::lookup(items, from_path, &Self::type_name(&field_type))
+                {
+                    subcommands.extend(Self::apply_fields(items, &item.path, &item.members, boundary, depth + 1));
+                }
+                continue;
+            }
+
+            let is_optional = field_type.starts_with("Option<");
+            let is_multiple = field_type.starts_with("Vec<");
+            let value_type = field_type
+                .strip_prefix("Option<")
+                .and_then(|t| t.strip_suffix('>'))
+                .unwrap_or(&field_type)
+                .to_string();
+            let description = keyword(&arg_attr, "help")
+                .map(literal_or_expression)
+                .unwrap_or_else(|| first_line(&member.docs.join("\n")));
+            let default_value = keyword(&arg_attr, "default_value")
+                .or_else(|| keyword(&arg_attr, "default_value_t"))
+                .or_else(|| keyword(&arg_attr, "default_values_t"))
+                .map(literal_or_expression);
+            let explicit_required = keyword(&arg_attr, "required").map(|v| v == "true");
+
+            let short = if has_flag("short") {
+                field_name.chars().next().map(|c| format!("-{}", c))
+            } else {
+                keyword(&arg_attr, "short")
+                    .map(|v| format!("-{}", literal_or_expression(v)))
+            };
+            let long = if has_flag("long") {
+                Some(format!("--{}", to_kebab_case(&field_name)))
+            } else {
+                keyword(&arg_attr, "long").map(|v| format!("--{}", literal_or_expression(v)))
+            };
+
+            let implicitly_required =
+                !is_optional && !is_multiple && default_value.is_none() && value_type != "bool";
+
+            if short.is_none() && long.is_none() {
+                boundary.arguments.push(CLIArgument {
+                    name: field_name,
+                    description,
+                    required: explicit_required.unwrap_or(implicitly_required),
+                    default_value,
+                    value_type,
+                });
+            } else {
+                boundary.options.push(CLIOption {
+                    name: long.clone().or(short.clone()).unwrap_or_default(),
+                    short_name: if long.is_some() { short } else { None },
+                    description,
+                    required: explicit_required.unwrap_or(implicitly_required),
+                    default_value,
+                    value_type,
+                });
+            }
+        }
+
+        subcommands
+    }
+
+    /// Value of `key = "..."` inside `#[command(...)]`/`#[clap(...)]` attributes
+    fn command_attr(attrs: &[String], key: &str) -> Option<String> {
+        attrs.iter().find_map(|attr| {
+            let inner = attr
+                .strip_prefix("command(")
+                .or_else(|| attr.strip_prefix("clap("))?
+                .strip_suffix(')')?;
+            keyword(&split_top_level(inner, Syntax::Rust), key).and_then(unquote)
+        })
+    }
+
+    /// `Option<Box<Commands>>` -> `Commands`
+    fn type_name(type_text: &str) -> String {
+        type_text
+            .rsplit(['<', ':'])
+            .next()
+            .unwrap_or(type_text)
+            .trim_end_matches('>')
+            .trim()
+            .to_string()
+    }
+}
+
+impl ClapMember {
+    /// Separate doc comments and attributes from the declaration
+    fn parse(text: &str) -> Self {
+        let mut member = Self::default();
+        let mut rest = text.trim_start();
+
+        loop {
+            if let Some(after) = rest.strip_prefix("///") {
+                let (line, remaining) = after.split_once('\n').unwrap_or((after, ""));
+                member.docs.push(line.trim().to_string());
+                rest = remaining.trim_start();
+            } else if rest.starts_with("//") {
+                rest = rest.split_once('\n').map(|(_, r)| r).unwrap_or("").trim_start();
+            } else if rest.starts_with("#[") {
+                let Some(inner) = balanced(rest, 1, Syntax::Rust) else {
+                    break;
+                };
+                member.attrs.push(inner.split_whitespace().collect::<Vec<_>>().join(" "));
+                rest = rest[inner.len() + 3..].trim_start();
+            } else {
+                break;
+            }
+        }
+
+        member.declaration = rest.trim().to_string();
+        member
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Python: argparse
+// ---------------------------------------------------------------------------
+
+fn extract_argparse(content: &str, path: &str, program_name: &str) -> Vec<CLIBoundary> {
+    let mut boundaries: Vec<CLIBoundary> = Vec::new();
+    // Variable name -> index of the command it belongs to
+    let mut variables: HashMap<String, usize> = HashMap::new();
+
+    for captures in ARGPARSE_EVENT_REGEX.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+        let Some(args_text) = balanced(content, whole.end() - 1, Syntax::Python) else {
+            continue;
+        };
+        let args = split_top_level(args_text, Syntax::Python);
+        let target = captures.get(1).map(|m| m.as_str().to_string());
+        let receiver = captures.get(2).map(|m| m.as_str());
+        let receiver_command = receiver.and_then(|r| variables.get(r).copied());
+        let line = line_of(content, whole.start());
+
+        match &captures[3] {
+            "ArgumentParser" => {
+                let name = keyword(&args, "prog")
+                    .and_then(unquote)
+                    .unwrap_or_else(|| program_name.to_string());
+                let description = keyword(&args, "description").map(literal_or_expression).unwrap_or_default();
+                boundaries.push(new_boundary(name, first_line(&description), path, line));
+                if let Some(target) = target {
+                    variables.insert(target, boundaries.len() - 1);
+                }
+            }
+            "add_parser" => {
+                let Some(parent) = receiver_command else {
+                    continue;
+                };
+                let Some(name) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let description = keyword(&args, "help")
+                    .or_else(|| keyword(&args, "description"))
+                    .map(literal_or_expression)
+                    .unwrap_or_default();
+                let command = format!("{} {}", boundaries[parent].command, name);
+                boundaries.push(new_boundary(command, first_line(&description), path, line));
+                if let Some(target) = target {
+                    variables.insert(target, boundaries.len() - 1);
+                }
+            }
+            // Subparser collections and argument groups add to the command they are created from
+            "add_subparsers" | "add_argument_group" | "add_mutually_exclusive_group" => {
+                if let (Some(target), Some(parent)) = (target, receiver_command) {
+                    variables.insert(target, parent);
+                }
+            }
+            "add_argument" => {
+                let Some(command) = receiver_command else {
+                    continue;
+                };
+                add_argparse_argument(&mut boundaries[command], &args);
+            }
+            _ => {}
+        }
+    }
+
+    boundaries
+}
+
+fn add_argparse_argument(boundary: &mut CLIBoundary, args: &[String]) {
+    let names: Vec<String> = args.iter().filter_map(|a| unquote(a)).collect();
+    if names.is_empty() {
+        return;
+    }
+
+    let action = keyword(args, "action").and_then(unquote);
+    let is_flag = matches!(action.as_deref(), Some("store_true" | "store_false" | "count"));
+    let value_type = if is_flag {
+        "bool".to_string()
+    } else if let Some(choices) = keyword(args, "choices") {
+        format!("choice {}", choices)
+    } else {
+        keyword(args, "type").map(|t| t.to_string()).unwrap_or_else(|| "str".to_string())
+    };
+    let default_value = keyword(args, "default")
+        .map(literal_or_expression)
+        .or_else(|| match action.as_deref() {
+            Some("store_true") => Some("False".to_string()),
+            Some("store_false") => Some("True".to_string()),
+            _ => None,
+        });
+    let description = keyword(args, "help").map(literal_or_expression).unwrap_or_default();
+    let nargs = keyword(args, "nargs").map(literal_or_expression);
+
+    if names[0].starts_with('-') {
+        let long = names.iter().find(|n| n.starts_with("--")).unwrap_or(&names[0]).clone();
+        let short = names.iter().find(|n| !n.starts_with("--") && *n != &long).cloned();
+        boundary.options.push(CLIOption {
+            name: long,
+            short_name: short,
+            description,
+            required: keyword(args, "required") == Some("True"),
+            default_value,
+            value_type,
+        });
+    } else {
+        let optional = default_value.is_some() || matches!(nargs.as_deref(), Some("?" | "*"));
+        boundary.arguments.push(CLIArgument {
+            name: names[0].clone(),
+            description,
+            required: !optional,
+            default_value,
+            value_type,
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Python: click
+// ---------------------------------------------------------------------------
+
+fn extract_click(content: &str, path: &str) -> Vec<CLIBoundary> {
+    let mut boundaries = Vec::new();
+    // Group function name -> command name
+    let mut groups: HashMap<String, String> = HashMap::new();
+
+    for captures in CLICK_COMMAND_REGEX.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+        let Some(args_text) = balanced(content, whole.end() - 1, Syntax::Python) else {
+            continue;
+        };
+        let Some(def) = CLICK_DEF_REGEX.captures_at(content, whole.end()) else {
+            continue;
+        };
+        let def_start = def.get(0).unwrap().start();
+        let function_name = def[1].to_string();
+        let args = split_top_level(args_text, Syntax::Python);
+
+        let own_name = args
+            .first()
+            .and_then(|a| unquote(a))
+            .or_else(|| keyword(&args, "name").and_then(unquote))
+            .unwrap_or_else(|| to_kebab_case(&function_name));
+        let receiver = &captures[1];
+        let command = match groups.get(receiver) {
+            Some(parent) => format!("{} {}", parent, own_name),
+            None => own_name,
+        };
+        if &captures[2] == "group" {
+            groups.insert(function_name.clone(), command.clone());
+        }
+
+        let description = keyword(&args, "help")
+            .map(literal_or_expression)
+            .or_else(|| {
+                // Docstring of the decorated function
+                let after_def = &content[def_start..];
+                let body_start = after_def.find(":\n")?;
+                let docstring = after_def[body_start + 1..].trim_start();
+                let quote = ["\"\"\"", "'''"].into_iter().find(|q| docstring.starts_with(q))?;
+                let end = docstring[3..].find(quote)?;
+                Some(docstring[3..3 + end].to_string())
+            })
+            .unwrap_or_default();
+
+        let mut boundary = new_boundary(command, first_line(&description), path, line_of(content, whole.start()));
+
+        // Parameters are declared by the decorators between the command decorator and the function
+        let decorators = &content[whole.end()..def_start];
+        for param in CLICK_PARAM_REGEX.captures_iter(decorators) {
+            let param_match = param.get(0).unwrap();
+            let Some(param_text) = balanced(decorators, param_match.end() - 1, Syntax::Python) else {
+                continue;
+            };
+            add_click_parameter(&mut boundary, &param[1], &split_top_level(param_text, Syntax::Python));
+        }
+
+        boundaries.push(boundary);
+    }
+
+    boundaries
+}
+
+fn add_click_parameter(boundary: &mut CLIBoundary, kind: &str, args: &[String]) {
+    let names: Vec<String> = args.iter().filter_map(|a| unquote(a)).collect();
+    if names.is_empty() {
+        return;
+    }
+
+    let is_flag = keyword(args, "is_flag") == Some("True") || names.iter().any(|n| n.contains('/'));
+    let value_type = if is_flag {
+        "bool".to_string()
+    } else {
+        keyword(args, "type").map(|t| t.to_string()).unwrap_or_else(|| "str".to_string())
+    };
+    let default_value = keyword(args, "default").map(literal_or_expression);
+    let description = keyword(args, "help").map(literal_or_expression).unwrap_or_default();
+    let required = keyword(args, "required").map(|v| v == "True");
+
+    if kind == "argument" {
+        boundary.arguments.push(CLIArgument {
+            name: names[0].clone(),
+            description,
+            required: required
+                .unwrap_or(default_value.is_none() && keyword(args, "nargs") != Some("-1")),
+            default_value,
+            value_type,
+        });
+    } else {
+        // Option declarations may include the Python parameter name without dashes
+        let flags: Vec<&String> = names.iter().filter(|n| n.starts_with('-')).collect();
+        let Some(long) = flags.iter().find(|n| n.starts_with("--")).or(flags.first()) else {
+            return;
+        };
+        let short = flags.iter().find(|n| !n.starts_with("--")).filter(|s| *s != long);
+        boundary.options.push(CLIOption {
+            name: long.to_string(),
+            short_name: short.map(|s| s.to_string()),
+            description,
+            required: required.unwrap_or(false),
+            default_value,
+            value_type,
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Go: cobra
+// ---------------------------------------------------------------------------
+
+const COBRA_FLAG_TYPES: [&str; 21] = [
+    "", "Bool", "String", "Int", "Int8", "Int16", "Int32", "Int64", "Uint", "Uint8", "Uint16",
+    "Uint32", "Uint64", "Float32", "Float64", "Duration", "StringSlice", "StringArray", "IntSlice",
+    "StringToString", "Count",
+];
+
+fn extract_cobra(content: &str, path: &str) -> Vec<CLIBoundary> {
+    let mut boundaries: Vec<CLIBoundary> = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut commands: HashMap<String, usize> = HashMap::new();
+    let mut flag_sets: HashMap<String, usize> = HashMap::new();
+
+    for captures in COBRA_EVENT_REGEX.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+
+        // Command definition
+        if whole.as_str().trim_end().ends_with('{') {
+            let Some(body) = balanced(content, whole.end() - 1, Syntax::CFamily) else {
+                continue;
+            };
+            let fields = split_top_level(body, Syntax::CFamily);
+            let Some(usage) = keyword(&fields, "Use").and_then(unquote) else {
+                continue;
+            };
+            let name = usage.split_whitespace().next().unwrap_or_default().to_string();
+            let description = keyword(&fields, "Short")
+                .or_else(|| keyword(&fields, "Long"))
+                .map(literal_or_expression)
+                .unwrap_or_default();
+            let mut boundary = new_boundary(name, first_line(&description), path, line_of(content, whole.start()));
+            if let Some(example) = keyword(&fields, "Example").and_then(unquote) {
+                boundary.examples = example.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect();
+            }
+            boundaries.push(boundary);
+            parents.push(None);
+            let variable = captures.get(1).map(|m| m.as_str()).unwrap_or("cmd");
+            commands.insert(variable.to_string(), boundaries.len() - 1);
+            continue;
+        }
+
+        // Flag set alias, e.g. `flags := cmd.Flags()`
+        if let (Some(alias), Some(command)) = (captures.get(2), captures.get(3)) {
+            if let Some(&index) = commands.get(command.as_str()) {
+                flag_sets.insert(alias.as_str().to_string(), index);
+            }
+            continue;
+        }
+
+        let (Some(receiver), Some(method)) = (captures.get(4), captures.get(6)) else {
+            continue;
+        };
+        let receiver = receiver.as_str();
+        let method = method.as_str();
+        let via_flags = captures.get(5).is_some();
+        let command = if via_flags {
+            commands.get(receiver).copied()
+        } else {
+            flag_sets.get(receiver).copied().or_else(|| commands.get(receiver).copied())
+        };
+        let Some(command) = command else {
+            continue;
+        };
+        let Some(args_text) = balanced(content, whole.end() - 1, Syntax::CFamily) else {
+            continue;
+        };
+        let args = split_top_level(args_text, Syntax::CFamily);
+
+        match method {
+            "AddCommand" if !via_flags => {
+                for child in &args {
+                    if let Some(&child) = commands.get(child.trim()) {
+                        parents[child] = Some(command);
+                    }
+                }
+            }
+            "MarkFlagRequired" | "MarkPersistentFlagRequired" => {
+                if let Some(flag) = args.first().and_then(|a| unquote(a)) {
+                    let long = format!("--{}", flag);
+                    for option in boundaries[command].options.iter_mut().filter(|o| o.name == long) {
+                        option.required = true;
+                    }
+                }
+            }
+            _ if via_flags || flag_sets.contains_key(receiver) => {
+                if let Some(option) = parse_cobra_flag(method, &args) {
+                    boundaries[command].options.push(option);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Prefix subcommands with their parent commands
+    let full_names: Vec<String> = (0..boundaries.len())
+        .map(|mut index| {
+            let mut parts = vec![boundaries[index].command.clone()];
+            let mut depth = 0;
+            while let Some(parent) = parents[index] {
+                depth += 1;
+                if depth > MAX_SUBCOMMAND_DEPTH {
+                    break;
+                }
+                parts.push(boundaries[parent].command.clone());
+                index = parent;
+            }
+            parts.reverse();
+            parts.join(" ")
+        })
+        .collect();
+    for (boundary, name) in boundaries.iter_mut().zip(full_names) {
+        boundary.command = name;
+    }
+
+    boundaries
+}
+
+/// Parse `StringVarP(&v, "name", "n", "default", "usage")` style flag definitions
+fn parse_cobra_flag(method: &str, args: &[String]) -> Option<CLIOption> {
+    let is_type = |base: &str| COBRA_FLAG_TYPES.contains(&base);
+    let (rest, has_short) = match method.strip_suffix('P') {
+        Some(rest) if is_type(rest.strip_suffix("Var").unwrap_or(rest)) => (rest, true),
+        _ => (method, false),
+    };
+    let (base, has_var) = match rest.strip_suffix("Var") {
+        Some(base) if is_type(base) => (base, true),
+        _ => (rest, false),
+    };
+    if !is_type(base) || (base.is_empty() && !has_var) {
+        return None;
+    }
+
+    // `Var`/`VarP` take a custom flag.Value and no default
+    let mut values = args.iter().skip(usize::from(has_var));
+    let name = unquote(values.next()?)?;
+    let short = if has_short { values.next().and_then(|s| unquote(s)) } else { None };
+    let default_value = if base.is_empty() { None } else { values.next().map(|v| literal_or_expression(v)) };
+    let description = values.next().map(|v| literal_or_expression(v)).unwrap_or_default();
+
+    Some(CLIOption {
+        name: format!("--{}", name),
+        short_name: short.filter(|s| !s.is_empty()).map(|s| format!("-{}", s)),
+        description,
+        required: false,
+        default_value: default_value.filter(|v| !v.is_empty()),
+        value_type: if base.is_empty() { "value".to_string() } else { base.to_lowercase() },
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Node: commander and yargs
+// ---------------------------------------------------------------------------
+
+/// Parse commander flags such as `-p, --port <number>`
+fn parse_flag_spec(spec: &str) -> (String, Option<String>, String) {
+    let mut long = None;
+    let mut short = None;
+    let mut value_type = "boolean".to_string();
+    for part in spec.split([',', ' ', '|']).filter(|p| !p.is_empty()) {
+        if part.starts_with("--") {
+            long = Some(part.to_string());
+        } else if part.starts_with('-') {
+            short = Some(part.to_string());
+        } else if part.starts_with('<') || part.starts_with('[') {
+            value_type = part.trim_matches(['<', '>', '[', ']']).to_string();
+        }
+    }
+    match long {
+        Some(long) => (long, short, value_type),
+        None => (short.unwrap_or_default(), None, value_type),
+    }
+}
+
+/// Positional arguments declared in a command spec, e.g. `serve <port> [host]`
+fn parse_command_spec(spec: &str) -> (String, Vec<CLIArgument>) {
+    let mut parts = spec.split_whitespace();
+    let name = parts.next().unwrap_or_default().to_string();
+    let arguments = parts
+        .filter(|p| p.starts_with('<') || p.starts_with('['))
+        .map(|p| CLIArgument {
+            name: p.trim_matches(['<', '>', '[', ']', '.']).to_string(),
+            description: String::new(),
+            required: p.starts_with('<'),
+            default_value: None,
+            value_type: if p.contains("...") { "string[]" } else { "string" }.to_string(),
+        })
+        .collect();
+    (name, arguments)
+}
+
+fn extract_commander(content: &str, path: &str, program_name: &str) -> Vec<CLIBoundary> {
+    let mut boundaries = vec![new_boundary(program_name.to_string(), String::new(), path, 1)];
+    let mut variables: HashMap<String, usize> = HashMap::new();
+    let mut current = 0;
+
+    for captures in COMMANDER_CALL_REGEX.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+        let Some(args_text) = balanced(content, whole.end() - 1, Syntax::CFamily) else {
+            continue;
+        };
+        let args = split_top_level(args_text, Syntax::CFamily);
+
+        // A new statement starting from a known variable switches the target command
+        if let Some(receiver) = captures.get(2) {
+            current = variables.get(receiver.as_str()).copied().unwrap_or(0);
+        }
+
+        match &captures[3] {
+            "command" => {
+                let Some(spec) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let (name, arguments) = parse_command_spec(&spec);
+                let command = if current == 0 {
+                    format!("{} {}", boundaries[0].command, name)
+                } else {
+                    format!("{} {}", boundaries[current].command, name)
+                };
+                let description = args.get(1).and_then(|a| unquote(a)).unwrap_or_default();
+                let mut boundary = new_boundary(command, description, path, line_of(content, whole.start()));
+                boundary.arguments = arguments;
+                boundaries.push(boundary);
+                current = boundaries.len() - 1;
+                if let Some(variable) = captures.get(1) {
+                    variables.insert(variable.as_str().to_string(), current);
+                }
+            }
+            "name" => {
+                if let Some(name) = args.first().and_then(|a| unquote(a)) {
+                    boundaries[current].command = name;
+                }
+            }
+            "description" | "summary" => {
+                if let Some(description) = args.first().and_then(|a| unquote(a))
+                    && boundaries[current].description.is_empty()
+                {
+                    boundaries[current].description = first_line(&description);
+                }
+            }
+            "argument" => {
+                let Some(spec) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let (_, mut arguments) = parse_command_spec(&format!("_ {}", spec));
+                for argument in &mut arguments {
+                    argument.description = args.get(1).and_then(|a| unquote(a)).unwrap_or_default();
+                    argument.default_value = args.get(2).map(|a| literal_or_expression(a));
+                    if argument.default_value.is_some() {
+                        argument.required = false;
+                    }
+                }
+                boundaries[current].arguments.extend(arguments);
+            }
+            method @ ("option" | "requiredOption") => {
+                let Some(spec) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let (name, short_name, value_type) = parse_flag_spec(&spec);
+                boundaries[current].options.push(CLIOption {
+                    name,
+                    short_name,
+                    description: args.get(1).and_then(|a| unquote(a)).unwrap_or_default(),
+                    required: method == "requiredOption",
+                    // A function in the third position is a value parser, the default follows it
+                    default_value: args
+                        .iter()
+                        .skip(2)
+                        .find(|a| !a.contains("=>") && !a.starts_with("function") && !a.starts_with("parse"))
+                        .map(|a| literal_or_expression(a)),
+                    value_type,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // The root program is only a boundary when it declares something itself
+    if boundaries[0].options.is_empty() && boundaries[0].arguments.is_empty() && boundaries.len() > 1 {
+        boundaries.remove(0);
+    }
+    boundaries
+        .into_iter()
+        .filter(|b| !b.options.is_empty() || !b.arguments.is_empty() || !b.description.is_empty())
+        .collect()
+}
+
+fn extract_yargs(content: &str, path: &str, program_name: &str) -> Vec<CLIBoundary> {
+    let mut boundaries = vec![new_boundary(program_name.to_string(), String::new(), path, 1)];
+    // Argument span of each `.command(...)` call, options declared inside belong to that command
+    let mut spans: Vec<(usize, usize, usize)> = Vec::new();
+
+    for captures in YARGS_CALL_REGEX.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+        let open = whole.end() - 1;
+        let Some(args_text) = balanced(content, open, Syntax::CFamily) else {
+            continue;
+        };
+        let args = split_top_level(args_text, Syntax::CFamily);
+        let current = spans
+            .iter()
+            .rev()
+            .find(|(start, end, _)| *start < open && open < *end)
+            .map(|(_, _, index)| *index)
+            .unwrap_or(0);
+
+        match &captures[1] {
+            "scriptName" => {
+                if let Some(name) = args.first().and_then(|a| unquote(a)) {
+                    boundaries[0].command = name;
+                }
+            }
+            "command" => {
+                let Some(spec) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let (name, arguments) = parse_command_spec(&spec);
+                let command = format!("{} {}", boundaries[current].command, name);
+                let description = args.get(1).and_then(|a| unquote(a)).unwrap_or_default();
+                let mut boundary = new_boundary(command, description, path, line_of(content, whole.start()));
+                boundary.arguments = arguments;
+                boundaries.push(boundary);
+                spans.push((open, open + args_text.len() + 1, boundaries.len() - 1));
+            }
+            kind @ ("option" | "positional") => {
+                let Some(name) = args.first().and_then(|a| unquote(a)) else {
+                    continue;
+                };
+                let settings = args
+                    .get(1)
+                    .and_then(|a| a.strip_prefix('{'))
+                    .and_then(|a| a.strip_suffix('}'))
+                    .map(|a| split_top_level(a, Syntax::CFamily))
+                    .unwrap_or_default();
+                let description = keyword(&settings, "describe")
+                    .or_else(|| keyword(&settings, "description"))
+                    .or_else(|| keyword(&settings, "desc"))
+                    .map(literal_or_expression)
+                    .unwrap_or_default();
+                let default_value = keyword(&settings, "default").map(literal_or_expression);
+                let value_type = keyword(&settings, "type").map(literal_or_expression).unwrap_or_else(|| "string".to_string());
+                let required = keyword(&settings, "demandOption").is_some_and(|v| v != "false");
+
+                if kind == "positional" {
+                    let boundary = &mut boundaries[current];
+                    match boundary.arguments.iter_mut().find(|a| a.name == name) {
+                        Some(argument) => {
+                            argument.description = description;
+                            argument.value_type = value_type;
+                            if default_value.is_some() {
+                                argument.required = false;
+                                argument.default_value = default_value;
+                            }
+                        }
+                        None => boundary.arguments.push(CLIArgument {
+                            name,
+                            description,
+                            required: default_value.is_none(),
+                            default_value,
+                            value_type,
+                        }),
+                    }
+                } else {
+                    let short_name = keyword(&settings, "alias")
+                        .map(|a| a.trim_matches(['[', ']']))
+                        .and_then(|a| split_top_level(a, Syntax::CFamily).into_iter().filter_map(|s| unquote(&s)).find(|s| s.len() == 1))
+                        .map(|s| format!("-{}", s));
+                    boundaries[current].options.push(CLIOption {
+                        name: format!("--{}", name),
+                        short_name,
+                        description,
+                        required,
+                        default_value,
+                        value_type,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if boundaries[0].options.is_empty() && boundaries[0].arguments.is_empty() && boundaries.len() > 1 {
+        boundaries.remove(0);
+    }
+    boundaries
+        .into_iter()
+        .filter(|b| !b.options.is_empty() || !b.arguments.is_empty() || !b.description.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option<'a>(boundary: &'a CLIBoundary, name: &str) -> &'a CLIOption {
+        boundary.options.iter().find(|o| o.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_extract_clap_derive() {
+        let content = r#"
+use clap::{Parser, Subcommand};
+
+/// Project tool
+#[derive(Parser, Debug)]
+#[command(name = "tool", about = "Does things")]
+pub struct Args {
+    /// Project path
+    #[arg(short, long, default_value = ".")]
+    pub project_path: PathBuf,
+
+    /// Verbose output
+    #[arg(short = 'V', long)]
+    pub verbose: bool,
+
+    #[arg(long)]
+    pub model: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Build the docs
+    Build {
+        /// Output directory
+        output: PathBuf,
+        #[arg(long, default_value_t = 4)]
+        jobs: usize,
+    },
+    Serve(ServeArgs),
+}
+
+#[derive(Args, Debug)]
+struct ServeArgs {
+    #[arg(short, long)]
+    port: u16,
+}
+"#;
+        let mut items = HashMap::new();
+        ClapParser::collect_items(content, "src/cli.rs", &mut items);
+        let boundaries = ClapParser::resolve(&items, "fallback");
+
+        assert_eq!(boundaries.len(), 3);
+        let root = &boundaries[0];
+        assert_eq!(root.command, "tool");
+        assert_eq!(root.description, "Does things");
+        assert_eq!(root.source_location, "src/cli.rs:7");
+
+        let path = option(root, "--project-path");
+        assert_eq!(path.short_name.as_deref(), Some("-p"));
+        assert_eq!(path.default_value.as_deref(), Some("."));
+        assert_eq!(path.description, "Project path");
+        assert!(!path.required);
+        assert_eq!(option(root, "--verbose").short_name.as_deref(), Some("-V"));
+        assert_eq!(option(root, "--model").value_type, "String");
+
+        let build = boundaries.iter().find(|b| b.command == "tool build").unwrap();
+        assert_eq!(build.description, "Build the docs");
+        assert_eq!(build.arguments[0].name, "output");
+        assert!(build.arguments[0].required);
+        assert_eq!(option(build, "--jobs").default_value.as_deref(), Some("4"));
+
+        let serve = boundaries.iter().find(|b| b.command == "tool serve").unwrap();
+        assert!(option(serve, "--port").required);
+    }
+
+    #[test]
+    fn test_clap_items_resolved_within_their_file() {
+        let server = r#"
+#[derive(Parser)]
+#[command(name = "server")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    Start,
+}
+"#;
+        let client = r#"
+#[derive(Parser)]
+#[command(name = "client")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    Fetch,
+}
+"#;
+        let mut items = HashMap::new();
+        ClapParser::collect_items(server, "server/src/main.rs", &mut items);
+        ClapParser::collect_items(client, "client/src/main.rs", &mut items);
+        let mut commands: Vec<_> = ClapParser::resolve(&items, "fallback")
+            .into_iter()
+            .map(|b| b.command)
+            .collect();
+        commands.sort();
+
+        assert_eq!(commands, ["client", "client fetch", "server", "server start"]);
+    }
+
+    #[test]
+    fn test_extract_argparse_and_click() {
+        let argparse = r#"
+import argparse
+parser = argparse.ArgumentParser(prog="etl", description="ETL runner")
+parser.add_argument("-v", "--verbose", action="store_true", help="Verbose logs")
+sub = parser.add_subparsers(dest="cmd")
+run = sub.add_parser("run", help="Run a job")
+run.add_argument("job", help="Job name")
+run.add_argument("--retries", type=int, default=3,
+                 help="Retry count")
+"#;
+        let boundaries = extract_argparse(argparse, "etl.py", "fallback");
+        assert_eq!(boundaries.len(), 2);
+        assert_eq!(boundaries[0].command, "etl");
+        let verbose = option(&boundaries[0], "--verbose");
+        assert_eq!(verbose.short_name.as_deref(), Some("-v"));
+        assert_eq!(verbose.value_type, "bool");
+        assert_eq!(boundaries[1].command, "etl run");
+        assert!(boundaries[1].arguments[0].required);
+        let retries = option(&boundaries[1], "--retries");
+        assert_eq!(retries.value_type, "int");
+        assert_eq!(retries.default_value.as_deref(), Some("3"));
+
+        let click = r#"
+import click
+
+@click.group()
+def cli():
+    pass
+
+@cli.command()
+@click.option("--count", "-c", default=1, type=int, help="Number of greetings")
+@click.argument("name")
+def hello(count, name):
+    """Greet someone."""
+"#;
+        let boundaries = extract_click(click, "app.py");
+        assert_eq!(boundaries.len(), 2);
+        let hello = &boundaries[1];
+        assert_eq!(hello.command, "cli hello");
+        assert_eq!(hello.description, "Greet someone.");
+        assert_eq!(option(hello, "--count").short_name.as_deref(), Some("-c"));
+        assert_eq!(hello.arguments[0].name, "name");
+    }
+
+    #[test]
+    fn test_extract_cobra() {
+        let content = r#"
+var rootCmd = &cobra.Command{
+	Use:   "app",
+	Short: "App server",
+}
+
+var serveCmd = &cobra.Command{
+	Use:   "serve [flags]",
+	Short: "Start the server",
+}
+
+func init() {
+	serveCmd.Flags().IntP("port", "p", 8080, "Port to listen on")
+	serveCmd.Flags().StringVar(&cfgFile, "config", "", "Config file")
+	serveCmd.MarkFlagRequired("config")
+	rootCmd.AddCommand(serveCmd)
+}
+"#;
+        let boundaries = extract_cobra(content, "cmd/root.go");
+        assert_eq!(boundaries.len(), 2);
+        let serve = &boundaries[1];
+        assert_eq!(serve.command, "app serve");
+        let port = option(serve, "--port");
+        assert_eq!(port.short_name.as_deref(), Some("-p"));
+        assert_eq!(port.default_value.as_deref(), Some("8080"));
+        assert_eq!(port.value_type, "int");
+        assert!(option(serve, "--config").required);
+    }
+
+    #[test]
+    fn test_extract_commander_and_yargs() {
+        let commander = r#"
+const { program } = require('commander');
+program
+  .name('deploy')
+  .option('-d, --debug', 'output extra debugging');
+program
+  .command('push <target>')
+  .description('Push a release')
+  .requiredOption('-t, --tag <tag>', 'release tag')
+  .option('--retries <n>', 'retry count', '3');
+"#;
+        let boundaries = extract_commander(commander, "cli.js", "fallback");
+        assert_eq!(boundaries[0].command, "deploy");
+        assert_eq!(option(&boundaries[0], "--debug").value_type, "boolean");
+        let push = &boundaries[1];
+        assert_eq!(push.command, "deploy push");
+        assert_eq!(push.arguments[0].name, "target");
+        assert!(option(push, "--tag").required);
+        assert_eq!(option(push, "--retries").default_value.as_deref(), Some("3"));
+
+        let yargs = r#"
+yargs(hideBin(process.argv))
+  .scriptName('srv')
+  .command('serve [port]', 'start the server', (yargs) => {
+    return yargs.positional('port', { describe: 'port to bind on', default: 5000 })
+  })
+  .option('verbose', { alias: 'v', type: 'boolean', description: 'Run with verbose logging' })
+  .parse()
+"#;
+        let boundaries = extract_yargs(yargs, "srv.js", "fallback");
+        assert_eq!(boundaries.len(), 2);
+        assert_eq!(boundaries[0].command, "srv");
+        assert_eq!(option(&boundaries[0], "--verbose").short_name.as_deref(), Some("-v"));
+        let serve = &boundaries[1];
+        assert_eq!(serve.command, "srv serve");
+        assert_eq!(serve.arguments[0].default_value.as_deref(), Some("5000"));
+        assert!(!serve.arguments[0].required);
+    }
+}
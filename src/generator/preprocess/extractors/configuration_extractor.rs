@@ -0,0 +1,930 @@
+use regex::Regex;
+use std::collections::BTreeMap;
+
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::types::configuration::{
+    ConfigFile, ConfigKey, ConfigurationInventory, EnvVariable, ReadSite,
+};
+use crate::types::project_structure::ProjectStructure;
+
+/// Maximum nesting depth of keys listed for a configuration file
+const MAX_FILE_KEY_DEPTH: usize = 3;
+/// Maximum number of read sites kept per environment variable
+const MAX_READ_SITES: usize = 20;
+/// File name stems treated as configuration files
+const CONFIG_FILE_STEMS: [&str; 5] = ["config", "settings", "application", "appsettings", "configuration"];
+/// Directory names whose data files are treated as configuration files
+const CONFIG_DIRS: [&str; 4] = ["config", "configs", "conf", "settings"];
+
+/// Statically extract the configuration surface (env var reads, typed config structures and config files)
+pub fn extract(project_structure: &ProjectStructure) -> ConfigurationInventory {
+    let scanner = ConfigurationScanner::new();
+    let mut env_variables: BTreeMap<String, EnvVariable> = BTreeMap::new();
+    let mut inventory = ConfigurationInventory::default();
+
+    for file in &project_structure.files {
+        let path = normalize_path(&file.path.to_string_lossy());
+        let Some(extension) = file.extension.as_deref() else {
+            continue;
+        };
+        let is_config_file = is_config_file(&path, &project_structure.project_name);
+        if !is_config_file && !scanner.scans_extension(extension) {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(project_structure.root_path.join(&path)) else {
+            continue;
+        };
+
+        if is_config_file {
+            if let Some(config_file) = parse_config_file(&path, &content) {
+                inventory.config_files.push(config_file);
+            }
+        } else {
+            scanner.scan_source(&content, &path, extension, &mut env_variables, &mut inventory.config_keys);
+        }
+    }
+
+    // Hidden env files are skipped by the structure extractor unless `include_hidden` is set
+    if let Ok(entries) = std::fs::read_dir(&project_structure.root_path) {
+        let mut env_files: Vec<String> = entries
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| is_env_file(name))
+            .filter(|name| !inventory.config_files.iter().any(|f| &f.path == name))
+            .collect();
+        env_files.sort();
+        for name in env_files {
+            if let Ok(content) = std::fs::read_to_string(project_structure.root_path.join(&name))
+                && let Some(config_file) = parse_config_file(&name, &content)
+            {
+                inventory.config_files.push(config_file);
+            }
+        }
+    }
+
+    for config_file in inventory.config_files.iter().filter(|f| f.format == "dotenv") {
+        let content = std::fs::read_to_string(project_structure.root_path.join(&config_file.path)).unwrap_or_default();
+        declare_env_file(&config_file.path, &content, &mut env_variables);
+    }
+
+    inventory.env_variables = env_variables.into_values().collect();
+    inventory
+}
+
+struct ConfigurationScanner {
+    /// Env var reads per source file extension
+    env_read_regexes: Vec<(&'static [&'static str], Regex)>,
+    clap_env_regex: Regex,
+    string_default_regex: Regex,
+    value_default_regex: Regex,
+    js_default_regex: Regex,
+    argument_default_regex: Regex,
+    rust_struct_regex: Regex,
+    rust_field_regex: Regex,
+    serde_attribute_regex: Regex,
+    pydantic_class_regex: Regex,
+    python_field_regex: Regex,
+    env_prefix_regex: Regex,
+    spring_value_regex: Regex,
+    spring_properties_regex: Regex,
+    java_field_regex: Regex,
+}
+
+impl ConfigurationScanner {
+    fn new() -> Self {
+        Self {
+            env_read_regexes: vec![
+                (
+                    &["rs"],
+                    Regex::new(r#"\b(?:env::var(?:_os)?|dotenvy?::var|(?:option_)?env!)\s*\(\s*"(\w+)"\s*\)"#).unwrap(),
+                ),
+                (
+                    &["js", "jsx", "ts", "tsx", "mjs", "cjs", "vue", "svelte"],
+                    Regex::new(r#"\b(?:process\.env|import\.meta\.env)(?:\.(\w+)|\[\s*['"](\w+)['"]\s*\])"#).unwrap(),
+                ),
+                (
+                    &["py"],
+                    Regex::new(r#"\bos\.(?:environ\[\s*['"](\w+)['"]\s*\]|(?:environ\.get|getenv)\s*\(\s*['"](\w+)['"])"#).unwrap(),
+                ),
+                (&["java", "kt"], Regex::new(r#"\bSystem\.getenv\s*\(\s*"(\w+)"\s*\)"#).unwrap()),
+                (&["go"], Regex::new(r#"\bos\.(?:Getenv|LookupEnv)\s*\(\s*"(\w+)"\s*\)"#).unwrap()),
+                (&["cs"], Regex::new(r#"\bEnvironment\.GetEnvironmentVariable\s*\(\s*"(\w+)""#).unwrap()),
+                (
+                    &["php"],
+                    Regex::new(r#"(?:\b(?:getenv|env)\s*\(\s*['"](\w+)['"]|\$_(?:ENV|SERVER)\[\s*['"](\w+)['"]\s*\])"#).unwrap(),
+                ),
+                (&["rb"], Regex::new(r#"\bENV(?:\[\s*['"](\w+)['"]\s*\]|\.fetch\s*\(\s*['"](\w+)['"])"#).unwrap()),
+            ],
+            clap_env_regex: Regex::new(r#"#\[(?:arg|clap)\(([^\]]*\benv\s*=\s*"(\w+)"[^\]]*)\)\]"#).unwrap(),
+            string_default_regex: Regex::new(r#"^[^;]*?\.unwrap_or(?:_else)?\s*\(\s*(?:\|[^|]*\|\s*)?"([^"]*)""#).unwrap(),
+            value_default_regex: Regex::new(r"^[^;]*?\.unwrap_or\(\s*([\w.:-]+)\s*\)").unwrap(),
+            js_default_regex: Regex::new(r#"^\s*(?:\|\||\?\?)\s*([^;,)\n]+)"#).unwrap(),
+            argument_default_regex: Regex::new(r#"^\s*,\s*([^)]*?)\s*\)"#).unwrap(),
+            rust_struct_regex: Regex::new(r"^\s*(?:pub(?:\([^)]*\))?\s+)?struct\s+(\w+)\s*\{").unwrap(),
+            rust_field_regex: Regex::new(r"^\s*(?:pub(?:\([^)]*\))?\s+)?(\w+)\s*:\s*(.+?),?\s*$").unwrap(),
+            serde_attribute_regex: Regex::new(r"^\s*#\[serde\((.*)\)\]").unwrap(),
+            pydantic_class_regex: Regex::new(r"^(\s*)class\s+(\w+)\s*\(\s*(?:[\w.]*\.)?BaseSettings\b").unwrap(),
+            python_field_regex: Regex::new(r"^\s+(\w+)\s*:\s*([^=#]+?)\s*(?:=\s*([^#]+?))?\s*(?:#.*)?$").unwrap(),
+            env_prefix_regex: Regex::new(r#"env_prefix\s*=\s*['"]([^'"]*)['"]"#).unwrap(),
+            spring_value_regex: Regex::new(r#"@Value\s*\(\s*"\$\{([^}:]+)(?::([^}]*))?\}"\s*\)"#).unwrap(),
+            spring_properties_regex: Regex::new(r#"@ConfigurationProperties\s*\(\s*(?:(?:value|prefix)\s*=\s*)?"([^"]+)""#).unwrap(),
+            java_field_regex: Regex::new(r"^\s*(?:(?:private|protected|public|final|static)\s+)*([\w.]+(?:<[^>]*>)?(?:\[\])?)\s+(\w+)\s*(?:=\s*([^;]+))?;").unwrap(),
+        }
+    }
+
+    fn scans_extension(&self, extension: &str) -> bool {
+        self.env_read_regexes
+            .iter()
+            .any(|(extensions, _)| extensions.contains(&extension))
+    }
+
+    fn scan_source(
+        &self,
+        content: &str,
+        path: &str,
+        extension: &str,
+        env_variables: &mut BTreeMap<String, EnvVariable>,
+        config_keys: &mut Vec<ConfigKey>,
+    ) {
+        for (extensions, regex) in &self.env_read_regexes {
+            if !extensions.contains(&extension) {
+                continue;
+            }
+            for (line_num, line) in content.lines().enumerate() {
+                if is_comment(line) {
+                    continue;
+                }
+                for captures in regex.captures_iter(line) {
+                    let Some(name) = captures.iter().skip(1).flatten().next() else {
+                        continue;
+                    };
+                    let whole = captures.get(0).unwrap();
+                    let read = self.analyze_read(whole.as_str(), &line[whole.end()..], line);
+                    record_env_read(env_variables, name.as_str(), read, path, line_num + 1);
+                }
+            }
+        }
+
+        match extension {
+            "rs" => {
+                self.scan_clap_env(content, path, env_variables);
+                self.scan_serde_structs(content, path, config_keys);
+            }
+            "py" => self.scan_pydantic_settings(content, path, config_keys),
+            "java" | "kt" => self.scan_spring_properties(content, path, config_keys),
+            _ => {}
+        }
+    }
+
+    /// Infer (type, default, required) of an env var read from the surrounding line
+    fn analyze_read(&self, matched: &str, rest: &str, line: &str) -> EnvRead {
+        let statement = rest.split(';').next().unwrap_or(rest);
+
+        let default_value = self
+            .string_default_regex
+            .captures(statement)
+            .or_else(|| self.value_default_regex.captures(statement))
+            .or_else(|| self.js_default_regex.captures(statement))
+            .or_else(|| {
+                // Second argument of `os.getenv("X", default)`, `env('X', default)`, `ENV.fetch("X", default)`
+                matched
+                    .ends_with(['\'', '"'])
+                    .then(|| self.argument_default_regex.captures(rest))
+                    .flatten()
+            })
+            .map(|captures| strip_quotes(captures[1].trim()))
+            .filter(|value| !value.is_empty() && value != "None" && value != "undefined");
+
+        let required = default_value.is_none()
+            && (matched.starts_with("env!")
+                || matched.starts_with("os.environ[")
+                || matched.starts_with("ENV.fetch")
+                || statement.trim_start().starts_with('?')
+                || statement.contains(".expect(")
+                || statement.contains(".unwrap()"));
+
+        EnvRead {
+            value_type: infer_type(line),
+            default_value,
+            required,
+        }
+    }
+
+    /// `#[arg(long, env = "X", default_value = "...")]` declares an env var backing a CLI option
+    fn scan_clap_env(&self, content: &str, path: &str, env_variables: &mut BTreeMap<String, EnvVariable>) {
+        for (line_num, line) in content.lines().enumerate() {
+            let Some(captures) = self.clap_env_regex.captures(line) else {
+                continue;
+            };
+            let default_value = captures[1]
+                .split(',')
+                .find_map(|part| {
+                    let (key, value) = part.split_once('=')?;
+                    key.trim().starts_with("default_value").then(|| strip_quotes(value.trim()))
+                });
+            let read = EnvRead {
+                value_type: "string".to_string(),
+                default_value,
+                required: false,
+            };
+            record_env_read(env_variables, &captures[2], read, path, line_num + 1);
+        }
+    }
+
+    /// Fields of serde-deserialized structures named like `Config`, `Settings` or `Options`
+    fn scan_serde_structs(&self, content: &str, path: &str, config_keys: &mut Vec<ConfigKey>) {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut deserialize = false;
+        let mut struct_attributes = String::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i];
+            let trimmed = line.trim();
+            i += 1;
+
+            if trimmed.starts_with("#[derive(") {
+                deserialize = trimmed.contains("Deserialize");
+                continue;
+            }
+            if let Some(captures) = self.serde_attribute_regex.captures(line) {
+                struct_attributes.push_str(&captures[1]);
+                continue;
+            }
+            if trimmed.starts_with("#[") || trimmed.starts_with("///") || trimmed.is_empty() {
+                continue;
+            }
+
+            let Some(captures) = self.rust_struct_regex.captures(line) else {
+                deserialize = false;
+                struct_attributes.clear();
+                continue;
+            };
+            let name = captures[1].to_string();
+            let is_config = deserialize && is_config_type_name(&name);
+            let struct_default = serde_option(&struct_attributes, "default").is_some();
+            let rename_all = serde_option(&struct_attributes, "rename_all");
+            deserialize = false;
+            struct_attributes.clear();
+            if !is_config {
+                continue;
+            }
+
+            let mut docs: Vec<String> = Vec::new();
+            let mut field_attributes = String::new();
+            while i < lines.len() {
+                let field_line = lines[i];
+                let field_trimmed = field_line.trim();
+                i += 1;
+
+                if field_trimmed.starts_with('}') {
+                    break;
+                }
+                if let Some(doc) = field_trimmed.strip_prefix("///") {
+                    docs.push(doc.trim().to_string());
+                    continue;
+                }
+                if let Some(captures) = self.serde_attribute_regex.captures(field_line) {
+                    field_attributes.push_str(&captures[1]);
+                    field_attributes.push(',');
+                    continue;
+                }
+                if field_trimmed.starts_with("#[") || field_trimmed.starts_with("//") || field_trimmed.is_empty() {
+                    continue;
+                }
+                let Some(field) = self.rust_field_regex.captures(field_line) else {
+                    docs.clear();
+                    field_attributes.clear();
+                    continue;
+                };
+
+                let skipped = field_attributes.contains("skip") && !field_attributes.contains("skip_serializing");
+                if !skipped {
+                    let key = serde_option(&field_attributes, "rename")
+                        .unwrap_or_else(|| rename_field(&field[1], rename_all.as_deref()));
+                    let default_value = match serde_option(&field_attributes, "default") {
+                        Some(function) if !function.is_empty() => Some(format!("{}()", function)),
+                        Some(_) => Some("Default::default()".to_string()),
+                        None if struct_default => Some(format!("{}::default()", name)),
+                        None => None,
+                    };
+                    config_keys.push(ConfigKey {
+                        key,
+                        value_type: field[2].split("//").next().unwrap_or_default().trim().trim_end_matches(',').to_string(),
+                        default_value,
+                        description: (!docs.is_empty()).then(|| docs.join(" ")),
+                        binding: format!("serde struct `{}`", name),
+                        env_name: None,
+                        source_location: ReadSite {
+                            file: path.to_string(),
+                            line_number: i,
+                        },
+                    });
+                }
+                docs.clear();
+                field_attributes.clear();
+            }
+        }
+    }
+
+    /// Fields of pydantic `BaseSettings` classes, which are read from prefixed env vars
+    fn scan_pydantic_settings(&self, content: &str, path: &str, config_keys: &mut Vec<ConfigKey>) {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let Some(captures) = self.pydantic_class_regex.captures(lines[i]) else {
+                i += 1;
+                continue;
+            };
+            let class_indent = captures[1].len();
+            let class_name = captures[2].to_string();
+            i += 1;
+
+            let mut prefix = String::new();
+            let mut fields = Vec::new();
+            while i < lines.len() {
+                let line = lines[i];
+                let indent = line.len() - line.trim_start().len();
+                if !line.trim().is_empty() && indent <= class_indent {
+                    break;
+                }
+                i += 1;
+
+                if let Some(captures) = self.env_prefix_regex.captures(line) {
+                    prefix = captures[1].to_string();
+                    continue;
+                }
+                let Some(field) = self.python_field_regex.captures(line) else {
+                    continue;
+                };
+                if field[1].starts_with('_') || &field[1] == "model_config" {
+                    continue;
+                }
+
+                let raw_default = field.get(3).map(|m| m.as_str().trim());
+                let (default_value, description) = match raw_default {
+                    Some(value) if value.starts_with("Field(") => {
+                        let arguments = value.trim_start_matches("Field(").trim_end_matches(')');
+                        let default = arguments
+                            .split(',')
+                            .next()
+                            .map(str::trim)
+                            .filter(|d| !d.is_empty() && *d != "..." && !d.contains('='))
+                            .map(strip_quotes);
+                        let description = arguments.split(',').find_map(|arg| {
+                            let (key, value) = arg.split_once('=')?;
+                            (key.trim() == "description").then(|| strip_quotes(value.trim()))
+                        });
+                        (default, description)
+                    }
+                    Some(value) => (Some(strip_quotes(value)), None),
+                    None => (None, None),
+                };
+                fields.push((field[1].to_string(), field[2].trim().to_string(), default_value, description, i));
+            }
+
+            for (name, value_type, default_value, description, line_number) in fields {
+                config_keys.push(ConfigKey {
+                    env_name: Some(format!("{}{}", prefix, name).to_uppercase()),
+                    key: name,
+                    value_type,
+                    default_value,
+                    description,
+                    binding: format!("pydantic settings `{}`", class_name),
+                    source_location: ReadSite {
+                        file: path.to_string(),
+                        line_number,
+                    },
+                });
+            }
+        }
+    }
+
+    /// Spring `@Value("${key:default}")` injections and `@ConfigurationProperties` classes
+    fn scan_spring_properties(&self, content: &str, path: &str, config_keys: &mut Vec<ConfigKey>) {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut properties_prefix: Option<(String, i32)> = None;
+        let mut depth = 0;
+
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(captures) = self.spring_value_regex.captures(line) {
+                let key = captures[1].trim().to_string();
+                // The annotated field or parameter follows the annotation, on the same or the next line
+                let value_type = std::iter::once(&line[captures.get(0).unwrap().end()..])
+                    .chain(lines.get(index + 1).copied())
+                    .find_map(|text| self.java_field_regex.captures(text.trim_end_matches([',', ')']).trim()))
+                    .map(|field| field[1].to_string())
+                    .unwrap_or_else(|| "String".to_string());
+                config_keys.push(ConfigKey {
+                    env_name: Some(spring_env_name(&key)),
+                    key,
+                    value_type,
+                    default_value: captures.get(2).map(|m| m.as_str().to_string()),
+                    description: None,
+                    binding: "Spring `@Value`".to_string(),
+                    source_location: ReadSite {
+                        file: path.to_string(),
+                        line_number: index + 1,
+                    },
+                });
+            } else if let Some(captures) = self.spring_properties_regex.captures(line) {
+                properties_prefix = Some((captures[1].to_string(), depth + 1));
+            } else if let Some((prefix, class_depth)) = &properties_prefix
+                && depth == *class_depth
+                && let Some(field) = self.java_field_regex.captures(line)
+                && !line.contains(" static ")
+            {
+                let key = format!("{}.{}", prefix, to_kebab_case(&field[2]));
+                config_keys.push(ConfigKey {
+                    env_name: Some(spring_env_name(&key)),
+                    key,
+                    value_type: field[1].to_string(),
+                    default_value: field.get(3).map(|m| strip_quotes(m.as_str().trim())),
+                    description: None,
+                    binding: format!("Spring `@ConfigurationProperties(\"{}\")`", prefix),
+                    source_location: ReadSite {
+                        file: path.to_string(),
+                        line_number: index + 1,
+                    },
+                });
+            }
+
+            depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+            if line.contains('}') && properties_prefix.as_ref().is_some_and(|(_, class_depth)| depth < *class_depth) {
+                properties_prefix = None;
+            }
+        }
+    }
+}
+
+struct EnvRead {
+    value_type: String,
+    default_value: Option<String>,
+    required: bool,
+}
+
+fn record_env_read(
+    env_variables: &mut BTreeMap<String, EnvVariable>,
+    name: &str,
+    read: EnvRead,
+    path: &str,
+    line_number: usize,
+) {
+    let variable = env_variables.entry(name.to_string()).or_insert_with(|| EnvVariable {
+        name: name.to_string(),
+        value_type: "string".to_string(),
+        default_value: None,
+        required: false,
+        read_sites: Vec::new(),
+        declared_in: Vec::new(),
+    });
+    if variable.value_type == "string" {
+        variable.value_type = read.value_type;
+    }
+    if variable.default_value.is_none() {
+        variable.default_value = read.default_value;
+    }
+    variable.required |= read.required;
+    if variable.read_sites.len() < MAX_READ_SITES {
+        variable.read_sites.push(ReadSite {
+            file: path.to_string(),
+            line_number,
+        });
+    }
+}
+
+/// Variables listed in an env file; example values serve as defaults for variables without one
+fn declare_env_file(path: &str, content: &str, env_variables: &mut BTreeMap<String, EnvVariable>) {
+    for (key, value) in key_value_lines(content) {
+        let key = key.trim_start_matches("export ").trim().to_string();
+        let variable = env_variables.entry(key.clone()).or_insert_with(|| EnvVariable {
+            name: key,
+            value_type: "string".to_string(),
+            default_value: None,
+            required: false,
+            read_sites: Vec::new(),
+            declared_in: Vec::new(),
+        });
+        if variable.default_value.is_none() && !value.is_empty() {
+            variable.default_value = Some(value);
+        }
+        if !variable.declared_in.iter().any(|p| p == path) {
+            variable.declared_in.push(path.to_string());
+        }
+    }
+}
+
+/// Parse the keys of a configuration file, `None` for unsupported or unparsable formats
+fn parse_config_file(path: &str, content: &str) -> Option<ConfigFile> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let extension = name.rsplit_once('.').map(|(_, e)| e.to_lowercase()).unwrap_or_default();
+
+    let (format, keys) = if is_env_file(name) {
+        ("dotenv", key_value_lines(content).into_iter().map(|(k, _)| k).collect())
+    } else {
+        match extension.as_str() {
+            "toml" => {
+                let value: toml::Value = toml::from_str(content).ok()?;
+                let mut keys = Vec::new();
+                flatten_toml(&value, "", 1, &mut keys);
+                ("toml", keys)
+            }
+            "json" => {
+                let value: serde_json::Value = serde_json::from_str(content).ok()?;
+                let mut keys = Vec::new();
+                flatten_json(&value, "", 1, &mut keys);
+                ("json", keys)
+            }
+            "yaml" | "yml" => ("yaml", yaml_keys(content)),
+            "properties" => ("properties", key_value_lines(content).into_iter().map(|(k, _)| k).collect()),
+            "ini" | "cfg" | "conf" => ("ini", ini_keys(content)),
+            _ => return None,
+        }
+    };
+
+    Some(ConfigFile {
+        path: path.to_string(),
+        format: format.to_string(),
+        keys,
+    })
+}
+
+fn flatten_toml(value: &toml::Value, prefix: &str, depth: usize, keys: &mut Vec<String>) {
+    let toml::Value::Table(table) = value else {
+        return;
+    };
+    for (key, child) in table {
+        let key = join_key(prefix, key);
+        if depth < MAX_FILE_KEY_DEPTH && child.is_table() {
+            flatten_toml(child, &key, depth + 1, keys);
+        } else {
+            keys.push(key);
+        }
+    }
+}
+
+fn flatten_json(value: &serde_json::Value, prefix: &str, depth: usize, keys: &mut Vec<String>) {
+    let serde_json::Value::Object(object) = value else {
+        return;
+    };
+    for (key, child) in object {
+        let key = join_key(prefix, key);
+        if depth < MAX_FILE_KEY_DEPTH && child.is_object() {
+            flatten_json(child, &key, depth + 1, keys);
+        } else {
+            keys.push(key);
+        }
+    }
+}
+
+/// Mapping keys of a YAML document by indentation, without list items and multi-document support
+fn yaml_keys(content: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    // (indent, key, has nested keys) of the enclosing mappings
+    let mut parents: Vec<(usize, String, bool)> = Vec::new();
+    // Keys holding lists or nothing are listed themselves
+    let close = |parent: (usize, String, bool), keys: &mut Vec<String>| {
+        if !parent.2 {
+            keys.push(parent.1);
+        }
+    };
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('-') || trimmed == "---" {
+            continue;
+        }
+        let Some((key, value)) = trimmed.split_once(':') else {
+            continue;
+        };
+        let key = strip_quotes(key.trim());
+        if key.is_empty() || key.contains(' ') {
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+        while parents.last().is_some_and(|(parent_indent, _, _)| *parent_indent >= indent) {
+            close(parents.pop().unwrap(), &mut keys);
+        }
+        let prefix = parents.last().map(|(_, k, _)| k.as_str()).unwrap_or_default();
+        let full_key = join_key(prefix, &key);
+        if let Some(parent) = parents.last_mut() {
+            parent.2 = true;
+        }
+
+        let value = value.trim();
+        if (value.is_empty() || value.starts_with('#')) && parents.len() + 1 < MAX_FILE_KEY_DEPTH {
+            parents.push((indent, full_key, false));
+        } else {
+            keys.push(full_key);
+        }
+    }
+    while let Some(parent) = parents.pop() {
+        close(parent, &mut keys);
+    }
+    keys
+}
+
+fn ini_keys(content: &str) -> Vec<String> {
+    let mut section = String::new();
+    let mut keys = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim().to_string();
+        } else if let Some((key, _)) = trimmed.split_once('=')
+            && !trimmed.starts_with(['#', ';'])
+        {
+            keys.push(join_key(&section, key.trim()));
+        }
+    }
+    keys
+}
+
+/// `KEY=value` lines of env and properties files
+fn key_value_lines(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(['#', '!']))
+        .filter_map(|line| {
+            let (key, value) = line.split_once(['=', ':'])?;
+            let value = value.split(" #").next().unwrap_or_default().trim();
+            Some((key.trim().to_string(), strip_quotes(value)))
+        })
+        .filter(|(key, _)| !key.is_empty() && (!key.contains(' ') || key.starts_with("export ")))
+        .collect()
+}
+
+fn is_env_file(name: &str) -> bool {
+    name == ".env" || name.starts_with(".env.")
+}
+
+fn is_config_file(path: &str, project_name: &str) -> bool {
+    let mut segments: Vec<&str> = path.split('/').collect();
+    let name = segments.pop().unwrap_or_default().to_lowercase();
+    if is_env_file(&name) {
+        return true;
+    }
+    let Some((stem, extension)) = name.split_once('.') else {
+        return false;
+    };
+    let extension = extension.rsplit('.').next().unwrap_or(extension);
+    if !["toml", "json", "yaml", "yml", "properties", "ini", "conf", "cfg"].contains(&extension) {
+        return false;
+    }
+
+    CONFIG_FILE_STEMS.iter().any(|s| stem.starts_with(s))
+        || stem == project_name.to_lowercase()
+        || segments.last().is_some_and(|dir| CONFIG_DIRS.contains(&dir.to_lowercase().as_str()))
+}
+
+fn is_config_type_name(name: &str) -> bool {
+    ["Config", "Configuration", "Settings", "Options", "Conf"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+fn is_comment(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with('*')
+}
+
+/// Type the value is converted to, judged from the conversion applied on the same line
+fn infer_type(line: &str) -> String {
+    let parse_regex_type = line
+        .split_once("parse::<")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(value_type, _)| value_type.to_string());
+    if let Some(value_type) = parse_regex_type {
+        return value_type;
+    }
+
+    let checks: [(&[&str], &str); 4] = [
+        (&["int(", "parseInt(", "Integer.parseInt(", "Long.parseLong(", "strconv.Atoi(", "int.Parse("], "integer"),
+        (&["float(", "parseFloat(", "Double.parseDouble(", "strconv.ParseFloat("], "float"),
+        (&["Number("], "number"),
+        (&["== \"true\"", "=== \"true\"", "=== 'true'", "== 'true'", "Boolean.parseBoolean(", "strconv.ParseBool(", "bool.Parse("], "boolean"),
+    ];
+    checks
+        .iter()
+        .find(|(needles, _)| needles.iter().any(|needle| line.contains(needle)))
+        .map(|(_, value_type)| value_type.to_string())
+        .unwrap_or_else(|| "string".to_string())
+}
+
+/// Value of `name = "value"` (or bare `name`, as an empty string) in serde attribute arguments
+fn serde_option(attributes: &str, name: &str) -> Option<String> {
+    attributes.split(',').find_map(|part| {
+        let part = part.trim();
+        match part.split_once('=') {
+            Some((key, value)) if key.trim() == name => Some(strip_quotes(value.trim())),
+            None if part == name => Some(String::new()),
+            _ => None,
+        }
+    })
+}
+
+fn rename_field(name: &str, rename_all: Option<&str>) -> String {
+    match rename_all {
+        Some("kebab-case") => name.replace('_', "-"),
+        Some("SCREAMING_SNAKE_CASE") => name.to_uppercase(),
+        Some("camelCase") => {
+            let mut result = String::new();
+            let mut upper = false;
+            for c in name.chars() {
+                if c == '_' {
+                    upper = true;
+                } else if upper {
+                    result.extend(c.to_uppercase());
+                    upper = false;
+                } else {
+                    result.push(c);
+                }
+            }
+            result
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// Spring relaxed binding: `app.max-size` can be set through `APP_MAXSIZE`
+fn spring_env_name(key: &str) -> String {
+    key.replace('-', "").replace('.', "_").to_uppercase()
+}
+
+fn to_kebab_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            result.push('-');
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+fn strip_quotes(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\'', '`'] {
+        if let Some(inner) = value.strip_prefix(quote).and_then(|v| v.strip_suffix(quote)) {
+            return inner.to_string();
+        }
+    }
+    value.trim_end_matches(".to_string()").trim_end_matches(".into()").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(files: &[(&str, &str)]) -> (BTreeMap<String, EnvVariable>, Vec<ConfigKey>) {
+        let scanner = ConfigurationScanner::new();
+        let mut env_variables = BTreeMap::new();
+        let mut config_keys = Vec::new();
+        for (path, content) in files {
+            let extension = path.rsplit('.').next().unwrap();
+            scanner.scan_source(content, path, extension, &mut env_variables, &mut config_keys);
+        }
+        (env_variables, config_keys)
+    }
+
+    #[test]
+    fn test_env_reads_across_languages() {
+        let (env, _) = scan(&[
+            (
+                "src/main.rs",
+                r#"
+let port: u16 = std::env::var("PORT").unwrap_or("8080".to_string()).parse::<u16>()?;
+let key = env::var("API_KEY").expect("API_KEY must be set");
+// env::var("COMMENTED_OUT")
+#[arg(long, env = "LITHO_MODEL", default_value = "gpt-4o")]
+"#,
+            ),
+            ("web/client.ts", "const url = process.env.API_URL ?? 'http://localhost';\nconst port = process.env['PORT'];"),
+            ("app/settings.py", "DEBUG = os.getenv('DEBUG', 'false') == 'true'\nSECRET = os.environ['SECRET_KEY']"),
+            ("cmd/main.go", "region := os.Getenv(\"AWS_REGION\")"),
+        ]);
+
+        let port = &env["PORT"];
+        assert_eq!(port.value_type, "u16");
+        assert_eq!(port.default_value.as_deref(), Some("8080"));
+        assert_eq!(port.read_sites.len(), 2);
+        assert_eq!(port.read_sites[1].to_string(), "web/client.ts:2");
+        assert!(env["API_KEY"].required);
+        assert!(!env.contains_key("COMMENTED_OUT"));
+        assert_eq!(env["LITHO_MODEL"].default_value.as_deref(), Some("gpt-4o"));
+        assert_eq!(env["API_URL"].default_value.as_deref(), Some("http://localhost"));
+        assert_eq!(env["DEBUG"].default_value.as_deref(), Some("false"));
+        assert!(env["SECRET_KEY"].required);
+        assert!(!env["AWS_REGION"].required);
+    }
+
+    #[test]
+    fn test_typed_config_structures() {
+        let (_, keys) = scan(&[
+            (
+                "src/config.rs",
+                r#"
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ServerConfig {
+    /// Listen address
+    pub bind_address: String,
+    #[serde(default = "default_workers")]
+    pub worker_count: usize, // threads
+    #[serde(skip)]
+    pub runtime: Handle,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserResponse {
+    pub name: String,
+}
+"#,
+            ),
+            (
+                "app/settings.py",
+                r#"
+class Settings(BaseSettings):
+    model_config = SettingsConfigDict(env_prefix="APP_")
+
+    database_url: str
+    pool_size: int = Field(5, description="Connections")
+"#,
+            ),
+            (
+                "src/main/java/Mailer.java",
+                r#"
+@ConfigurationProperties(prefix = "mail")
+public class MailProperties {
+    private String smtpHost = "localhost";
+    private static final int LIMIT = 3;
+}
+
+public class Mailer {
+    @Value("${mail.retries:3}")
+    private int retries;
+}
+"#,
+            ),
+        ]);
+
+        let key = |name: &str| keys.iter().find(|k| k.key == name).unwrap_or_else(|| panic!("missing {}", name));
+        assert_eq!(keys.len(), 6, "{:#?}", keys);
+
+        let bind = key("bind-address");
+        assert_eq!(bind.description.as_deref(), Some("Listen address"));
+        assert_eq!(bind.binding, "serde struct `ServerConfig`");
+        let workers = key("worker-count");
+        assert_eq!(workers.value_type, "usize");
+        assert_eq!(workers.default_value.as_deref(), Some("default_workers()"));
+        assert_eq!(workers.source_location.line_number, 8);
+
+        assert_eq!(key("database_url").env_name.as_deref(), Some("APP_DATABASE_URL"));
+        let pool = key("pool_size");
+        assert_eq!(pool.default_value.as_deref(), Some("5"));
+        assert_eq!(pool.description.as_deref(), Some("Connections"));
+
+        let smtp = key("mail.smtp-host");
+        assert_eq!(smtp.default_value.as_deref(), Some("localhost"));
+        assert_eq!(smtp.env_name.as_deref(), Some("MAIL_SMTPHOST"));
+        let retries = key("mail.retries");
+        assert_eq!(retries.value_type, "int");
+        assert_eq!(retries.default_value.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_config_files() {
+        assert!(is_config_file("config/production.yaml", "demo"));
+        assert!(is_config_file("appsettings.Development.json", "demo"));
+        assert!(is_config_file("demo.toml", "demo"));
+        assert!(!is_config_file("tsconfig.json", "demo"));
+        assert!(!is_config_file("Cargo.toml", "demo"));
+
+        let yaml = parse_config_file(
+            "config/app.yaml",
+            "server:\n  port: 8080\n  tls:\n    cert: a.pem\n# comment\nlog_level: info\nitems:\n  - a\n",
+        )
+        .unwrap();
+        assert_eq!(yaml.keys, vec!["server.port", "server.tls.cert", "log_level", "items"]);
+
+        let toml = parse_config_file("settings.toml", "name = \"x\"\n[llm]\nmodel = \"m\"\n").unwrap();
+        assert_eq!(toml.keys, vec!["llm.model", "name"]);
+
+        let mut env = BTreeMap::new();
+        declare_env_file(".env.example", "# comment\nexport DATABASE_URL=postgres://localhost\nEMPTY=\n", &mut env);
+        assert_eq!(env["DATABASE_URL"].default_value.as_deref(), Some("postgres://localhost"));
+        assert_eq!(env["DATABASE_URL"].declared_in, vec![".env.example"]);
+        assert!(env["EMPTY"].default_value.is_none());
+    }
+}
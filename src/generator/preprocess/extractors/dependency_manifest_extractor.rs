@@ -0,0 +1,903 @@
+use anyhow::Result;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::LazyLock;
+use walkdir::WalkDir;
+
+use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::dependency_inventory::{
+    DependencyInventory, Ecosystem, ExternalDependency, ImportSite,
+};
+use crate::types::project_structure::ProjectStructure;
+
+/// Maximum number of import sites recorded per dependency
+const MAX_IMPORT_SITES: usize = 50;
+
+static POM_DEPENDENCY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<dependency>(.*?)</dependency>").unwrap());
+static POM_PROPERTIES_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<properties>(.*?)</properties>").unwrap());
+static POM_PROPERTY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<([\w.\-]+)>([^<]*)</[\w.\-]+>").unwrap());
+static GRADLE_DEPENDENCY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^\s*(\w+)\s*\(?\s*["']([^"':\s]+):([^"':\s]+)(?::([^"'\s]+))?["']"#).unwrap()
+});
+static CSPROJ_PACKAGE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?s)<PackageReference\s+Include="([^"]+)"([^>]*?)(?:/>|>(.*?)</PackageReference>)"#).unwrap()
+});
+static CSPROJ_VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"Version="([^"]+)""#).unwrap());
+static SWIFT_PACKAGE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\.package\(\s*(?:name:\s*"[^"]*"\s*,\s*)?url:\s*"([^"]+)"\s*,?([^\n]*)"#).unwrap()
+});
+static SWIFT_VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""([^"]+)""#).unwrap());
+
+/// Dependency declared by a single manifest, before merging
+struct DeclaredDependency {
+    name: String,
+    version: Option<String>,
+    ecosystem: Ecosystem,
+    is_dev: bool,
+}
+
+impl DeclaredDependency {
+    fn new(name: &str, version: Option<String>, ecosystem: Ecosystem, is_dev: bool) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            version: version.map(|v| v.trim().to_string()).filter(|v| !v.is_empty()),
+            ecosystem,
+            is_dev,
+        }
+    }
+}
+
+/// Parse package manifests and lock files of the project into a normalized dependency inventory,
+/// and link each dependency to the source files importing it
+pub fn extract(
+    context: &GeneratorContext,
+    project_structure: &ProjectStructure,
+) -> Result<DependencyInventory> {
+    let config = &context.config;
+    let root = &config.project_path;
+
+    let mut manifests = Vec::new();
+    let mut declared: Vec<(String, DeclaredDependency)> = Vec::new();
+    // Locked versions keyed by lock file directory
+    let mut locks: HashMap<String, HashMap<(Ecosystem, String), String>> = HashMap::new();
+
+    // Directories are filtered like in the structure extractor. Files are not: lock files and requirements files
+    // are excluded from the source analysis by default, yet they are what the inventory is built from
+    let walker = WalkDir::new(root)
+        .max_depth(config.max_depth.into())
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            (!hidden || config.include_hidden) && !context.ignore_rules.is_ignored(entry.path(), true)
+        });
+
+    for entry in walker.filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative = relative_path(root, path);
+        let directory = parent_of(&relative);
+
+        let Some(kind) = ManifestKind::detect(&file_name) else {
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+
+        match kind {
+            ManifestKind::Manifest(parse) => {
+                let dependencies = parse(&content, &file_name);
+                if !dependencies.is_empty() {
+                    manifests.push(relative.clone());
+                }
+                declared.extend(dependencies.into_iter().map(|dep| (relative.clone(), dep)));
+            }
+            ManifestKind::Lock(ecosystem, parse) => {
+                let versions = parse(&content);
+                if !versions.is_empty() {
+                    manifests.push(relative.clone());
+                }
+                let lock = locks.entry(directory).or_default();
+                for (name, version) in versions {
+                    lock.entry((ecosystem, name)).or_insert(version);
+                }
+            }
+        }
+    }
+
+    let mut dependencies = merge_declarations(declared, &locks);
+    link_import_sites(&mut dependencies, root, project_structure);
+
+    manifests.sort();
+    dependencies.sort_by(|a, b| (a.ecosystem, a.is_dev, &a.name).cmp(&(b.ecosystem, b.is_dev, &b.name)));
+
+    Ok(DependencyInventory {
+        manifests,
+        dependencies,
+    })
+}
+
+type ManifestParser = fn(&str, &str) -> Vec<DeclaredDependency>;
+type LockParser = fn(&str) -> Vec<(String, String)>;
+
+enum ManifestKind {
+    Manifest(ManifestParser),
+    Lock(Ecosystem, LockParser),
+}
+
+impl ManifestKind {
+    fn detect(file_name: &str) -> Option<Self> {
+        let lower = file_name.to_lowercase();
+        match file_name {
+            "Cargo.toml" => Some(Self::Manifest(parse_cargo_toml)),
+            "Cargo.lock" => Some(Self::Lock(Ecosystem::Cargo, parse_toml_lock)),
+            "package.json" => Some(Self::Manifest(parse_package_json)),
+            "package-lock.json" => Some(Self::Lock(Ecosystem::Npm, parse_package_lock)),
+            "yarn.lock" => Some(Self::Lock(Ecosystem::Npm, parse_yarn_lock)),
+            "pnpm-lock.yaml" => Some(Self::Lock(Ecosystem::Npm, parse_pnpm_lock)),
+            "pyproject.toml" => Some(Self::Manifest(parse_pyproject)),
+            "poetry.lock" | "uv.lock" => Some(Self::Lock(Ecosystem::PyPI, parse_toml_lock)),
+            "pom.xml" => Some(Self::Manifest(parse_pom)),
+            "build.gradle" | "build.gradle.kts" => Some(Self::Manifest(parse_gradle)),
+            "go.mod" => Some(Self::Manifest(parse_go_mod)),
+            "Package.swift" => Some(Self::Manifest(parse_package_swift)),
+            _ if lower.starts_with("requirements") && lower.ends_with(".txt") => {
+                Some(Self::Manifest(parse_requirements))
+            }
+            _ if lower.ends_with(".csproj") => Some(Self::Manifest(parse_csproj)),
+            _ => None,
+        }
+    }
+}
+
+/// Merge declarations of the same package across manifests and attach locked versions
+fn merge_declarations(
+    declared: Vec<(String, DeclaredDependency)>,
+    locks: &HashMap<String, HashMap<(Ecosystem, String), String>>,
+) -> Vec<ExternalDependency> {
+    let mut merged: Vec<ExternalDependency> = Vec::new();
+    let mut index: HashMap<(Ecosystem, String), usize> = HashMap::new();
+
+    for (manifest, dep) in declared {
+        let key = (dep.ecosystem, dep.name.clone());
+        let resolved_version = find_locked_version(locks, &manifest, &key);
+
+        if let Some(&i) = index.get(&key) {
+            let existing = &mut merged[i];
+            if existing.version.is_none() {
+                existing.version = dep.version;
+            }
+            if existing.resolved_version.is_none() {
+                existing.resolved_version = resolved_version;
+            }
+            // Production use anywhere wins over development use
+            existing.is_dev &= dep.is_dev;
+            if !existing.declared_in.contains(&manifest) {
+                existing.declared_in.push(manifest);
+            }
+            continue;
+        }
+
+        index.insert(key, merged.len());
+        merged.push(ExternalDependency {
+            name: dep.name,
+            version: dep.version,
+            resolved_version,
+            ecosystem: dep.ecosystem,
+            is_dev: dep.is_dev,
+            declared_in: vec![manifest],
+            import_sites: Vec::new(),
+        });
+    }
+
+    merged
+}
+
+/// Look up the lock file next to the manifest, then in parent directories (workspace roots)
+fn find_locked_version(
+    locks: &HashMap<String, HashMap<(Ecosystem, String), String>>,
+    manifest: &str,
+    key: &(Ecosystem, String),
+) -> Option<String> {
+    let mut directory = parent_of(manifest);
+    loop {
+        if let Some(version) = locks.get(&directory).and_then(|lock| lock.get(key)) {
+            return Some(version.clone());
+        }
+        if directory.is_empty() {
+            return None;
+        }
+        directory = parent_of(&directory);
+    }
+}
+
+/// Record where each dependency is imported in project source files
+fn link_import_sites(
+    dependencies: &mut [ExternalDependency],
+    root: &Path,
+    project_structure: &ProjectStructure,
+) {
+    if dependencies.is_empty() {
+        return;
+    }
+
+    let language_processor = LanguageProcessorManager::new();
+
+    for file in &project_structure.files {
+        let Some(ecosystem) = file
+            .extension
+            .as_deref()
+            .and_then(|ext| Ecosystem::for_source_extension(&ext.to_lowercase()))
+        else {
+            continue;
+        };
+        if !dependencies.iter().any(|dep| dep.ecosystem == ecosystem) {
+            continue;
+        }
+
+        let full_path = root.join(&file.path);
+        let Ok(content) = std::fs::read_to_string(&full_path) else {
+            continue;
+        };
+        let relative = file.path.to_string_lossy().replace('\\', "/");
+
+        for (specifier, line_number) in
+            language_processor.extract_import_specifiers(&full_path, &content)
+        {
+            let specifier = specifier.trim().trim_end_matches(',');
+            // Prefer the most specific package name, e.g. `@nestjs/core` over `@nestjs`
+            let matched = dependencies
+                .iter_mut()
+                .filter(|dep| dep.ecosystem == ecosystem && dep.matches_import(specifier))
+                .max_by_key(|dep| dep.name.len());
+
+            if let Some(dep) = matched
+                && dep.import_sites.len() < MAX_IMPORT_SITES
+            {
+                dep.import_sites.push(ImportSite {
+                    file: relative.clone(),
+                    line_number,
+                });
+            }
+        }
+    }
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn parent_of(path: &str) -> String {
+    match path.rfind('/') {
+        Some(pos) => path[..pos].to_string(),
+        None => String::new(),
+    }
+}
+
+// =========================== Rust ===========================
+
+fn parse_cargo_toml(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+
+    let mut dependencies = Vec::new();
+    let mut collect_sections = |table: &toml::Table| {
+        for (section, is_dev) in [
+            ("dependencies", false),
+            ("dev-dependencies", true),
+            ("build-dependencies", true),
+        ] {
+            if let Some(deps) = table.get(section).and_then(|v| v.as_table()) {
+                dependencies.extend(deps.iter().filter_map(|(name, spec)| cargo_dependency(name, spec, is_dev)));
+            }
+        }
+    };
+
+    collect_sections(&manifest);
+    if let Some(workspace) = manifest.get("workspace").and_then(|v| v.as_table()) {
+        collect_sections(workspace);
+    }
+    if let Some(targets) = manifest.get("target").and_then(|v| v.as_table()) {
+        for target in targets.values().filter_map(|v| v.as_table()) {
+            collect_sections(target);
+        }
+    }
+
+    dependencies
+}
+
+fn cargo_dependency(name: &str, spec: &toml::Value, is_dev: bool) -> Option<DeclaredDependency> {
+    match spec {
+        toml::Value::String(version) => Some(DeclaredDependency::new(
+            name,
+            Some(version.clone()),
+            Ecosystem::Cargo,
+            is_dev,
+        )),
+        toml::Value::Table(table) => {
+            // Path dependencies are crates of this project, not third-party packages
+            if table.contains_key("path") {
+                return None;
+            }
+            let package = table.get("package").and_then(|v| v.as_str()).unwrap_or(name);
+            let version = table
+                .get("version")
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string())
+                .or_else(|| table.get("git").and_then(|v| v.as_str()).map(|git| format!("git: {}", git)));
+            Some(DeclaredDependency::new(package, version, Ecosystem::Cargo, is_dev))
+        }
+        _ => None,
+    }
+}
+
+/// `[[package]]` name/version pairs used by Cargo.lock, poetry.lock and uv.lock
+fn parse_toml_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+
+    lock.get("package")
+        .and_then(|v| v.as_array())
+        .map(|packages| {
+            packages
+                .iter()
+                .filter_map(|package| {
+                    let name = package.get("name")?.as_str()?;
+                    let version = package.get("version")?.as_str()?;
+                    Some((name.to_string(), version.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// =========================== JavaScript ===========================
+
+fn parse_package_json(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    let Ok(package) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    let mut dependencies = Vec::new();
+    for (section, is_dev) in [
+        ("dependencies", false),
+        ("peerDependencies", false),
+        ("optionalDependencies", false),
+        ("devDependencies", true),
+    ] {
+        let Some(deps) = package.get(section).and_then(|v| v.as_object()) else {
+            continue;
+        };
+        for (name, version) in deps {
+            let version = version.as_str().unwrap_or_default();
+            // Workspace and local references point to packages of this project
+            if ["workspace:", "file:", "link:", "portal:"]
+                .iter()
+                .any(|protocol| version.starts_with(protocol))
+            {
+                continue;
+            }
+            dependencies.push(DeclaredDependency::new(
+                name,
+                Some(version.to_string()),
+                Ecosystem::Npm,
+                is_dev,
+            ));
+        }
+    }
+
+    dependencies
+}
+
+fn parse_package_lock(content: &str) -> Vec<(String, String)> {
+    let Ok(lock) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    let mut versions = Vec::new();
+
+    // lockfileVersion 2/3
+    if let Some(packages) = lock.get("packages").and_then(|v| v.as_object()) {
+        for (key, package) in packages {
+            let Some(name) = key.rsplit("node_modules/").next().filter(|_| key.contains("node_modules/")) else {
+                continue;
+            };
+            if let Some(version) = package.get("version").and_then(|v| v.as_str()) {
+                versions.push((name.to_string(), version.to_string()));
+            }
+        }
+    }
+
+    // lockfileVersion 1
+    if let Some(deps) = lock.get("dependencies").and_then(|v| v.as_object()) {
+        for (name, package) in deps {
+            if let Some(version) = package.get("version").and_then(|v| v.as_str()) {
+                versions.push((name.clone(), version.to_string()));
+            }
+        }
+    }
+
+    versions
+}
+
+fn parse_yarn_lock(content: &str) -> Vec<(String, String)> {
+    let mut versions = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        if !line.starts_with(' ') && line.ends_with(':') {
+            // e.g. `"@babel/core@^7.0.0", "@babel/core@^7.1.0":`
+            current = line
+                .trim_end_matches(':')
+                .split(',')
+                .filter_map(|spec| split_scoped(spec.trim().trim_matches('"'), '@').map(|(name, _)| name))
+                .collect();
+            current.dedup();
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if let Some(version) = trimmed
+            .strip_prefix("version ")
+            .or_else(|| trimmed.strip_prefix("version: "))
+        {
+            let version = version.trim().trim_matches('"');
+            for name in current.drain(..) {
+                versions.push((name, version.to_string()));
+            }
+        }
+    }
+
+    versions
+}
+
+/// Locked versions of the `packages` section, keyed `/name@version` (v6), `name@version` (v9) or `/name/version` (v5)
+fn parse_pnpm_lock(content: &str) -> Vec<(String, String)> {
+    let mut versions = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        if !line.starts_with(' ') {
+            in_packages = line.trim_end() == "packages:";
+            continue;
+        }
+        // Package keys are the entries indented once, their fields being indented further
+        if !in_packages || line.starts_with("   ") || !line.trim_end().ends_with(':') {
+            continue;
+        }
+
+        let key = line.trim().trim_end_matches(':').trim_matches(|c| c == '\'' || c == '"');
+        let key = key.strip_prefix('/').unwrap_or(key);
+        // Peer dependency suffix, e.g. `react-dom@18.2.0(react@18.2.0)`
+        let key = key.split('(').next().unwrap_or(key);
+        let Some((name, version)) = split_scoped(key, '@').or_else(|| split_scoped(key, '/')) else {
+            continue;
+        };
+        if version.starts_with(|c: char| c.is_ascii_digit()) {
+            versions.push((name, version.to_string()));
+        }
+    }
+
+    versions
+}
+
+/// Split `name<separator>rest` at the last separator, the leading `@` of a scoped package being part of the name
+fn split_scoped(spec: &str, separator: char) -> Option<(String, &str)> {
+    let (scope, unscoped) = match spec.strip_prefix('@') {
+        Some(unscoped) => ("@", unscoped),
+        None => ("", spec),
+    };
+    let (name, rest) = if separator == '@' {
+        unscoped.split_once(separator)?
+    } else {
+        unscoped.rsplit_once(separator)?
+    };
+    (!name.is_empty()).then(|| (format!("{}{}", scope, name), rest))
+}
+
+// =========================== Python ===========================
+
+fn parse_pyproject(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    let Ok(pyproject) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+
+    let mut dependencies = Vec::new();
+    let mut push_requirements = |requirements: Option<&toml::Value>, is_dev: bool| {
+        if let Some(requirements) = requirements.and_then(|v| v.as_array()) {
+            dependencies.extend(
+                requirements
+                    .iter()
+                    .filter_map(|r| r.as_str())
+                    .filter_map(|r| parse_pep508(r, is_dev)),
+            );
+        }
+    };
+
+    // PEP 621
+    if let Some(project) = pyproject.get("project") {
+        push_requirements(project.get("dependencies"), false);
+        if let Some(groups) = project.get("optional-dependencies").and_then(|v| v.as_table()) {
+            for (group, requirements) in groups {
+                push_requirements(Some(requirements), is_dev_group(group));
+            }
+        }
+    }
+
+    // PEP 735
+    if let Some(groups) = pyproject.get("dependency-groups").and_then(|v| v.as_table()) {
+        for requirements in groups.values() {
+            push_requirements(Some(requirements), true);
+        }
+    }
+
+    // Poetry
+    if let Some(poetry) = pyproject
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .and_then(|v| v.as_table())
+    {
+        let mut sections: Vec<(&toml::Value, bool)> = Vec::new();
+        if let Some(deps) = poetry.get("dependencies") {
+            sections.push((deps, false));
+        }
+        if let Some(deps) = poetry.get("dev-dependencies") {
+            sections.push((deps, true));
+        }
+        if let Some(groups) = poetry.get("group").and_then(|v| v.as_table()) {
+            for (group, table) in groups {
+                if let Some(deps) = table.get("dependencies") {
+                    sections.push((deps, group != "main"));
+                }
+            }
+        }
+
+        for (deps, is_dev) in sections {
+            let Some(deps) = deps.as_table() else {
+                continue;
+            };
+            for (name, spec) in deps {
+                if name == "python" {
+                    continue;
+                }
+                let version = match spec {
+                    toml::Value::String(version) => Some(version.clone()),
+                    toml::Value::Table(table) => {
+                        if table.contains_key("path") {
+                            continue;
+                        }
+                        table.get("version").and_then(|v| v.as_str()).map(|v| v.to_string())
+                    }
+                    _ => None,
+                };
+                dependencies.push(DeclaredDependency::new(name, version, Ecosystem::PyPI, is_dev));
+            }
+        }
+    }
+
+    dependencies
+}
+
+fn parse_requirements(content: &str, file_name: &str) -> Vec<DeclaredDependency> {
+    let is_dev = is_dev_group(&file_name.to_lowercase());
+
+    content
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or(line).trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('-'))
+        .filter_map(|line| parse_pep508(line, is_dev))
+        .collect()
+}
+
+/// Parse a PEP 508 requirement like `requests[socks]>=2.31; python_version > "3.8"`
+fn parse_pep508(requirement: &str, is_dev: bool) -> Option<DeclaredDependency> {
+    let requirement = requirement.split(';').next()?.trim();
+    let name_end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    let rest = requirement[name_end..].trim();
+    // Skip extras like `[socks]`
+    let rest = match rest.strip_prefix('[') {
+        Some(after) => after.split_once(']').map(|(_, v)| v).unwrap_or("").trim(),
+        None => rest,
+    };
+    let version = rest.strip_prefix('@').map(|url| url.trim()).unwrap_or(rest);
+
+    Some(DeclaredDependency::new(
+        name,
+        Some(version.to_string()),
+        Ecosystem::PyPI,
+        is_dev,
+    ))
+}
+
+fn is_dev_group(name: &str) -> bool {
+    ["dev", "test", "lint", "doc", "typing"]
+        .iter()
+        .any(|keyword| name.contains(keyword))
+}
+
+// =========================== JVM ===========================
+
+fn parse_pom(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    let properties: HashMap<String, String> = POM_PROPERTIES_REGEX
+        .captures(content)
+        .and_then(|c| c.get(1))
+        .map(|block| {
+            POM_PROPERTY_REGEX
+                .captures_iter(block.as_str())
+                .map(|c| (c[1].to_string(), c[2].trim().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    POM_DEPENDENCY_REGEX
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let block = captures.get(1)?.as_str();
+            let group_id = xml_tag(block, "groupId")?;
+            let artifact_id = xml_tag(block, "artifactId")?;
+            let version = xml_tag(block, "version").map(|version| {
+                // Resolve `${property}` placeholders from <properties>
+                version
+                    .strip_prefix("${")
+                    .and_then(|v| v.strip_suffix('}'))
+                    .and_then(|key| properties.get(key).cloned())
+                    .unwrap_or(version)
+            });
+            let is_dev = matches!(xml_tag(block, "scope").as_deref(), Some("test" | "provided"));
+
+            Some(DeclaredDependency::new(
+                &format!("{}:{}", group_id, artifact_id),
+                version,
+                Ecosystem::Maven,
+                is_dev,
+            ))
+        })
+        .collect()
+}
+
+fn xml_tag(block: &str, tag: &str) -> Option<String> {
+    let start = block.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = block[start..].find(&format!("</{}>", tag))? + start;
+    Some(block[start..end].trim().to_string())
+}
+
+fn parse_gradle(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    GRADLE_DEPENDENCY_REGEX
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let configuration = &captures[1];
+            let is_dev = configuration.starts_with("test")
+                || configuration.starts_with("androidTest")
+                || configuration.starts_with("debug")
+                || configuration == "classpath";
+            let known = is_dev
+                || [
+                    "implementation",
+                    "api",
+                    "compileOnly",
+                    "runtimeOnly",
+                    "annotationProcessor",
+                    "kapt",
+                    "ksp",
+                    "compile",
+                    "runtime",
+                ]
+                .contains(&configuration);
+            if !known {
+                return None;
+            }
+
+            Some(DeclaredDependency::new(
+                &format!("{}:{}", &captures[2], &captures[3]),
+                captures.get(4).map(|v| v.as_str().to_string()),
+                Ecosystem::Maven,
+                is_dev,
+            ))
+        })
+        .collect()
+}
+
+// =========================== Go ===========================
+
+fn parse_go_mod(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    let mut dependencies = Vec::new();
+    let mut in_require_block = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        let requirement = if in_require_block {
+            if trimmed == ")" {
+                in_require_block = false;
+                continue;
+            }
+            trimmed
+        } else if trimmed == "require (" {
+            in_require_block = true;
+            continue;
+        } else if let Some(requirement) = trimmed.strip_prefix("require ") {
+            requirement
+        } else {
+            continue;
+        };
+
+        // Only direct dependencies belong to the inventory
+        if requirement.is_empty() || requirement.starts_with("//") || requirement.contains("// indirect") {
+            continue;
+        }
+
+        let mut parts = requirement.split_whitespace();
+        if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+            dependencies.push(DeclaredDependency::new(
+                module,
+                Some(version.to_string()),
+                Ecosystem::Go,
+                false,
+            ));
+        }
+    }
+
+    dependencies
+}
+
+// =========================== .NET ===========================
+
+fn parse_csproj(content: &str, file_name: &str) -> Vec<DeclaredDependency> {
+    let is_dev = file_name.to_lowercase().contains("test");
+
+    CSPROJ_PACKAGE_REGEX
+        .captures_iter(content)
+        .map(|captures| {
+            let version = captures
+                .get(2)
+                .and_then(|attrs| CSPROJ_VERSION_REGEX.captures(attrs.as_str()))
+                .map(|c| c[1].to_string())
+                .or_else(|| captures.get(3).and_then(|body| xml_tag(body.as_str(), "Version")));
+            DeclaredDependency::new(&captures[1], version, Ecosystem::NuGet, is_dev)
+        })
+        .collect()
+}
+
+// =========================== Swift ===========================
+
+fn parse_package_swift(content: &str, _file_name: &str) -> Vec<DeclaredDependency> {
+    SWIFT_PACKAGE_REGEX
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let url = &captures[1];
+            let name = url.trim_end_matches('/').rsplit('/').next()?.trim_end_matches(".git");
+            let requirement = captures.get(2).map(|m| m.as_str()).unwrap_or_default();
+            let version = SWIFT_VERSION_REGEX.captures(requirement).map(|c| {
+                if requirement.contains("branch:") {
+                    format!("branch: {}", &c[1])
+                } else {
+                    c[1].to_string()
+                }
+            });
+            Some(DeclaredDependency::new(name, version, Ecosystem::SwiftPM, false))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cargo_toml() {
+        let content = r#"
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+anyhow = "1.0"
+local = { path = "../local" }
+renamed = { package = "real-name", version = "0.3" }
+
+[dev-dependencies]
+tempfile = "3"
+"#;
+        let deps = parse_cargo_toml(content, "Cargo.toml");
+        let names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert!(names.contains(&"serde"));
+        assert!(names.contains(&"real-name"));
+        assert!(!names.contains(&"local"));
+        assert!(deps.iter().find(|d| d.name == "tempfile").unwrap().is_dev);
+    }
+
+    #[test]
+    fn test_parse_pep508() {
+        let dep = parse_pep508("requests[socks]>=2.31; python_version > \"3.8\"", false).unwrap();
+        assert_eq!(dep.name, "requests");
+        assert_eq!(dep.version.as_deref(), Some(">=2.31"));
+    }
+
+    #[test]
+    fn test_parse_yarn_lock() {
+        let content = r#"
+"@babel/core@^7.0.0", "@babel/core@^7.1.0":
+  version "7.2.0"
+  resolved "https://registry.yarnpkg.com/@babel/core/-/core-7.2.0.tgz"
+
+lodash@^4.17.0:
+  version "4.17.21"
+"#;
+        let versions = parse_yarn_lock(content);
+        assert!(versions.contains(&("@babel/core".to_string(), "7.2.0".to_string())));
+        assert!(versions.contains(&("lodash".to_string(), "4.17.21".to_string())));
+
+        // Empty specs, a bare `@` and multibyte names must not panic
+        let versions = parse_yarn_lock("\"\", @, é@^1.0.0:\n  version \"1.0.1\"\n");
+        assert_eq!(versions, vec![("é".to_string(), "1.0.1".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_pnpm_lock() {
+        let content = r#"lockfileVersion: '9.0'
+
+importers:
+  .:
+    dependencies:
+      lodash:
+        specifier: ^4.17.0
+        version: 4.17.21
+
+packages:
+  '@babel/core@7.2.0':
+    resolution: {integrity: sha512-abc}
+  lodash@4.17.21:
+    resolution: {integrity: sha512-def}
+  /react-dom@18.2.0(react@18.2.0):
+    resolution: {integrity: sha512-ghi}
+  /left-pad/1.3.0:
+    dev: true
+
+snapshots:
+  lodash@4.17.21: {}
+"#;
+        let versions = parse_pnpm_lock(content);
+        assert_eq!(
+            versions,
+            vec![
+                ("@babel/core".to_string(), "7.2.0".to_string()),
+                ("lodash".to_string(), "4.17.21".to_string()),
+                ("react-dom".to_string(), "18.2.0".to_string()),
+                ("left-pad".to_string(), "1.3.0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_go_mod() {
+        let content = "module example.com/app\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.1\n\tgolang.org/x/text v0.14.0 // indirect\n)\n";
+        let deps = parse_go_mod(content, "go.mod");
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "github.com/gin-gonic/gin");
+    }
+}
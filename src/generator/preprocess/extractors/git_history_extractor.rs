@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::process::Stdio;
+
+use anyhow::Result;
+use tokio::process::Command as TokioCommand;
+
+use crate::config::GitHistoryConfig;
+
+const RECORD_SEPARATOR: char = '\u{1e}';
+const FIELD_SEPARATOR: char = '\u{1f}';
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+/// Files untouched for this long relative to the latest commit get no freshness credit
+const STALE_AFTER_DAYS: i64 = 365;
+/// Files maintained over this long get full longevity credit
+const MATURE_AFTER_DAYS: i64 = 365;
+/// Number of distinct authors at which the ownership signal saturates
+const AUTHOR_SATURATION: usize = 3;
+
+/// Change statistics of a single file
+#[derive(Debug, Clone, Default)]
+pub struct GitFileHistory {
+    pub commit_count: usize,
+    /// Commits within `recent_days` of the latest commit
+    pub recent_commit_count: usize,
+    pub authors: HashSet<String>,
+    /// Unix timestamp of the oldest analyzed commit touching the file
+    pub first_commit: i64,
+    /// Unix timestamp of the newest commit touching the file
+    pub last_commit: i64,
+}
+
+/// Change statistics of the project's files, keyed by path relative to the project root (`/` separated)
+#[derive(Debug, Clone, Default)]
+pub struct GitHistory {
+    pub files: HashMap<String, GitFileHistory>,
+    /// Unix timestamp of the newest analyzed commit, used as the reference point instead of the wall clock
+    pub latest_commit: i64,
+    max_commit_count: usize,
+    max_recent_commit_count: usize,
+}
+
+impl GitHistory {
+    /// Read the history of the repository containing `project_path`.
+    /// Returns `None` when git is unavailable or the project is not inside a repository.
+    pub async fn extract(project_path: &Path, config: &GitHistoryConfig) -> Option<Self> {
+        match Self::read_log(project_path, config.max_commits).await {
+            Ok(Some(log)) => {
+                let history = Self::parse(&log, config.recent_days);
+                (!history.files.is_empty()).then_some(history)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                eprintln!("⚠️ Failed to read git history: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn read_log(project_path: &Path, max_commits: usize) -> Result<Option<String>> {
+        let output = match TokioCommand::new("git")
+            .arg("-C")
+            .arg(project_path)
+            .args(["-c", "core.quotePath=false", "log", "--no-merges", "--relative"])
+            .arg(format!("--max-count={}", max_commits))
+            .arg("--format=%x1e%at%x1f%ae")
+            .arg("--name-only")
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .await
+        {
+            Ok(output) => output,
+            // git is not installed
+            Err(_) => return Ok(None),
+        };
+
+        if !output.status.success() {
+            // Not a git repository or no commits yet
+            return Ok(None);
+        }
+
+        Ok(Some(String::from_utf8_lossy(&output.stdout).to_string()))
+    }
+
+    /// Parse `git log --format=%x1e%at%x1f%ae --name-only` output
+    fn parse(log: &str, recent_days: u64) -> Self {
+        let mut commits: Vec<(i64, &str, Vec<&str>)> = Vec::new();
+        for record in log.split(RECORD_SEPARATOR).filter(|r| !r.trim().is_empty()) {
+            let mut lines = record.lines();
+            let Some((timestamp, author)) = lines
+                .next()
+                .and_then(|header| header.split_once(FIELD_SEPARATOR))
+            else {
+                continue;
+            };
+            let Ok(timestamp) = timestamp.trim().parse::<i64>() else {
+                continue;
+            };
+            let files = lines.map(str::trim).filter(|l| !l.is_empty()).collect();
+            commits.push((timestamp, author.trim(), files));
+        }
+
+        let latest_commit = commits.iter().map(|(t, _, _)| *t).max().unwrap_or_default();
+        let recent_since = latest_commit - recent_days as i64 * SECONDS_PER_DAY;
+
+        let mut files: HashMap<String, GitFileHistory> = HashMap::new();
+        for (timestamp, author, changed) in &commits {
+            for file in changed {
+                let history = files.entry(file.to_string()).or_insert_with(|| GitFileHistory {
+                    first_commit: *timestamp,
+                    last_commit: *timestamp,
+                    ..Default::default()
+                });
+                history.commit_count += 1;
+                if *timestamp >= recent_since {
+                    history.recent_commit_count += 1;
+                }
+                history.authors.insert(author.to_lowercase());
+                history.first_commit = history.first_commit.min(*timestamp);
+                history.last_commit = history.last_commit.max(*timestamp);
+            }
+        }
+
+        Self {
+            max_commit_count: files.values().map(|h| h.commit_count).max().unwrap_or_default(),
+            max_recent_commit_count: files
+                .values()
+                .map(|h| h.recent_commit_count)
+                .max()
+                .unwrap_or_default(),
+            files,
+            latest_commit,
+        }
+    }
+
+    /// Activity score (0.0 - 1.0) of a file combining commit frequency, recent churn, number of authors,
+    /// time since the last change and the span over which it has been maintained. `None` for files without history.
+    pub fn activity_score(&self, file: &Path) -> Option<f64> {
+        let key = file.to_string_lossy().replace('\\', "/");
+        let history = self.files.get(&key)?;
+
+        // Logarithmic so that a handful of extremely hot files does not flatten everything else
+        let frequency = if self.max_commit_count > 1 {
+            (history.commit_count as f64).ln_1p() / (self.max_commit_count as f64).ln_1p()
+        } else {
+            0.0
+        };
+        let churn = if self.max_recent_commit_count > 0 {
+            history.recent_commit_count as f64 / self.max_recent_commit_count as f64
+        } else {
+            0.0
+        };
+        let ownership = history.authors.len().min(AUTHOR_SATURATION) as f64 / AUTHOR_SATURATION as f64;
+        let idle_days = (self.latest_commit - history.last_commit) / SECONDS_PER_DAY;
+        let freshness = 1.0 - idle_days.clamp(0, STALE_AFTER_DAYS) as f64 / STALE_AFTER_DAYS as f64;
+        // Core files keep being changed over the life of the project, unlike a burst of commits on a one-off file
+        let maintained_days = (history.last_commit - history.first_commit) / SECONDS_PER_DAY;
+        let longevity = maintained_days.clamp(0, MATURE_AFTER_DAYS) as f64 / MATURE_AFTER_DAYS as f64;
+
+        Some(
+            (0.3 * frequency + 0.25 * churn + 0.15 * ownership + 0.2 * freshness + 0.1 * longevity)
+                .min(1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(days: i64, author: &str, files: &[&str]) -> String {
+        format!(
+            "{}{}{}{}\n\n{}\n",
+            RECORD_SEPARATOR,
+            1_700_000_000 + days * SECONDS_PER_DAY,
+            FIELD_SEPARATOR,
+            author,
+            files.join("\n")
+        )
+    }
+
+    #[test]
+    fn test_hotspot_scores_above_stale_vendored_file() {
+        let mut log = String::new();
+        // git log lists the newest commit first
+        for day in (400..500).rev().step_by(10) {
+            let author = if day % 20 == 0 { "a@x.io" } else { "b@x.io" };
+            log.push_str(&commit(day, author, &["src/engine.rs"]));
+        }
+        log.push_str(&commit(0, "a@x.io", &["vendor/lib.js", "src/engine.rs"]));
+
+        let history = GitHistory::parse(&log, 90);
+        let engine = &history.files["src/engine.rs"];
+        assert_eq!(engine.commit_count, 11);
+        assert_eq!(engine.recent_commit_count, 10);
+        assert_eq!(engine.authors.len(), 2);
+
+        let hot = history.activity_score(Path::new("src/engine.rs")).unwrap();
+        let stale = history.activity_score(Path::new("vendor/lib.js")).unwrap();
+        assert!(hot > 0.8, "hot = {}", hot);
+        assert!(stale < 0.2, "stale = {}", stale);
+        assert!(history.activity_score(Path::new("src/new.rs")).is_none());
+    }
+
+    #[test]
+    fn test_long_maintained_file_scores_above_burst() {
+        let mut log = String::new();
+        log.push_str(&commit(400, "a@x.io", &["src/core.rs", "src/migration.rs"]));
+        log.push_str(&commit(399, "a@x.io", &["src/migration.rs"]));
+        log.push_str(&commit(0, "a@x.io", &["src/core.rs"]));
+
+        // Same commit count, recent churn, authors and last change: only the maintained span differs
+        let history = GitHistory::parse(&log, 0);
+        let core = history.activity_score(Path::new("src/core.rs")).unwrap();
+        let migration = history.activity_score(Path::new("src/migration.rs")).unwrap();
+        assert_eq!(history.files["src/core.rs"].commit_count, 2);
+        assert_eq!(history.files["src/migration.rs"].commit_count, 2);
+        assert!(core - migration > 0.09, "core = {}, migration = {}", core, migration);
+    }
+}
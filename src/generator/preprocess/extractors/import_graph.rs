@@ -0,0 +1,625 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::FileInfo;
+
+const PAGERANK_DAMPING: f64 = 0.85;
+const PAGERANK_ITERATIONS: usize = 50;
+
+const JS_EXTENSIONS: [&str; 8] = ["ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "svelte"];
+
+/// A resolved import from one project file to another
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ImportEdge {
+    /// Importing file (relative path, `/` separated)
+    pub from: String,
+    /// Imported file (relative path, `/` separated)
+    pub to: String,
+    /// Line of the import statement in the importing file
+    pub line_number: Option<usize>,
+}
+
+/// File-level import graph of the project, built from statically resolved imports
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ImportGraph {
+    /// All analyzed files
+    pub nodes: Vec<String>,
+    /// Resolved imports between project files
+    pub edges: Vec<ImportEdge>,
+}
+
+impl ImportGraph {
+    /// Build the import graph by reading the given files and resolving their imports to project files
+    pub fn build(
+        root_path: &Path,
+        files: &[FileInfo],
+        language_processor: &LanguageProcessorManager,
+    ) -> Self {
+        let nodes: Vec<String> = files
+            .iter()
+            .map(|file| normalize_path(&file.path.to_string_lossy()))
+            .collect();
+        let resolver = ImportResolver::new(&nodes);
+
+        let mut edges = Vec::new();
+        let mut seen = HashSet::new();
+
+        for node in &nodes {
+            let full_path = root_path.join(node);
+            let Ok(content) = std::fs::read_to_string(&full_path) else {
+                continue;
+            };
+
+            for (specifier, line_number) in
+                language_processor.extract_import_specifiers(&full_path, &content)
+            {
+                if let Some(target) = resolver.resolve(node, &specifier)
+                    && target != *node
+                    && seen.insert((node.clone(), target.clone()))
+                {
+                    edges.push(ImportEdge {
+                        from: node.clone(),
+                        to: target,
+                        line_number: if line_number > 0 { Some(line_number) } else { None },
+                    });
+                }
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    /// Outgoing adjacency list indexed by node position
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.as_str(), i))
+            .collect();
+
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for edge in &self.edges {
+            if let (Some(&from), Some(&to)) = (index.get(edge.from.as_str()), index.get(edge.to.as_str())) {
+                adjacency[from].push(to);
+            }
+        }
+        adjacency
+    }
+
+    /// Strongly connected components (iterative Tarjan), each component as node indices
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let adjacency = self.adjacency();
+        let node_count = adjacency.len();
+
+        let mut index_counter = 0;
+        let mut indices: Vec<Option<usize>> = vec![None; node_count];
+        let mut low_links = vec![0; node_count];
+        let mut on_stack = vec![false; node_count];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+
+        for start in 0..node_count {
+            if indices[start].is_some() {
+                continue;
+            }
+
+            // Each frame holds the node and the position of the next neighbor to visit
+            let mut call_stack = vec![(start, 0usize)];
+            indices[start] = Some(index_counter);
+            low_links[start] = index_counter;
+            index_counter += 1;
+            stack.push(start);
+            on_stack[start] = true;
+
+            while let Some(&mut (node, ref mut next)) = call_stack.last_mut() {
+                if *next < adjacency[node].len() {
+                    let neighbor = adjacency[node][*next];
+                    *next += 1;
+
+                    match indices[neighbor] {
+                        None => {
+                            indices[neighbor] = Some(index_counter);
+                            low_links[neighbor] = index_counter;
+                            index_counter += 1;
+                            stack.push(neighbor);
+                            on_stack[neighbor] = true;
+                            call_stack.push((neighbor, 0));
+                        }
+                        Some(neighbor_index) if on_stack[neighbor] => {
+                            low_links[node] = low_links[node].min(neighbor_index);
+                        }
+                        _ => {}
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some(&(parent, _)) = call_stack.last() {
+                        low_links[parent] = low_links[parent].min(low_links[node]);
+                    }
+
+                    if Some(low_links[node]) == indices[node] {
+                        let mut component = Vec::new();
+                        while let Some(member) = stack.pop() {
+                            on_stack[member] = false;
+                            component.push(member);
+                            if member == node {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+
+        components
+    }
+
+    /// PageRank of every node: files imported by many (important) files rank high
+    pub fn pagerank(&self) -> Vec<f64> {
+        let n = self.nodes.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let adjacency = self.adjacency();
+        let base = (1.0 - PAGERANK_DAMPING) / n as f64;
+        let mut ranks = vec![1.0 / n as f64; n];
+
+        for _ in 0..PAGERANK_ITERATIONS {
+            // Rank of files without imports is spread evenly over all files
+            let dangling: f64 = adjacency
+                .iter()
+                .zip(&ranks)
+                .filter(|(targets, _)| targets.is_empty())
+                .map(|(_, rank)| rank)
+                .sum();
+            let mut next = vec![base + PAGERANK_DAMPING * dangling / n as f64; n];
+            for (from, targets) in adjacency.iter().enumerate() {
+                if targets.is_empty() {
+                    continue;
+                }
+                let share = PAGERANK_DAMPING * ranks[from] / targets.len() as f64;
+                for &to in targets {
+                    next[to] += share;
+                }
+            }
+            ranks = next;
+        }
+
+        ranks
+    }
+
+    /// Centrality (0.0 - 1.0) of every node keyed by path, combining PageRank and fan-in
+    pub fn centrality_scores(&self) -> HashMap<String, f64> {
+        let ranks = self.pagerank();
+        let mut fan_in = vec![0usize; self.nodes.len()];
+        for targets in self.adjacency() {
+            for to in targets {
+                fan_in[to] += 1;
+            }
+        }
+
+        let max_rank = ranks.iter().cloned().fold(0.0, f64::max);
+        let max_fan_in = fan_in.iter().copied().max().unwrap_or_default();
+        if max_fan_in == 0 {
+            return HashMap::new();
+        }
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let score = 0.6 * ranks[i] / max_rank + 0.4 * fan_in[i] as f64 / max_fan_in as f64;
+                (node.clone(), score)
+            })
+            .collect()
+    }
+
+    /// Find import cycles, returning the member files of each cycle and one example path through it
+    pub fn find_cycles(&self) -> Vec<(Vec<String>, Vec<String>)> {
+        let adjacency = self.adjacency();
+
+        let mut cycles = Vec::new();
+        for component in self.strongly_connected_components() {
+            let is_cycle = component.len() > 1
+                || component
+                    .first()
+                    .is_some_and(|&node| adjacency[node].contains(&node));
+            if !is_cycle {
+                continue;
+            }
+
+            let members: HashSet<usize> = component.iter().copied().collect();
+            let start = *component.iter().min().unwrap_or(&component[0]);
+            let example_path = Self::shortest_cycle_through(&adjacency, &members, start)
+                .into_iter()
+                .map(|node| self.nodes[node].clone())
+                .collect();
+
+            let mut member_names: Vec<String> =
+                component.iter().map(|&node| self.nodes[node].clone()).collect();
+            member_names.sort();
+
+            cycles.push((member_names, example_path));
+        }
+
+        cycles.sort_by(|a, b| a.0.cmp(&b.0));
+        cycles
+    }
+
+    /// BFS inside a strongly connected component to find a shortest cycle starting and ending at `start`
+    fn shortest_cycle_through(
+        adjacency: &[Vec<usize>],
+        members: &HashSet<usize>,
+        start: usize,
+    ) -> Vec<usize> {
+        let mut previous: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(node) = queue.pop_front() {
+            for &neighbor in &adjacency[node] {
+                if !members.contains(&neighbor) {
+                    continue;
+                }
+                if neighbor == start {
+                    let mut path = vec![start];
+                    let mut current = node;
+                    while current != start {
+                        path.push(current);
+                        current = previous[&current];
+                    }
+                    path[1..].reverse();
+                    path.push(start);
+                    return path;
+                }
+                if let std::collections::hash_map::Entry::Vacant(entry) = previous.entry(neighbor) {
+                    entry.insert(node);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        vec![start]
+    }
+}
+
+/// Resolves raw import specifiers to project files
+struct ImportResolver {
+    files: HashSet<String>,
+    /// File paths without extension, indexed by file stem
+    stem_index: HashMap<String, Vec<String>>,
+}
+
+impl ImportResolver {
+    fn new(nodes: &[String]) -> Self {
+        let mut stem_index: HashMap<String, Vec<String>> = HashMap::new();
+        for node in nodes {
+            let stem = Path::new(node)
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            stem_index.entry(stem).or_default().push(node.clone());
+        }
+
+        Self {
+            files: nodes.iter().cloned().collect(),
+            stem_index,
+        }
+    }
+
+    fn resolve(&self, from: &str, specifier: &str) -> Option<String> {
+        let specifier = specifier.trim().trim_end_matches(',').trim_matches(|c| c == '"' || c == '\'');
+        if specifier.is_empty() {
+            return None;
+        }
+
+        let extension = Path::new(from)
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "rs" => self.resolve_rust(from, specifier),
+            "py" => self.resolve_python(from, specifier),
+            ext if JS_EXTENSIONS.contains(&ext) => self.resolve_js(from, specifier),
+            _ => self.resolve_qualified_name(from, specifier),
+        }
+    }
+
+    fn resolve_rust(&self, from: &str, specifier: &str) -> Option<String> {
+        let segments: Vec<&str> = specifier.split("::").map(|s| s.trim()).collect();
+        let from_path = Path::new(from);
+        let parent = parent_dir(from);
+
+        // Directory holding the children of the current module
+        let file_name = from_path.file_name()?.to_string_lossy().to_string();
+        let module_dir = if matches!(file_name.as_str(), "mod.rs" | "lib.rs" | "main.rs") {
+            parent.clone()
+        } else {
+            join_path(&parent, &from_path.file_stem()?.to_string_lossy())
+        };
+
+        let (mut base, mut rest) = match segments.first().copied() {
+            Some("crate") => (rust_crate_root(from), &segments[1..]),
+            Some("self") => (module_dir.clone(), &segments[1..]),
+            Some("super") => (parent_dir(&module_dir), &segments[1..]),
+            _ => return None,
+        };
+        while rest.first() == Some(&"super") {
+            base = parent_dir(&base);
+            rest = &rest[1..];
+        }
+
+        // Items may follow the module path, so try the longest module prefix first
+        for len in (1..=rest.len()).rev() {
+            let module_path = join_path(&base, &rest[..len].join("/"));
+            for candidate in [format!("{}.rs", module_path), format!("{}/mod.rs", module_path)] {
+                if self.files.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn resolve_js(&self, from: &str, specifier: &str) -> Option<String> {
+        let base = if specifier.starts_with('.') {
+            normalize_relative(&join_path(&parent_dir(from), specifier))?
+        } else if let Some(rest) = specifier.strip_prefix("@/").or_else(|| specifier.strip_prefix("~/")) {
+            format!("src/{}", rest)
+        } else {
+            // Bare specifiers are packages
+            return None;
+        };
+
+        if self.files.contains(&base) {
+            return Some(base);
+        }
+        for ext in JS_EXTENSIONS {
+            for candidate in [format!("{}.{}", base, ext), format!("{}/index.{}", base, ext)] {
+                if self.files.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn resolve_python(&self, from: &str, specifier: &str) -> Option<String> {
+        let dots = specifier.chars().take_while(|&c| c == '.').count();
+        let module = &specifier[dots..];
+        let segments: Vec<&str> = module.split('.').filter(|s| !s.is_empty()).collect();
+
+        let bases: Vec<String> = if dots > 0 {
+            let mut base = parent_dir(from);
+            for _ in 1..dots {
+                base = parent_dir(&base);
+            }
+            vec![base]
+        } else {
+            vec![String::new(), "src".to_string()]
+        };
+
+        for base in &bases {
+            // `from a.b import c` may refer to module `a/b` or to package member `a/b/c`
+            for len in (1..=segments.len()).rev() {
+                let module_path = join_path(base, &segments[..len].join("/"));
+                for candidate in [format!("{}.py", module_path), format!("{}/__init__.py", module_path)] {
+                    if self.files.contains(&candidate) {
+                        return Some(candidate);
+                    }
+                }
+            }
+            if segments.is_empty() {
+                let candidate = join_path(base, "__init__.py");
+                if self.files.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Resolve fully qualified names (Java/Kotlin packages, C#/PHP namespaces, ...) by path suffix
+    fn resolve_qualified_name(&self, from: &str, specifier: &str) -> Option<String> {
+        let specifier = specifier.split(" as ").next().unwrap_or(specifier).trim();
+        if specifier.starts_with('.') || specifier.contains('/') {
+            return self.resolve_js(from, specifier);
+        }
+
+        let segments: Vec<&str> = specifier
+            .split(['.', '\\', ':'])
+            .filter(|s| !s.is_empty() && *s != "*")
+            .collect();
+        // A single segment is too ambiguous to resolve reliably
+        if segments.len() < 2 {
+            return None;
+        }
+
+        let from_extension = Path::new(from).extension().map(|e| e.to_string_lossy().to_string());
+        for len in (2..=segments.len()).rev() {
+            let suffix = segments[..len].join("/");
+            let Some(candidates) = self.stem_index.get(segments[len - 1]) else {
+                continue;
+            };
+            let matched = candidates.iter().find(|candidate| {
+                let without_extension = match candidate.rfind('.') {
+                    Some(pos) => &candidate[..pos],
+                    None => candidate.as_str(),
+                };
+                let same_language = Path::new(candidate.as_str())
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_string())
+                    == from_extension;
+                same_language
+                    && (without_extension == suffix || without_extension.ends_with(&format!("/{}", suffix)))
+            });
+            if let Some(matched) = matched {
+                return Some(matched.clone());
+            }
+        }
+
+        None
+    }
+}
+
+/// Normalize a path to `/` separators without leading `./`
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
+
+fn parent_dir(path: &str) -> String {
+    match path.rfind('/') {
+        Some(pos) => path[..pos].to_string(),
+        None => String::new(),
+    }
+}
+
+fn join_path(base: &str, child: &str) -> String {
+    if base.is_empty() {
+        child.to_string()
+    } else {
+        format!("{}/{}", base, child)
+    }
+}
+
+/// Resolve `.` and `..` components, returns None if the path escapes the project root
+fn normalize_relative(path: &str) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            _ => parts.push(part),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// The crate root of a Rust file is the closest ancestor `src` directory
+fn rust_crate_root(from: &str) -> String {
+    let mut current = parent_dir(from);
+    loop {
+        if current == "src" || current.ends_with("/src") {
+            return current;
+        }
+        if current.is_empty() {
+            return parent_dir(from);
+        }
+        current = parent_dir(&current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(nodes: &[&str], edges: &[(&str, &str)]) -> ImportGraph {
+        ImportGraph {
+            nodes: nodes.iter().map(|n| n.to_string()).collect(),
+            edges: edges
+                .iter()
+                .map(|(from, to)| ImportEdge {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    line_number: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let graph = graph(
+            &["a", "b", "c", "d"],
+            &[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")],
+        );
+
+        let cycles = graph.find_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].0, vec!["a", "b", "c"]);
+        assert_eq!(cycles[0].1, vec!["a", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_centrality_prefers_widely_imported_files() {
+        let graph = graph(
+            &["main", "a", "b", "util", "leaf"],
+            &[("main", "a"), ("main", "b"), ("a", "util"), ("b", "util"), ("main", "util")],
+        );
+
+        let scores = graph.centrality_scores();
+        assert_eq!(scores["util"], 1.0);
+        assert!(scores["a"] > scores["main"]);
+        assert!(scores["leaf"] < scores["a"]);
+    }
+
+    #[test]
+    fn test_acyclic_graph_has_no_cycles() {
+        let graph = graph(&["a", "b", "c"], &[("a", "b"), ("b", "c"), ("a", "c")]);
+        assert!(graph.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_imports() {
+        let nodes: Vec<String> = [
+            "src/main.rs",
+            "src/config.rs",
+            "src/generator/mod.rs",
+            "src/generator/context.rs",
+            "web/src/app.ts",
+            "web/src/utils/index.ts",
+            "pkg/service.py",
+            "pkg/models/__init__.py",
+            "src/main/java/com/example/core/Service.java",
+            "src/main/java/com/example/api/Controller.java",
+        ]
+        .iter()
+        .map(|n| n.to_string())
+        .collect();
+        let resolver = ImportResolver::new(&nodes);
+
+        assert_eq!(
+            resolver.resolve("src/main.rs", "crate::config::Config"),
+            Some("src/config.rs".to_string())
+        );
+        assert_eq!(
+            resolver.resolve("src/generator/mod.rs", "self::context"),
+            Some("src/generator/context.rs".to_string())
+        );
+        assert_eq!(
+            resolver.resolve("src/generator/context.rs", "super::super::config::Config"),
+            Some("src/config.rs".to_string())
+        );
+        assert_eq!(
+            resolver.resolve("src/generator/context.rs", "crate::generator::context"),
+            Some("src/generator/context.rs".to_string())
+        );
+        assert_eq!(resolver.resolve("src/main.rs", "std::path::Path"), None);
+        assert_eq!(
+            resolver.resolve("web/src/app.ts", "./utils"),
+            Some("web/src/utils/index.ts".to_string())
+        );
+        assert_eq!(resolver.resolve("web/src/app.ts", "react"), None);
+        assert_eq!(
+            resolver.resolve("pkg/service.py", ".models"),
+            Some("pkg/models/__init__.py".to_string())
+        );
+        assert_eq!(
+            resolver.resolve(
+                "src/main/java/com/example/api/Controller.java",
+                "com.example.core.Service"
+            ),
+            Some("src/main/java/com/example/core/Service.java".to_string())
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
+use std::collections::HashMap;
 use regex::Regex;
 use std::path::Path;
 
@@ -14,6 +15,11 @@ pub struct CSharpProcessor {
     struct_regex: Regex,
     property_regex: Regex,
     constructor_regex: Regex,
+    http_attribute_regex: Regex,
+    route_attribute_regex: Regex,
+    minimal_api_regex: Regex,
+    map_group_regex: Regex,
+    handler_regex: Regex,
 }
 
 impl CSharpProcessor {
@@ -28,6 +34,11 @@ impl CSharpProcessor {
             struct_regex: Regex::new(r"^\s*(public|private|protected|internal)?\s*(readonly)?\s*(partial)?\s*struct\s+(\w+)").unwrap(),
             property_regex: Regex::new(r"^\s*(public|private|protected|internal)?\s*(static)?\s*(virtual|override|abstract)?\s*(\w+)\s+(\w+)\s*\{\s*(get|set)").unwrap(),
             constructor_regex: Regex::new(r"^\s*(public|private|protected|internal)?\s*(\w+)\s*\(([^)]*)\)").unwrap(),
+            http_attribute_regex: Regex::new(r#"\bHttp(Get|Post|Put|Delete|Patch|Head|Options)\s*(?:\(\s*"([^"]*)")?"#).unwrap(),
+            route_attribute_regex: Regex::new(r#"\bRoute\s*\(\s*"([^"]*)""#).unwrap(),
+            minimal_api_regex: Regex::new(r#"\b(\w+)\.Map(Get|Post|Put|Delete|Patch)\s*\(\s*"([^"]*)""#).unwrap(),
+            map_group_regex: Regex::new(r#"\b(\w+)\s*=\s*(\w+)\.MapGroup\s*\(\s*"([^"]*)""#).unwrap(),
+            handler_regex: Regex::new(r"(\w+)\s*(?:<[^>]*>)?\s*\(").unwrap(),
         }
     }
 }
@@ -375,6 +386,113 @@ impl LanguageProcessor for CSharpProcessor {
         
         interfaces
     }
+
+    fn extract_import_specifiers(&self, content: &str, file_path: &Path) -> Vec<(String, usize)> {
+        if file_path.extension().and_then(|e| e.to_str()) != Some("cs") {
+            return Vec::new();
+        }
+
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| {
+                let captures = self.using_regex.captures(line)?;
+                let using_str = captures.get(1)?.as_str().trim();
+                // Aliases point at the right-hand side namespace
+                let using_str = using_str.rsplit(" = ").next().unwrap_or(using_str);
+                let using_str = using_str.trim_start_matches("static ").trim();
+                Some((using_str.to_string(), line_num + 1))
+            })
+            .collect()
+    }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        // Class-level `[Route]` template and controller name (without the `Controller` suffix)
+        let mut prefix = String::new();
+        let mut controller = String::new();
+        // Attributes waiting for the class or action they annotate
+        let mut pending_route: Option<String> = None;
+        let mut pending_verbs: Vec<(String, Option<String>, usize)> = Vec::new();
+        let mut pending_line = 0;
+        // `MapGroup` variable -> URL prefix
+        let mut groups: HashMap<String, String> = HashMap::new();
+
+        for (line_num, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("//") {
+                continue;
+            }
+
+            if trimmed.starts_with('[') {
+                for captures in self.http_attribute_regex.captures_iter(trimmed) {
+                    let template = captures.get(2).map(|m| m.as_str().to_string());
+                    pending_verbs.push((captures[1].to_string(), template, line_num + 1));
+                }
+                if let Some(captures) = self.route_attribute_regex.captures(trimmed) {
+                    pending_route = Some(captures[1].to_string());
+                    pending_line = line_num + 1;
+                }
+                continue;
+            }
+
+            // Minimal APIs: `app.MapGet("/todos/{id}", ...)`, optionally on a `MapGroup` prefix
+            if let Some(captures) = self.map_group_regex.captures(line) {
+                let parent = groups.get(&captures[2]).cloned().unwrap_or_default();
+                groups.insert(captures[1].to_string(), RouteDefinition::join_path(&parent, &captures[3]));
+            }
+            for captures in self.minimal_api_regex.captures_iter(line) {
+                let group = groups.get(&captures[1]).map(String::as_str).unwrap_or_default();
+                routes.push(RouteDefinition::new(
+                    &captures[2],
+                    &RouteDefinition::join_path(group, &captures[3]),
+                    None,
+                    "ASP.NET Core",
+                    line_num + 1,
+                ));
+            }
+
+            if let Some(captures) = self.class_regex.captures(line) {
+                let name = &captures[6];
+                controller = name.strip_suffix("Controller").unwrap_or(name).to_string();
+                prefix = pending_route.take().unwrap_or_default();
+                pending_verbs.clear();
+                continue;
+            }
+
+            if pending_route.is_none() && pending_verbs.is_empty() {
+                continue;
+            }
+            let Some(action) = self.handler_regex.captures(trimmed).map(|m| m[1].to_string()) else {
+                continue;
+            };
+
+            let action_route = pending_route.take();
+            if pending_verbs.is_empty() {
+                pending_verbs.push(("ANY".to_string(), None, pending_line));
+            }
+            for (method, template, line_number) in pending_verbs.drain(..) {
+                let template = template.or_else(|| action_route.clone()).unwrap_or_default();
+                // A template starting with `/` or `~/` ignores the controller route
+                let path = if template.starts_with('/') || template.starts_with("~/") {
+                    RouteDefinition::join_path("", template.trim_start_matches('~'))
+                } else {
+                    RouteDefinition::join_path(&prefix, &template)
+                };
+                let path = path
+                    .replace("[controller]", &controller.to_lowercase())
+                    .replace("[action]", &action.to_lowercase());
+                let handler = if controller.is_empty() {
+                    action.clone()
+                } else {
+                    format!("{}Controller.{}", controller, action)
+                };
+                routes.push(RouteDefinition::new(&method, &path, Some(handler), "ASP.NET Core", line_number));
+            }
+        }
+
+        routes
+    }
 }
 
 impl CSharpProcessor {
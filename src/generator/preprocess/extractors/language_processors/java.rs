@@ -1,5 +1,5 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
 use regex::Regex;
 use std::path::Path;
 
@@ -12,6 +12,10 @@ pub struct JavaProcessor {
     interface_regex: Regex,
     enum_regex: Regex,
     constructor_regex: Regex,
+    mapping_regex: Regex,
+    mapping_path_regex: Regex,
+    request_method_regex: Regex,
+    handler_regex: Regex,
 }
 
 impl JavaProcessor {
@@ -24,6 +28,10 @@ impl JavaProcessor {
             interface_regex: Regex::new(r"^\s*(public|private|protected)?\s*interface\s+(\w+)").unwrap(),
             enum_regex: Regex::new(r"^\s*(public|private|protected)?\s*enum\s+(\w+)").unwrap(),
             constructor_regex: Regex::new(r"^\s*(public|private|protected)?\s*(\w+)\s*\(([^)]*)\)").unwrap(),
+            mapping_regex: Regex::new(r"^\s*@(Request|Get|Post|Put|Delete|Patch)Mapping\b\s*(?:\((.*)\))?").unwrap(),
+            mapping_path_regex: Regex::new(r#"^\s*\{?\s*"([^"]*)"|\b(?:value|path)\s*=\s*\{?\s*"([^"]*)""#).unwrap(),
+            request_method_regex: Regex::new(r"RequestMethod\.(\w+)").unwrap(),
+            handler_regex: Regex::new(r"(\w+)\s*\(").unwrap(),
         }
     }
 }
@@ -239,6 +247,79 @@ impl LanguageProcessor for JavaProcessor {
         
         interfaces
     }
+
+    fn extract_import_specifiers(&self, content: &str, _file_path: &Path) -> Vec<(String, usize)> {
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| {
+                let captures = self.import_regex.captures(line)?;
+                let import_str = captures.get(1)?.as_str().trim();
+                let import_str = import_str.trim_start_matches("static ").trim();
+                Some((import_str.to_string(), line_num + 1))
+            })
+            .collect()
+    }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        // Class-level `@RequestMapping` prefix of the current controller
+        let mut prefix = String::new();
+        let mut controller: Option<String> = None;
+        // Mapping annotations waiting for the class or method they annotate: (methods, path, line)
+        let mut pending: Vec<(Vec<String>, String, usize)> = Vec::new();
+
+        for (line_num, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if let Some(captures) = self.mapping_regex.captures(line) {
+                let args = captures.get(2).map(|m| m.as_str()).unwrap_or_default();
+                let path = self
+                    .mapping_path_regex
+                    .captures(args)
+                    .and_then(|m| m.get(1).or_else(|| m.get(2)))
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                let methods = match &captures[1] {
+                    "Request" => {
+                        let methods: Vec<String> = self
+                            .request_method_regex
+                            .captures_iter(args)
+                            .map(|m| m[1].to_string())
+                            .collect();
+                        if methods.is_empty() { vec!["ANY".to_string()] } else { methods }
+                    }
+                    method => vec![method.to_string()],
+                };
+                pending.push((methods, path, line_num + 1));
+                continue;
+            }
+
+            if trimmed.is_empty() || trimmed.starts_with('@') || trimmed.starts_with("//") || trimmed.starts_with('*') {
+                continue;
+            }
+
+            if let Some(captures) = self.class_regex.captures(line) {
+                controller = Some(captures[4].to_string());
+                prefix = pending.drain(..).next().map(|(_, path, _)| path).unwrap_or_default();
+                continue;
+            }
+
+            if !pending.is_empty() {
+                let handler = self.handler_regex.captures(trimmed).map(|m| match &controller {
+                    Some(controller) => format!("{}.{}", controller, &m[1]),
+                    None => m[1].to_string(),
+                });
+                for (methods, path, line_number) in pending.drain(..) {
+                    let path = RouteDefinition::join_path(&prefix, &path);
+                    for method in methods {
+                        routes.push(RouteDefinition::new(&method, &path, handler.clone(), "Spring MVC", line_number));
+                    }
+                }
+            }
+        }
+
+        routes
+    }
 }
 
 impl JavaProcessor {
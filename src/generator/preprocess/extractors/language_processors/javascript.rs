@@ -1,5 +1,5 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
 use regex::Regex;
 use std::path::Path;
 
@@ -13,6 +13,7 @@ pub struct JavaScriptProcessor {
     class_regex: Regex,
     method_regex: Regex,
     export_function_regex: Regex,
+    route_matcher: NodeRouteMatcher,
 }
 
 impl JavaScriptProcessor {
@@ -32,6 +33,7 @@ impl JavaScriptProcessor {
                 r"^\s*export\s+(async\s+)?function\s+(\w+)\s*\(([^)]*)\)",
             )
             .unwrap(),
+            route_matcher: NodeRouteMatcher::new(),
         }
     }
 }
@@ -328,6 +330,10 @@ impl LanguageProcessor for JavaScriptProcessor {
 
         interfaces
     }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        self.route_matcher.extract_routes(content)
+    }
 }
 
 impl JavaScriptProcessor {
@@ -423,3 +429,110 @@ impl JavaScriptProcessor {
         }
     }
 }
+
+/// HTTP route matcher for Node.js frameworks (Express, Koa, Fastify, NestJS), shared by the JavaScript and TypeScript processors
+#[derive(Debug)]
+pub(super) struct NodeRouteMatcher {
+    method_call_regex: Regex,
+    handler_regex: Regex,
+    nest_controller_regex: Regex,
+    nest_method_regex: Regex,
+    class_method_regex: Regex,
+}
+
+/// Receivers of `.get('/path')` style calls that are HTTP clients or collections, not routers
+const NON_ROUTER_RECEIVERS: [&str; 10] = [
+    "axios", "http", "https", "fetch", "request", "client", "superagent", "cy", "map", "params",
+];
+
+impl NodeRouteMatcher {
+    pub(super) fn new() -> Self {
+        Self {
+            method_call_regex: Regex::new(
+                r#"\b(\w+)\s*\.\s*(get|post|put|delete|del|patch|all|options|head)\s*\(\s*['"`]([^'"`]*)['"`]"#,
+            )
+            .unwrap(),
+            handler_regex: Regex::new(r"([\w.]+)\s*\)\s*;?\s*$").unwrap(),
+            nest_controller_regex: Regex::new(
+                r#"@Controller\s*\(\s*(?:\{[^}]*path\s*:\s*)?(?:['"`]([^'"`]*)['"`])?"#,
+            )
+            .unwrap(),
+            nest_method_regex: Regex::new(
+                r#"@(Get|Post|Put|Delete|Patch|All|Options|Head)\s*\(\s*(?:['"`]([^'"`]*)['"`])?\s*\)"#,
+            )
+            .unwrap(),
+            class_method_regex: Regex::new(r"^\s*(?:public\s+|private\s+|protected\s+)?(?:async\s+)?(\w+)\s*\(").unwrap(),
+        }
+    }
+
+    pub(super) fn extract_routes(&self, content: &str) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        let framework = if content.contains("koa") {
+            "Koa"
+        } else if content.contains("fastify") {
+            "Fastify"
+        } else {
+            "Express"
+        };
+
+        // NestJS: decorated controller classes
+        let mut controller_prefix: Option<String> = None;
+        let mut pending: Vec<(String, String, usize)> = Vec::new();
+
+        for (line_num, line) in content.lines().enumerate() {
+            if let Some(captures) = self.nest_controller_regex.captures(line) {
+                controller_prefix = Some(captures.get(1).map(|m| m.as_str().to_string()).unwrap_or_default());
+                continue;
+            }
+            if controller_prefix.is_some() {
+                if let Some(captures) = self.nest_method_regex.captures(line) {
+                    let method = match &captures[1] {
+                        "All" => "ANY".to_string(),
+                        method => method.to_string(),
+                    };
+                    let path = captures.get(2).map(|m| m.as_str()).unwrap_or_default();
+                    pending.push((method, path.to_string(), line_num + 1));
+                    continue;
+                }
+                if !pending.is_empty()
+                    && !line.trim_start().starts_with('@')
+                    && let Some(captures) = self.class_method_regex.captures(line)
+                {
+                    let prefix = controller_prefix.as_deref().unwrap_or_default();
+                    for (method, path, line_number) in pending.drain(..) {
+                        routes.push(RouteDefinition::new(
+                            &method,
+                            &RouteDefinition::join_path(prefix, &path),
+                            Some(captures[1].to_string()),
+                            "NestJS",
+                            line_number,
+                        ));
+                    }
+                }
+                continue;
+            }
+
+            // Express/Koa/Fastify: `router.get('/users/:id', auth, controller.show)`
+            for captures in self.method_call_regex.captures_iter(line) {
+                let receiver = &captures[1];
+                let path = &captures[3];
+                if NON_ROUTER_RECEIVERS.contains(&receiver) || !(path.starts_with('/') || path == "*") {
+                    continue;
+                }
+                let method = match &captures[2] {
+                    "all" => "ANY",
+                    "del" => "DELETE",
+                    method => method,
+                };
+                let handler = self
+                    .handler_regex
+                    .captures(line)
+                    .map(|m| m[1].to_string())
+                    .filter(|h| h != path);
+                routes.push(RouteDefinition::new(method, path, handler, framework, line_num + 1));
+            }
+        }
+
+        routes
+    }
+}
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use crate::types::code::{CodeComplexity, Dependency, InterfaceInfo};
+use crate::types::code::{CodeComplexity, Dependency, InterfaceInfo, RouteDefinition};
 
 /// Language processor trait
 pub trait LanguageProcessor: Send + Sync + std::fmt::Debug {
@@ -23,6 +23,42 @@ pub trait LanguageProcessor: Send + Sync + std::fmt::Debug {
 
     /// Extract code interface definitions
     fn extract_interfaces(&self, content: &str, file_path: &Path) -> Vec<InterfaceInfo>;
+
+    /// Extract raw import specifiers (module paths as written in source) with their line numbers,
+    /// used to resolve imports to project files
+    fn extract_import_specifiers(&self, content: &str, file_path: &Path) -> Vec<(String, usize)> {
+        let source_file = file_path.to_string_lossy().to_string();
+
+        self.extract_dependencies(content, file_path)
+            .into_iter()
+            .filter(|dep| {
+                // Declarations and package manager entries are not imports of project files
+                !matches!(
+                    dep.dependency_type.as_str(),
+                    "package"
+                        | "namespace"
+                        | "composer"
+                        | "nuget_package"
+                        | "framework_reference"
+                        | "solution_project"
+                )
+            })
+            .filter_map(|dep| {
+                // Processors store the import target either in `name` or in `path`
+                let specifier = if dep.name == source_file {
+                    dep.path?
+                } else {
+                    dep.name
+                };
+                Some((specifier, dep.line_number.unwrap_or(0)))
+            })
+            .collect()
+    }
+
+    /// Extract HTTP routes declared through the web frameworks of this language
+    fn extract_routes(&self, _content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        Vec::new()
+    }
 }
 
 /// Language processor manager
@@ -107,6 +143,24 @@ impl LanguageProcessorManager {
         }
     }
 
+    /// Extract raw import specifiers
+    pub fn extract_import_specifiers(&self, file_path: &Path, content: &str) -> Vec<(String, usize)> {
+        if let Some(processor) = self.get_processor(file_path) {
+            processor.extract_import_specifiers(content, file_path)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Extract HTTP routes
+    pub fn extract_routes(&self, file_path: &Path, content: &str) -> Vec<RouteDefinition> {
+        if let Some(processor) = self.get_processor(file_path) {
+            processor.extract_routes(content, file_path)
+        } else {
+            Vec::new()
+        }
+    }
+
     pub fn calculate_complexity_metrics(&self, content: &str) -> CodeComplexity {
         let lines: Vec<&str> = content.lines().collect();
         let lines_of_code = lines.len();
@@ -1,5 +1,5 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
 use regex::Regex;
 use serde_json::Value;
 use std::collections::HashSet;
@@ -31,8 +31,31 @@ pub struct PhpProcessor {
     enum_regex: Regex,
     /// Set of normalized namespace prefixes treated as internal
     internal_namespaces: HashSet<String>,
+    /// Regex to capture Laravel `Route::get('/path', handler)` style routes.
+    laravel_route_regex: Regex,
+    /// Regex to capture Laravel `Route::match(['get', 'post'], '/path', handler)` routes.
+    laravel_match_regex: Regex,
+    /// Regex to capture Laravel `Route::view('/path', 'view')` pages.
+    laravel_view_regex: Regex,
+    /// Regex to capture Laravel `Route::resource('photos', PhotoController::class)` declarations.
+    laravel_resource_regex: Regex,
+    /// Regex to capture the prefix of a Laravel route group.
+    laravel_group_prefix_regex: Regex,
+    /// Regex to capture a `[Controller::class, 'action']` or `'Controller@action'` route handler.
+    laravel_handler_regex: Regex,
 }
 
+/// Routes registered by `Route::resource` as (action, method, path suffix, included by `apiResource`)
+const LARAVEL_RESOURCE_ACTIONS: [(&str, &str, &str, bool); 7] = [
+    ("index", "GET", "", true),
+    ("create", "GET", "/create", false),
+    ("store", "POST", "", true),
+    ("show", "GET", "/{id}", true),
+    ("edit", "GET", "/{id}/edit", false),
+    ("update", "PUT", "/{id}", true),
+    ("destroy", "DELETE", "/{id}", true),
+];
+
 impl PhpProcessor {
     pub fn new() -> Self {
         Self {
@@ -47,6 +70,12 @@ impl PhpProcessor {
             method_regex: Regex::new(r"^\s*(?:(public|protected|private)\s+)?(?:(static)\s+)?(?:(abstract|final)\s+)?function\s+(&)?\s*(\w+)\s*\(([^)]*)\)\s*(?::\s*([^{;]+))?").unwrap(),
             enum_regex: Regex::new(r"^\s*enum\s+(\w+)(?:\s*:\s*\w+)?\s*\{?").unwrap(),
             internal_namespaces: Self::detect_internal_namespaces(),
+            laravel_route_regex: Regex::new(r#"Route::(get|post|put|patch|delete|options|any)\s*\(\s*['"]([^'"]*)['"]\s*(?:,\s*(.*))?"#).unwrap(),
+            laravel_match_regex: Regex::new(r#"Route::match\s*\(\s*\[([^\]]*)\]\s*,\s*['"]([^'"]*)['"]\s*(?:,\s*(.*))?"#).unwrap(),
+            laravel_view_regex: Regex::new(r#"Route::view\s*\(\s*['"]([^'"]*)['"]\s*,\s*['"]([^'"]*)['"]"#).unwrap(),
+            laravel_resource_regex: Regex::new(r#"Route::(resource|apiResource)\s*\(\s*['"]([^'"]*)['"]\s*,\s*\\?([\w\\]+)::class"#).unwrap(),
+            laravel_group_prefix_regex: Regex::new(r#"prefix\s*\(\s*['"]([^'"]*)['"]|['"]prefix['"]\s*=>\s*['"]([^'"]*)['"]"#).unwrap(),
+            laravel_handler_regex: Regex::new(r#"^\[\s*\\?([\w\\]+)::class\s*,\s*['"](\w+)['"]|^['"]([\w\\]+@\w+)['"]"#).unwrap(),
         }
     }
 }
@@ -183,9 +212,138 @@ impl LanguageProcessor for PhpProcessor {
         }
         interfaces
     }
+
+    fn extract_routes(&self, content: &str, file_path: &Path) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        // Laravel's RouteServiceProvider mounts routes/api.php under /api
+        let normalized_path = file_path.to_string_lossy().replace('\\', "/");
+        let file_prefix = if normalized_path.ends_with("routes/api.php") { "/api" } else { "" };
+        // Open route groups as (accumulated prefix, brace depth inside the group)
+        let mut groups: Vec<(String, i32)> = Vec::new();
+        let mut depth = 0;
+
+        for (line_num, line) in content.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with('*') {
+                continue;
+            }
+            let prefix = groups.last().map(|(prefix, _)| prefix.as_str()).unwrap_or(file_prefix);
+            let line_number = line_num + 1;
+
+            if let Some(captures) = self.laravel_route_regex.captures(line) {
+                let handler = captures.get(3).and_then(|m| self.laravel_handler(m.as_str()));
+                let path = RouteDefinition::join_path(prefix, &captures[2]);
+                routes.push(RouteDefinition::new(&captures[1], &path, handler, "Laravel", line_number));
+            } else if let Some(captures) = self.laravel_match_regex.captures(line) {
+                let handler = captures.get(3).and_then(|m| self.laravel_handler(m.as_str()));
+                let path = RouteDefinition::join_path(prefix, &captures[2]);
+                for method in captures[1].split(',') {
+                    let method = method.trim().trim_matches(['\'', '"']);
+                    if !method.is_empty() {
+                        routes.push(RouteDefinition::new(method, &path, handler.clone(), "Laravel", line_number));
+                    }
+                }
+            } else if let Some(captures) = self.laravel_view_regex.captures(line) {
+                let path = RouteDefinition::join_path(prefix, &captures[1]);
+                let mut route = RouteDefinition::new("GET", &path, Some(captures[2].to_string()), "Laravel", line_number);
+                route.is_page = true;
+                routes.push(route);
+            } else if let Some(captures) = self.laravel_resource_regex.captures(line) {
+                let api_only = &captures[1] == "apiResource";
+                let controller = captures[3].rsplit('\\').next().unwrap_or(&captures[3]);
+                let base = Self::laravel_resource_path(prefix, &captures[2]);
+                let parameter = Self::singular(captures[2].rsplit('.').next().unwrap_or_default());
+                for (action, method, suffix, in_api) in LARAVEL_RESOURCE_ACTIONS {
+                    if api_only && !in_api {
+                        continue;
+                    }
+                    let path = format!("{}{}", base, suffix.replace("{id}", &format!("{{{}}}", parameter)));
+                    let handler = Some(format!("{}@{}", controller, action));
+                    routes.push(RouteDefinition::new(method, &path, handler, "Laravel", line_number));
+                }
+            }
+
+            let opens_group = line.contains("->group(") || line.contains("Route::group(");
+            let group_prefix = self
+                .laravel_group_prefix_regex
+                .captures(line)
+                .and_then(|m| m.get(1).or_else(|| m.get(2)))
+                .map(|m| m.as_str())
+                .filter(|_| opens_group);
+            let group_path = opens_group.then(|| RouteDefinition::join_path(prefix, group_prefix.unwrap_or_default()));
+
+            depth += Self::brace_delta(line);
+            if let Some(group_path) = group_path {
+                groups.push((group_path, depth));
+            }
+            while groups.last().is_some_and(|(_, group_depth)| depth < *group_depth) {
+                groups.pop();
+            }
+        }
+
+        routes
+    }
 }
 
 impl PhpProcessor {
+    /// `Controller@action` of a Laravel route handler, `None` for closures
+    fn laravel_handler(&self, arguments: &str) -> Option<String> {
+        let captures = self.laravel_handler_regex.captures(arguments.trim())?;
+        if let Some(action) = captures.get(3) {
+            return Some(action.as_str().rsplit('\\').next().unwrap_or_default().to_string());
+        }
+        let controller = captures[1].rsplit('\\').next().unwrap_or_default();
+        Some(format!("{}@{}", controller, &captures[2]))
+    }
+
+    /// Base path of a (possibly nested, `photos.comments`) Laravel resource
+    fn laravel_resource_path(prefix: &str, name: &str) -> String {
+        let segments: Vec<&str> = name.split('.').collect();
+        let mut path = String::new();
+        for (i, segment) in segments.iter().enumerate() {
+            path.push('/');
+            path.push_str(segment);
+            if i + 1 < segments.len() {
+                path.push_str(&format!("/{{{}}}", Self::singular(segment)));
+            }
+        }
+        RouteDefinition::join_path(prefix, &path)
+    }
+
+    /// Naive singular form used for resource route parameters (`photos` -> `photo`)
+    fn singular(word: &str) -> String {
+        let word = word.trim_matches('/');
+        let word = word.rsplit('/').next().unwrap_or(word);
+        if let Some(stem) = word.strip_suffix("ies") {
+            format!("{}y", stem)
+        } else if word.ends_with("ss") {
+            word.to_string()
+        } else {
+            word.strip_suffix('s').unwrap_or(word).to_string()
+        }
+    }
+
+    /// Net change of brace depth on a line, ignoring braces inside string literals such as `'/users/{id}'`
+    fn brace_delta(line: &str) -> i32 {
+        let mut delta = 0;
+        let mut quote: Option<char> = None;
+        let mut escaped = false;
+        for c in line.chars() {
+            match quote {
+                Some(_) if escaped => escaped = false,
+                Some(_) if c == '\\' => escaped = true,
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None => match c {
+                    '\'' | '"' => quote = Some(c),
+                    '{' => delta += 1,
+                    '}' => delta -= 1,
+                    _ => {}
+                },
+            }
+        }
+        delta
+    }
     fn extract_class_interface<'a>(
         &self,
         line: &str,
@@ -1,6 +1,7 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
 use regex::Regex;
+use std::collections::HashMap;
 use std::path::Path;
 
 #[derive(Debug)]
@@ -11,6 +12,12 @@ pub struct PythonProcessor {
     class_regex: Regex,
     method_regex: Regex,
     async_function_regex: Regex,
+    route_decorator_regex: Regex,
+    route_methods_regex: Regex,
+    router_declaration_regex: Regex,
+    route_prefix_regex: Regex,
+    def_regex: Regex,
+    django_path_regex: Regex,
 }
 
 impl PythonProcessor {
@@ -22,6 +29,15 @@ impl PythonProcessor {
             class_regex: Regex::new(r"^\s*class\s+(\w+)(?:\([^)]*\))?:").unwrap(),
             method_regex: Regex::new(r"^\s+def\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^:]+))?:").unwrap(),
             async_function_regex: Regex::new(r"^\s*async\s+def\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^:]+))?:").unwrap(),
+            route_decorator_regex: Regex::new(
+                r#"^\s*@(\w+)\.(route|get|post|put|delete|patch|head|options|api_route|websocket)\s*\(\s*[rf]?['"]([^'"]*)['"](.*)"#,
+            )
+            .unwrap(),
+            route_methods_regex: Regex::new(r#"methods\s*=\s*[\[(]([^\])]*)[\])]"#).unwrap(),
+            router_declaration_regex: Regex::new(r"^\s*(\w+)\s*=\s*(?:\w+\.)?(Blueprint|APIRouter)\s*\((.*)").unwrap(),
+            route_prefix_regex: Regex::new(r#"(?:url_prefix|prefix)\s*=\s*['"]([^'"]*)['"]"#).unwrap(),
+            def_regex: Regex::new(r"^\s*(?:async\s+)?def\s+(\w+)").unwrap(),
+            django_path_regex: Regex::new(r#"^\s*(re_path|path|url)\s*\(\s*r?['"]([^'"]*)['"]\s*,\s*([\w.]+)"#).unwrap(),
         }
     }
 }
@@ -206,6 +222,79 @@ impl LanguageProcessor for PythonProcessor {
         
         interfaces
     }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        let is_fastapi = content.contains("fastapi");
+        // Blueprint/APIRouter variable -> URL prefix
+        let mut prefixes: HashMap<String, String> = HashMap::new();
+        // Decorated routes waiting for their handler function
+        let mut pending: Vec<RouteDefinition> = Vec::new();
+
+        for (line_num, line) in content.lines().enumerate() {
+            if let Some(captures) = self.router_declaration_regex.captures(line) {
+                let prefix = self
+                    .route_prefix_regex
+                    .captures(&captures[3])
+                    .map(|m| m[1].to_string())
+                    .unwrap_or_default();
+                prefixes.insert(captures[1].to_string(), prefix);
+                continue;
+            }
+
+            // Flask `@app.route("/x", methods=["POST"])`, FastAPI `@router.get("/items/{id}")`
+            if let Some(captures) = self.route_decorator_regex.captures(line) {
+                let prefix = prefixes.get(&captures[1]).map(String::as_str).unwrap_or_default();
+                let path = RouteDefinition::join_path(prefix, &captures[3]);
+                let framework = if is_fastapi { "FastAPI" } else { "Flask" };
+                let methods: Vec<String> = match &captures[2] {
+                    "route" | "api_route" => self
+                        .route_methods_regex
+                        .captures(&captures[4])
+                        .map(|m| {
+                            m[1].split(',')
+                                .map(|method| method.trim().trim_matches(['"', '\'']).to_string())
+                                .filter(|method| !method.is_empty())
+                                .collect()
+                        })
+                        .unwrap_or_else(|| vec!["GET".to_string()]),
+                    "websocket" => vec!["WEBSOCKET".to_string()],
+                    method => vec![method.to_string()],
+                };
+                for method in methods {
+                    pending.push(RouteDefinition::new(&method, &path, None, framework, line_num + 1));
+                }
+                continue;
+            }
+
+            if !pending.is_empty()
+                && let Some(captures) = self.def_regex.captures(line)
+            {
+                for mut route in pending.drain(..) {
+                    route.handler = Some(captures[1].to_string());
+                    routes.push(route);
+                }
+                continue;
+            }
+
+            // Django URLconf: `path("articles/<int:year>/", views.year_archive)`
+            if let Some(captures) = self.django_path_regex.captures(line)
+                && &captures[3] != "include"
+            {
+                let mut route = RouteDefinition::new(
+                    "ANY",
+                    &RouteDefinition::join_path("", &captures[2]),
+                    Some(captures[3].to_string()),
+                    "Django",
+                    line_num + 1,
+                );
+                route.is_page = true;
+                routes.push(route);
+            }
+        }
+
+        routes
+    }
 }
 
 impl PythonProcessor {
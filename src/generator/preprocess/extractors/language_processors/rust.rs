@@ -1,29 +1,46 @@
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
+use crate::utils::source_scan::{Syntax, balanced, line_of, split_top_level, unquote};
 use regex::Regex;
 use std::path::Path;
 
 #[derive(Debug)]
 pub struct RustProcessor {
     use_regex: Regex,
+    use_statement_regex: Regex,
     mod_regex: Regex,
     fn_regex: Regex,
     struct_regex: Regex,
     trait_regex: Regex,
     impl_regex: Regex,
     enum_regex: Regex,
+    route_attribute_regex: Regex,
+    route_call_regex: Regex,
+    actix_resource_regex: Regex,
+    actix_method_regex: Regex,
+    axum_method_regex: Regex,
+    route_method_regex: Regex,
+    handler_fn_regex: Regex,
 }
 
 impl RustProcessor {
     pub fn new() -> Self {
         Self {
             use_regex: Regex::new(r"^\s*use\s+([^;]+);").unwrap(),
+            use_statement_regex: Regex::new(r"(?m)^[ \t]*(?:pub(?:\([^)]*\))?\s+)?use\s+([^;]+);").unwrap(),
             mod_regex: Regex::new(r"^\s*mod\s+([^;]+);").unwrap(),
             fn_regex: Regex::new(r"^\s*(pub\s+)?(async\s+)?fn\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^{]+))?").unwrap(),
             struct_regex: Regex::new(r"^\s*(pub\s+)?struct\s+(\w+)").unwrap(),
             trait_regex: Regex::new(r"^\s*(pub\s+)?trait\s+(\w+)").unwrap(),
             impl_regex: Regex::new(r"^\s*impl(?:\s*<[^>]*>)?\s+(?:(\w+)\s+for\s+)?(\w+)").unwrap(),
             enum_regex: Regex::new(r"^\s*(pub\s+)?enum\s+(\w+)").unwrap(),
+            route_attribute_regex: Regex::new(r#"#\[(get|post|put|delete|patch|head|options|route)\s*\(\s*"([^"]*)"([^\]]*)\]"#).unwrap(),
+            route_call_regex: Regex::new(r"\.route\s*\(").unwrap(),
+            actix_resource_regex: Regex::new(r#"web::resource\s*\(\s*"([^"]*)"\s*\)"#).unwrap(),
+            actix_method_regex: Regex::new(r"web::(get|post|put|delete|patch|head|options)\s*\(\s*\)\s*\.to\s*\(\s*([\w:]+)").unwrap(),
+            axum_method_regex: Regex::new(r"\b(get|post|put|delete|patch|head|options|any)\s*\(\s*([\w:]+)\s*\)").unwrap(),
+            route_method_regex: Regex::new(r#"method\s*=\s*"(\w+)""#).unwrap(),
+            handler_fn_regex: Regex::new(r"\bfn\s+(\w+)").unwrap(),
         }
     }
 }
@@ -249,9 +266,138 @@ impl LanguageProcessor for RustProcessor {
         
         interfaces
     }
+
+    fn extract_import_specifiers(&self, content: &str, _file_path: &Path) -> Vec<(String, usize)> {
+        // `mod` declarations only wire up the module tree, they are not imports
+        let mut specifiers = Vec::new();
+
+        // rustfmt spreads grouped imports over several lines, so match whole statements up to their `;`
+        for captures in self.use_statement_regex.captures_iter(content) {
+            let line_number = line_of(content, captures.get(0).unwrap().start());
+            for path in self.expand_use_tree(&captures[1]) {
+                specifiers.push((path, line_number));
+            }
+        }
+
+        specifiers
+    }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        let mut routes = Vec::new();
+        let attribute_framework = if content.contains("rocket") { "Rocket" } else { "actix-web" };
+
+        // Attribute macros: `#[get("/users/{id}")]` (actix-web) or `#[get("/users/<id>")]` (Rocket)
+        for captures in self.route_attribute_regex.captures_iter(content) {
+            let whole = captures.get(0).unwrap();
+            let methods: Vec<String> = match &captures[1] {
+                "route" => self
+                    .route_method_regex
+                    .captures_iter(&captures[3])
+                    .map(|m| m[1].to_string())
+                    .collect(),
+                method => vec![method.to_string()],
+            };
+            let handler = self
+                .handler_fn_regex
+                .captures(&content[whole.end()..])
+                .map(|m| m[1].to_string());
+            let line_number = line_of(content, whole.start());
+            for method in if methods.is_empty() { vec!["ANY".to_string()] } else { methods } {
+                routes.push(RouteDefinition::new(
+                    &method,
+                    &captures[2],
+                    handler.clone(),
+                    attribute_framework,
+                    line_number,
+                ));
+            }
+        }
+
+        // Router builders: `.route("/users", get(list).post(create))` (axum) or
+        // `.route("/users", web::get().to(list))` (actix-web)
+        for route_call in self.route_call_regex.find_iter(content) {
+            let Some(args_text) = balanced(content, route_call.end() - 1, Syntax::Rust) else {
+                continue;
+            };
+            let args = split_top_level(args_text, Syntax::Rust);
+            let Some(path) = args.first().and_then(|a| unquote(a)) else {
+                continue;
+            };
+            let methods = args[1..].join(",");
+            let line_number = line_of(content, route_call.start());
+
+            let actix_routes: Vec<_> = self.actix_method_regex.captures_iter(&methods).collect();
+            if !actix_routes.is_empty() {
+                for captures in actix_routes {
+                    routes.push(RouteDefinition::new(&captures[1], &path, Some(captures[2].to_string()), "actix-web", line_number));
+                }
+            } else {
+                for captures in self.axum_method_regex.captures_iter(&methods) {
+                    routes.push(RouteDefinition::new(&captures[1], &path, Some(captures[2].to_string()), "axum", line_number));
+                }
+            }
+        }
+
+        // Actix resources: `web::resource("/users").route(web::get().to(list))`
+        for captures in self.actix_resource_regex.captures_iter(content) {
+            let whole = captures.get(0).unwrap();
+            let chain_end = content[whole.end()..]
+                .find([';', '\n'])
+                .map(|offset| whole.end() + offset)
+                .unwrap_or(content.len());
+            for method in self.actix_method_regex.captures_iter(&content[whole.end()..chain_end]) {
+                routes.push(RouteDefinition::new(
+                    &method[1],
+                    &captures[1],
+                    Some(method[2].to_string()),
+                    "actix-web",
+                    line_of(content, whole.start()),
+                ));
+            }
+        }
+
+        routes
+    }
 }
 
 impl RustProcessor {
+    /// Expand a use tree like `crate::a::{b, c::{D, E}}` into full paths
+    fn expand_use_tree(&self, use_tree: &str) -> Vec<String> {
+        let use_tree: String = use_tree
+            .lines()
+            .map(|line| line.split("//").next().unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut paths = Vec::new();
+        Self::expand_use_subtree("", &use_tree, &mut paths);
+        paths
+    }
+
+    fn expand_use_subtree(prefix: &str, tree: &str, paths: &mut Vec<String>) {
+        let tree = tree.trim();
+        let join = |item: &str| match (prefix.is_empty(), item) {
+            (true, _) => item.to_string(),
+            (false, "self") => prefix.to_string(),
+            (false, _) => format!("{}::{}", prefix, item),
+        };
+
+        if let Some(start) = tree.find('{') {
+            let Some(inner) = balanced(tree, start, Syntax::Rust) else {
+                return;
+            };
+            let nested_prefix = join(tree[..start].trim().trim_end_matches("::").trim());
+            for subtree in split_top_level(inner, Syntax::Rust) {
+                Self::expand_use_subtree(&nested_prefix, &subtree, paths);
+            }
+            return;
+        }
+
+        let item = tree.split(" as ").next().unwrap_or(tree).trim();
+        if !item.is_empty() {
+            paths.push(join(item));
+        }
+    }
+
     /// Parse Rust function parameters
     fn parse_rust_parameters(&self, params_str: &str) -> Vec<ParameterInfo> {
         let mut parameters = Vec::new();
@@ -341,4 +487,41 @@ impl RustProcessor {
             path.to_string()
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_extract_multi_line_grouped_imports() {
+        let processor = RustProcessor::new();
+        let content = r#"use std::path::Path;
+
+use crate::{
+    config::Config,
+    generator::{
+        context::GeneratorContext,
+        // Storage and ownership
+        types::{self, Generator as _},
+    },
+    utils::*,
+};
+pub(crate) use super::helpers::{ignore as ignore_rules};
+"#;
+        let specifiers = processor.extract_import_specifiers(content, &PathBuf::from("src/lib.rs"));
+
+        assert_eq!(
+            specifiers,
+            vec![
+                ("std::path::Path".to_string(), 1),
+                ("crate::config::Config".to_string(), 3),
+                ("crate::generator::context::GeneratorContext".to_string(), 3),
+                ("crate::generator::types".to_string(), 3),
+                ("crate::generator::types::Generator".to_string(), 3),
+                ("crate::utils::*".to_string(), 3),
+                ("super::helpers::ignore".to_string(), 12),
+            ]
+        );
+    }
+}
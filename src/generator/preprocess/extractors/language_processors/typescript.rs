@@ -1,5 +1,6 @@
+use super::javascript::NodeRouteMatcher;
 use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use crate::types::code::{InterfaceInfo, ParameterInfo, RouteDefinition};
 use regex::Regex;
 use std::path::Path;
 
@@ -13,6 +14,7 @@ pub struct TypeScriptProcessor {
     class_regex: Regex,
     enum_regex: Regex,
     method_regex: Regex,
+    route_matcher: NodeRouteMatcher,
 }
 
 impl TypeScriptProcessor {
@@ -26,6 +28,7 @@ impl TypeScriptProcessor {
             class_regex: Regex::new(r"^\s*(export\s+)?(abstract\s+)?class\s+(\w+)").unwrap(),
             enum_regex: Regex::new(r"^\s*(export\s+)?enum\s+(\w+)").unwrap(),
             method_regex: Regex::new(r"^\s*(public|private|protected)?\s*(static\s+)?(async\s+)?(\w+)\s*\(([^)]*)\)\s*:\s*([^{]+)?").unwrap(),
+            route_matcher: NodeRouteMatcher::new(),
         }
     }
 }
@@ -331,6 +334,10 @@ impl LanguageProcessor for TypeScriptProcessor {
 
         interfaces
     }
+
+    fn extract_routes(&self, content: &str, _file_path: &Path) -> Vec<RouteDefinition> {
+        self.route_matcher.extract_routes(content)
+    }
 }
 
 impl TypeScriptProcessor {
@@ -1,3 +1,13 @@
+pub mod architecture_health;
+pub mod cli_extractor;
+pub mod configuration_extractor;
+pub mod dependency_manifest_extractor;
+pub mod git_history_extractor;
+pub mod import_graph;
 pub mod language_processors;
+pub mod route_extractor;
 pub mod structure_extractor;
+pub mod symbol_index_extractor;
+pub mod test_mapping_extractor;
 pub mod original_document_extractor;
+pub mod workspace_extractor;
@@ -1,34 +1,307 @@
+use std::path::Path;
+
 use anyhow::Result;
-use tokio::fs::read_to_string;
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::config::Config;
 use crate::generator::context::GeneratorContext;
-use crate::types::original_document::OriginalDocument;
+use crate::types::original_document::{DocumentKind, DocumentSource, OriginalDocument};
+use crate::utils::ignore_rules::IgnoreRules;
+
+/// Maximum number of documents ingested, in priority order
+const MAX_DOCUMENTS: usize = 60;
+/// Maximum characters kept per document
+const MAX_DOCUMENT_CHARS: usize = 16_000;
+/// Changelogs are cut to their latest entries
+const MAX_CHANGELOG_LINES: usize = 150;
+/// Maximum characters kept per module doc comment
+const MAX_DOC_COMMENT_CHARS: usize = 4_000;
+
+/// Directories holding user-facing documentation
+const DOC_DIRS: [&str; 4] = ["docs", "doc", "documentation", "wiki"];
+/// Directories holding architecture decision records
+const ADR_DIRS: [&str; 5] = ["adr", "adrs", "decisions", "decision-records", "architecture-decisions"];
+/// Extensions of documentation files
+const DOC_EXTENSIONS: [&str; 6] = ["md", "markdown", "rst", "txt", "adoc", "mdx"];
 
 pub async fn extract(context: &GeneratorContext) -> Result<OriginalDocument> {
-    let readme = match read_to_string(context.config.project_path.join("README.md")).await {
-        Ok(content) => {
-            let trimmed_content = trim_markdown(&content);
-            Some(trimmed_content)
-        },
-        Err(_) => None
+    let config = context.config.clone();
+    let ignore_rules = context.ignore_rules.clone();
+    Ok(tokio::task::spawn_blocking(move || extract_from(&config, &ignore_rules)).await?)
+}
+
+/// Collect READMEs (localized variants included), `docs/` trees, ADRs, contributing guides,
+/// changelogs and module doc comments, keeping their markdown structure
+pub fn extract_from(config: &Config, ignore_rules: &IgnoreRules) -> OriginalDocument {
+    let root = &config.project_path;
+    let readme_pattern =
+        Regex::new(r"(?i)^readme(?:[._-]([a-z]{2,3}(?:[_-][a-z0-9]{2,4})?))?(?:\.(?:md|markdown|rst|txt|adoc))?$")
+            .unwrap();
+
+    let walker = WalkDir::new(root)
+        .max_depth(config.max_depth.into())
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            // `.github` holds contributing guides and is scanned despite being hidden. Only directories go through the
+            // ignore rules, the default `excluded_files` listing `*.md` and `*.txt`
+            (name == ".github" || !name.starts_with('.')) && !ignore_rules.is_ignored(entry.path(), true)
+        });
+
+    let mut documents = Vec::new();
+    for entry in walker.filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+        let Some((kind, language)) = classify(&relative, &readme_pattern) else {
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        if let Some(document) = build_document(kind, relative, language, &content) {
+            documents.push(document);
+        }
+    }
+
+    documents.sort_by(|a, b| {
+        (a.kind, a.path.matches('/').count(), a.language.is_some(), &a.path).cmp(&(
+            b.kind,
+            b.path.matches('/').count(),
+            b.language.is_some(),
+            &b.path,
+        ))
+    });
+    documents.truncate(MAX_DOCUMENTS);
+
+    OriginalDocument { documents }
+}
+
+/// Kind of documentation a file holds and the language of localized READMEs, `None` for other files
+fn classify(relative: &str, readme_pattern: &Regex) -> Option<(DocumentKind, Option<String>)> {
+    let segments: Vec<String> = relative.split('/').map(|s| s.to_lowercase()).collect();
+    let (file_name, dirs) = segments.split_last()?;
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    let extension = Path::new(file_name)
+        .extension()
+        .map(|e| e.to_string_lossy().to_string());
+    let is_doc_file = extension
+        .as_deref()
+        .is_none_or(|e| DOC_EXTENSIONS.contains(&e));
+    let in_github_dir = dirs.first().is_some_and(|d| d == ".github");
+
+    if let Some(captures) = readme_pattern.captures(file_name.as_str()) {
+        if in_github_dir {
+            return None;
+        }
+        let original_name = relative.rsplit('/').next().unwrap_or(relative);
+        let language = captures
+            .get(1)
+            .map(|m| original_name[m.start()..m.end()].to_string());
+        return Some((DocumentKind::Readme, language));
+    }
+    if is_doc_file && (stem == "contributing" || stem == "hacking") && dirs.len() <= 1 {
+        return Some((DocumentKind::Contributing, None));
+    }
+    if is_doc_file && matches!(stem, "changelog" | "changes" | "history") && dirs.is_empty() {
+        return Some((DocumentKind::Changelog, None));
+    }
+    if is_doc_file && extension.is_some() && !in_github_dir {
+        if dirs.iter().any(|d| ADR_DIRS.contains(&d.as_str())) {
+            return Some((DocumentKind::Adr, None));
+        }
+        if dirs.first().is_some_and(|d| DOC_DIRS.contains(&d.as_str())) {
+            return Some((DocumentKind::Guide, None));
+        }
+    }
+    if matches!(
+        file_name.as_str(),
+        "lib.rs" | "main.rs" | "mod.rs" | "__init__.py" | "doc.go" | "package-info.java"
+    ) {
+        return Some((DocumentKind::DocComment, None));
+    }
+    None
+}
+
+fn build_document(
+    kind: DocumentKind,
+    path: String,
+    language: Option<String>,
+    content: &str,
+) -> Option<DocumentSource> {
+    let content = match kind {
+        DocumentKind::DocComment => module_doc_comment(&path, content)?,
+        DocumentKind::Changelog => {
+            let lines: Vec<&str> = content.lines().collect();
+            let mut kept = lines[..lines.len().min(MAX_CHANGELOG_LINES)].join("\n");
+            if lines.len() > MAX_CHANGELOG_LINES {
+                kept.push_str("\n...");
+            }
+            kept
+        }
+        _ => content.trim().to_string(),
     };
-    Ok(OriginalDocument {
-        readme,
+    if content.trim().is_empty() {
+        return None;
+    }
+
+    let limit = if kind == DocumentKind::DocComment {
+        MAX_DOC_COMMENT_CHARS
+    } else {
+        MAX_DOCUMENT_CHARS
+    };
+    let (content, truncated) = truncate_chars(&content, limit);
+
+    Some(DocumentSource {
+        kind,
+        title: document_title(&content),
+        path,
+        language,
+        content,
+        truncated,
     })
 }
 
-fn trim_markdown(markdown: &str) -> String {
-    let lines: Vec<&str> = markdown.lines().collect();
-    let mut description = String::new();
+/// Leading module documentation: `//!` lines in Rust, the module docstring in Python,
+/// the package comment in Go and the package Javadoc in Java
+fn module_doc_comment(path: &str, content: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().map(str::trim).collect();
+    let text = if path.ends_with(".rs") {
+        lines
+            .iter()
+            .skip_while(|line| line.is_empty() || line.starts_with("#!["))
+            .take_while(|line| line.starts_with("//!"))
+            .map(|line| {
+                let text = line.trim_start_matches("//!");
+                text.strip_prefix(' ').unwrap_or(text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else if path.ends_with(".py") {
+        // Skip the shebang and encoding comments preceding the docstring
+        let code_start = lines
+            .iter()
+            .position(|line| !line.is_empty() && !line.starts_with('#'))?;
+        let body = lines[code_start..].join("\n");
+        let quote = ["\"\"\"", "'''"].into_iter().find(|q| body.starts_with(q))?;
+        let inner = &body[quote.len()..];
+        inner[..inner.find(quote)?].trim().to_string()
+    } else if path.ends_with(".go") {
+        lines
+            .iter()
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| line.starts_with("//"))
+            .map(|line| line.trim_start_matches("//").trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        let start = content.find("/**")?;
+        let end = start + content[start..].find("*/")?;
+        content[start + 3..end]
+            .lines()
+            .map(|line| line.trim().trim_start_matches('*').trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string()
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
 
-    for line in lines.iter().take(500) {
-        if line.starts_with('#') || line.starts_with("```") {
-            continue;
+/// First markdown heading or reStructuredText title
+fn document_title(content: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().map(str::trim).collect();
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            if !heading.is_empty() {
+                return Some(heading.to_string());
+            }
         }
-        if !line.trim().is_empty() {
-            description.push_str(line);
-            description.push(' ');
+        let underlined = lines.get(i + 1).is_some_and(|next| {
+            next.len() >= 3 && next.chars().all(|c| c == '=' || c == '-') && !line.is_empty()
+        });
+        if underlined && !line.chars().all(|c| c == '=' || c == '-') {
+            return Some(line.to_string());
         }
     }
+    None
+}
+
+fn truncate_chars(content: &str, limit: usize) -> (String, bool) {
+    match content.char_indices().nth(limit) {
+        Some((end, _)) => (content[..end].to_string(), true),
+        None => (content.to_string(), false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempProject;
 
-    description
-}
\ No newline at end of file
+    #[test]
+    fn test_extract_documents() {
+        let root = TempProject::new("original_document");
+        let files = [
+            ("README.md", "# Shop\n\n## Usage\n\n```sh\nshop run\n```\n"),
+            ("README.zh-CN.md", "# 商店\n"),
+            ("CONTRIBUTING.md", "# Contributing\nOpen a PR.\n"),
+            ("CHANGELOG.md", "# Changelog\n## 1.0.0\n"),
+            ("docs/guide/setup.rst", "Setup\n=====\n\nInstall it.\n"),
+            ("docs/adr/0001-use-postgres.md", "# Use Postgres\n"),
+            ("src/lib.rs", "//! Order handling for the shop\n//!\n//! See `OrderStore`.\n\npub mod store;\n"),
+            ("src/store.rs", "//! Not a module root\n"),
+            ("target/README.md", "# Build output\n"),
+            (".gitignore", "drafts/\n"),
+            ("docs/drafts/plan.md", "# Unpublished plan\n"),
+        ];
+        for (path, content) in files {
+            root.write(path, content);
+        }
+
+        let config = Config {
+            project_path: root.path().to_path_buf(),
+            ..Default::default()
+        };
+        let document = extract_from(&config, &IgnoreRules::load(&config));
+        let summary: Vec<(DocumentKind, &str)> = document
+            .documents
+            .iter()
+            .map(|d| (d.kind, d.path.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (DocumentKind::Readme, "README.md"),
+                (DocumentKind::Readme, "README.zh-CN.md"),
+                (DocumentKind::Guide, "docs/guide/setup.rst"),
+                (DocumentKind::Adr, "docs/adr/0001-use-postgres.md"),
+                (DocumentKind::Contributing, "CONTRIBUTING.md"),
+                (DocumentKind::Changelog, "CHANGELOG.md"),
+                (DocumentKind::DocComment, "src/lib.rs"),
+            ]
+        );
+
+        let readme = document.readme().unwrap();
+        assert!(readme.content.contains("```sh\nshop run\n```"));
+        assert_eq!(readme.title.as_deref(), Some("Shop"));
+        assert_eq!(document.documents[1].language.as_deref(), Some("zh-CN"));
+        assert_eq!(document.documents[2].title.as_deref(), Some("Setup"));
+        assert_eq!(
+            document.documents[6].content,
+            "Order handling for the shop\n\nSee `OrderStore`."
+        );
+    }
+}
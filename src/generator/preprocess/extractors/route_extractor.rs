@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::research::types::{APIBoundary, RouterBoundary, RouterParam};
+use crate::types::code::RouteDefinition;
+use crate::types::FileInfo;
+
+/// HTTP routes statically extracted from web framework declarations
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtractedRoutes {
+    pub api_boundaries: Vec<APIBoundary>,
+    pub router_boundaries: Vec<RouterBoundary>,
+}
+
+impl ExtractedRoutes {
+    /// Extract routes (axum/actix/rocket, Express/Koa/NestJS, Flask/FastAPI/Django, Spring MVC,
+    /// ASP.NET Core and Laravel) from the project files
+    pub fn extract(
+        root_path: &Path,
+        files: &[FileInfo],
+        language_processor: &LanguageProcessorManager,
+    ) -> Self {
+        let mut routes = Self::default();
+        let mut seen = HashSet::new();
+
+        for file in files {
+            let path = normalize_path(&file.path.to_string_lossy());
+            let full_path = root_path.join(&path);
+            let Ok(content) = std::fs::read_to_string(&full_path) else {
+                continue;
+            };
+
+            for route in language_processor.extract_routes(&full_path, &content) {
+                if seen.insert((route.method.clone(), route.path.clone(), path.clone())) {
+                    routes.push(route, &path);
+                }
+            }
+        }
+
+        routes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.api_boundaries.is_empty() && self.router_boundaries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.api_boundaries.len() + self.router_boundaries.len()
+    }
+
+    fn push(&mut self, route: RouteDefinition, file: &str) {
+        let source_location = format!("{}:{}", file, route.line_number);
+        let params = route.path_params();
+        let description = match &route.handler {
+            Some(handler) => format!("{} route handled by `{}`", route.framework, handler),
+            None => format!("{} route", route.framework),
+        };
+
+        if route.is_page {
+            self.router_boundaries.push(RouterBoundary {
+                path: route.path,
+                description,
+                source_location,
+                params: params
+                    .into_iter()
+                    .map(|(key, value_type)| RouterParam {
+                        key,
+                        value_type,
+                        description: String::new(),
+                    })
+                    .collect(),
+            });
+        } else {
+            let request_format = (!params.is_empty()).then(|| {
+                let params: Vec<String> = params
+                    .iter()
+                    .map(|(name, value_type)| format!("{}: {}", name, value_type))
+                    .collect();
+                format!("Path parameters: {}", params.join(", "))
+            });
+            self.api_boundaries.push(APIBoundary {
+                endpoint: route.path,
+                method: route.method,
+                description,
+                request_format,
+                response_format: None,
+                authentication: None,
+                source_location,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempProject;
+
+    fn find<'a>(routes: &'a ExtractedRoutes, method: &str, endpoint: &str) -> &'a APIBoundary {
+        routes
+            .api_boundaries
+            .iter()
+            .find(|api| api.method == method && api.endpoint == endpoint)
+            .unwrap_or_else(|| panic!("missing {} {} in {:#?}", method, endpoint, routes.api_boundaries))
+    }
+
+    #[test]
+    fn test_extract_routes_across_frameworks() {
+        let root = TempProject::new("routes");
+        let files = vec![
+            root.write(
+                "src/server.rs",
+                r#"
+pub fn app() -> Router {
+    Router::new()
+        .route("/users/{id}", get(get_user).delete(delete_user))
+        .nest("/api", api_routes())
+}
+
+#[post("/login")]
+async fn login() -> impl Responder {}
+"#,
+            ),
+            root.write(
+                "src/routes.ts",
+                r#"
+const router = express.Router();
+router.get('/orders/:orderId', getOrder);
+
+@Controller('cats')
+export class CatsController {
+  @Post(':id/feed')
+  feed(@Param('id') id: string) {}
+}
+"#,
+            ),
+            root.write(
+                "app/views.py",
+                r#"
+bp = Blueprint("admin", __name__, url_prefix="/admin")
+
+@bp.route("/stats", methods=["GET", "POST"])
+def stats():
+    pass
+"#,
+            ),
+            root.write(
+                "app/urls.py",
+                r#"
+urlpatterns = [
+    path("articles/<int:year>/", views.year_archive),
+]
+"#,
+            ),
+            root.write(
+                "src/main/java/UserController.java",
+                r#"
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+    @GetMapping("/{id}")
+    public ResponseEntity<User> get(@PathVariable Long id) {}
+
+    @RequestMapping(value = "/search", method = RequestMethod.POST)
+    public List<User> search() {}
+}
+"#,
+            ),
+            root.write(
+                "Controllers/ProductsController.cs",
+                r#"
+[ApiController]
+[Route("api/[controller]")]
+public class ProductsController : ControllerBase
+{
+    [HttpGet("{id:int}")]
+    public async Task<ActionResult<Product>> GetById(int id) {}
+}
+
+app.MapGet("/health", () => "ok");
+"#,
+            ),
+            root.write(
+                "routes/api.php",
+                r#"<?php
+Route::prefix('v1')->group(function () {
+    Route::get('/posts/{post}', [PostController::class, 'show']);
+    Route::apiResource('photos', PhotoController::class);
+});
+Route::post('/upload', 'UploadController@store');
+"#,
+            ),
+        ];
+
+        let routes = ExtractedRoutes::extract(root.path(), &files, &LanguageProcessorManager::new());
+
+        let user = find(&routes, "GET", "/users/{id}");
+        assert_eq!(user.source_location, "src/server.rs:4");
+        assert_eq!(user.request_format.as_deref(), Some("Path parameters: id: string"));
+        find(&routes, "DELETE", "/users/{id}");
+        find(&routes, "POST", "/login");
+
+        find(&routes, "GET", "/orders/:orderId");
+        find(&routes, "POST", "/cats/:id/feed");
+
+        find(&routes, "GET", "/admin/stats");
+        find(&routes, "POST", "/admin/stats");
+        let archive = &routes.router_boundaries[0];
+        assert_eq!(archive.path, "/articles/<int:year>");
+        assert_eq!(archive.params[0].key, "year");
+        assert_eq!(archive.params[0].value_type, "int");
+
+        let spring = find(&routes, "GET", "/api/users/{id}");
+        assert!(spring.description.contains("UserController.get"));
+        find(&routes, "POST", "/api/users/search");
+
+        let product = find(&routes, "GET", "/api/products/{id:int}");
+        assert!(product.description.contains("ProductsController.GetById"));
+        assert_eq!(product.request_format.as_deref(), Some("Path parameters: id: int"));
+        find(&routes, "GET", "/health");
+
+        assert!(find(&routes, "GET", "/api/v1/posts/{post}").description.contains("PostController@show"));
+        find(&routes, "PUT", "/api/v1/photos/{photo}");
+        assert!(
+            !routes
+                .api_boundaries
+                .iter()
+                .any(|api| api.endpoint == "/api/v1/photos/create")
+        );
+        find(&routes, "POST", "/api/upload");
+    }
+}
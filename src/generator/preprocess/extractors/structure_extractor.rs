@@ -1,21 +1,36 @@
 use crate::generator::context::GeneratorContext;
 use crate::generator::preprocess::agents::code_purpose_analyze::CodePurposeEnhancer;
+use crate::generator::preprocess::extractors::git_history_extractor::GitHistory;
+use crate::generator::preprocess::extractors::import_graph::{ImportGraph, normalize_path};
 use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::preprocess::extractors::route_extractor::ExtractedRoutes;
+use crate::generator::preprocess::extractors::symbol_index_extractor::SymbolIndexer;
+use crate::generator::preprocess::extractors::test_mapping_extractor::TestMapper;
 use crate::types::code::{CodeDossier, CodePurpose, CodePurposeMapper};
 use crate::types::project_structure::ProjectStructure;
+use crate::types::symbol_index::SymbolIndex;
+use crate::types::test_mapping::TestMapping;
 use crate::types::{DirectoryInfo, FileInfo};
 use crate::utils::file_utils::{is_binary_file_path, is_test_directory, is_test_file};
+use crate::utils::ignore_rules::IgnoreRules;
 use crate::utils::sources::read_code_source;
 use anyhow::Result;
 use futures::future::BoxFuture;
 use std::collections::HashMap;
 use std::fs::Metadata;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Share of the core file ranking score taken from import graph centrality
+const CENTRALITY_WEIGHT: f64 = 0.5;
+/// Minimum number of core files kept regardless of `core_component_percentage`, so small projects are fully covered
+const MIN_CORE_FILES: usize = 20;
 
 /// Project structure extractor
 pub struct StructureExtractor {
     language_processor: LanguageProcessorManager,
     code_purpose_enhancer: CodePurposeEnhancer,
+    ignore_rules: Arc<IgnoreRules>,
     context: GeneratorContext,
 }
 
@@ -24,6 +39,7 @@ impl StructureExtractor {
         Self {
             language_processor: LanguageProcessorManager::new(),
             code_purpose_enhancer: CodePurposeEnhancer::new(),
+            ignore_rules: context.ignore_rules.clone(),
             context,
         }
     }
@@ -65,8 +81,15 @@ impl StructureExtractor {
         )
         .await?;
 
+        // Git history of the project, used as an additional importance signal
+        let git_history = if self.context.config.git_history.enabled {
+            GitHistory::extract(project_path, &self.context.config.git_history).await
+        } else {
+            None
+        };
+
         // Calculate importance scores
-        self.calculate_importance_scores(&mut files, &mut directories);
+        self.calculate_importance_scores(&mut files, &mut directories, git_history.as_ref());
 
         let project_name = self.context.config.get_project_name();
 
@@ -135,7 +158,7 @@ impl StructureExtractor {
                         .to_string();
 
                     // Skip hidden directories and commonly ignored directories
-                    if !self.should_ignore_directory(&dir_name) {
+                    if !self.should_ignore_directory(&path, &dir_name) {
                         dir_subdirectory_count += 1;
 
                         // Recursively scan subdirectories
@@ -222,15 +245,12 @@ impl StructureExtractor {
         }
     }
 
-    fn should_ignore_directory(&self, dir_name: &str) -> bool {
+    fn should_ignore_directory(&self, path: &Path, dir_name: &str) -> bool {
         let config = &self.context.config;
-        let dir_name_lower = dir_name.to_lowercase();
 
-        // Check excluded directories configured in Config
-        for excluded_dir in &config.excluded_dirs {
-            if dir_name_lower == excluded_dir.to_lowercase() {
-                return true;
-            }
+        // Check excluded directories configured in Config, .gitignore and .lithoignore
+        if self.ignore_rules.is_ignored(path, true) {
+            return true;
         }
 
         // Check if it's a test directory (if not including test files)
@@ -254,19 +274,9 @@ impl StructureExtractor {
             .unwrap_or("")
             .to_lowercase();
 
-        let _path_str = path.to_string_lossy().to_lowercase();
-
-        // Check excluded files
-        for excluded_file in &config.excluded_files {
-            if excluded_file.contains('*') {
-                // Simple wildcard matching
-                let pattern = excluded_file.replace('*', "");
-                if file_name.contains(&pattern.to_lowercase()) {
-                    return true;
-                }
-            } else if file_name == excluded_file.to_lowercase() {
-                return true;
-            }
+        // Check excluded files configured in Config, .gitignore and .lithoignore
+        if self.ignore_rules.is_ignored(path, false) {
+            return true;
         }
 
         // Check excluded extensions
@@ -322,7 +332,10 @@ impl StructureExtractor {
         &self,
         files: &mut [FileInfo],
         directories: &mut [DirectoryInfo],
+        git_history: Option<&GitHistory>,
     ) {
+        let git_weight = self.context.config.git_history.weight.clamp(0.0, 1.0);
+
         // Calculate file importance scores
         for file in files.iter_mut() {
             let mut score: f64 = 0.0;
@@ -372,6 +385,11 @@ impl StructureExtractor {
                 }
             }
 
+            // Blend in git activity so hotspots rise and stale (e.g. vendored) files sink
+            if let Some(activity) = git_history.and_then(|h| h.activity_score(&file.path)) {
+                score = (1.0 - git_weight) * score.min(1.0) + git_weight * activity;
+            }
+
             file.importance_score = score.min(1.0);
             file.is_core = score > 0.5;
         }
@@ -404,23 +422,34 @@ impl StructureExtractor {
     }
 
     /// Identify core files
+    ///
+    /// With `identify_components` enabled, the heuristic importance score is combined with the
+    /// centrality of each file in the import graph and only the top `core_component_percentage`
+    /// of candidate files are selected. Otherwise every file flagged `is_core` is selected.
     pub async fn identify_core_codes(
         &self,
         structure: &ProjectStructure,
+        import_graph: Option<&ImportGraph>,
     ) -> Result<Vec<CodeDossier>> {
         let mut core_codes = Vec::new();
 
-        // Filter core files based on importance score
-        let mut core_files: Vec<_> = structure.files.iter().filter(|f| f.is_core).collect();
-
-        // Sort by importance score in descending order, ensuring the most important components are processed first
-        core_files.sort_by(|a, b| {
-            b.importance_score
-                .partial_cmp(&a.importance_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        let core_files: Vec<(&FileInfo, f64)> = if self.context.config.identify_components {
+            self.rank_core_files(structure, import_graph)
+        } else {
+            // Filter core files based on importance score
+            let mut core_files: Vec<_> = structure
+                .files
+                .iter()
+                .filter(|f| f.is_core)
+                .map(|f| (f, f.importance_score))
+                .collect();
+
+            // Sort by importance score in descending order, ensuring the most important components are processed first
+            core_files.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            core_files
+        };
 
-        for file in core_files {
+        for (file, importance_score) in core_files {
             let code_purpose = self.determine_code_purpose(file).await;
 
             // Extract interface information
@@ -436,7 +465,7 @@ impl StructureExtractor {
                 file_path: file.path.clone(),
                 source_summary,
                 code_purpose,
-                importance_score: file.importance_score,
+                importance_score,
                 description: None,           // Filled later through LLM analysis
                 functions: Vec::new(),       // Filled later through code analysis
                 interfaces: interface_names, // Interface names extracted from code analysis
@@ -446,6 +475,71 @@ impl StructureExtractor {
         Ok(core_codes)
     }
 
+    /// Rank candidate files by heuristic importance and import graph centrality, keeping the top
+    /// `core_component_percentage` of them (at least `MIN_CORE_FILES`)
+    fn rank_core_files<'a>(
+        &self,
+        structure: &'a ProjectStructure,
+        import_graph: Option<&ImportGraph>,
+    ) -> Vec<(&'a FileInfo, f64)> {
+        let centrality = import_graph
+            .map(|graph| graph.centrality_scores())
+            .unwrap_or_default();
+
+        // Candidates are heuristic core files plus source files taking part in the import graph
+        let mut candidates: Vec<(&FileInfo, f64)> = structure
+            .files
+            .iter()
+            .filter_map(|file| {
+                let file_centrality = centrality
+                    .get(&normalize_path(&file.path.to_string_lossy()))
+                    .copied()
+                    .unwrap_or_default();
+                let is_candidate = file.is_core
+                    || (file_centrality > 0.0
+                        && self.language_processor.get_processor(&file.path).is_some());
+                if !is_candidate {
+                    return None;
+                }
+
+                let score = if centrality.is_empty() {
+                    file.importance_score
+                } else {
+                    CENTRALITY_WEIGHT * file_centrality
+                        + (1.0 - CENTRALITY_WEIGHT) * file.importance_score
+                };
+                Some((file, score))
+            })
+            .collect();
+
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let percentage = self.context.config.core_component_percentage.clamp(0.0, 100.0);
+        let limit = ((candidates.len() as f64 * percentage / 100.0).ceil() as usize).max(MIN_CORE_FILES);
+        candidates.truncate(limit);
+        candidates
+    }
+
+    /// Build the file-level import graph of the project
+    pub fn build_import_graph(&self, structure: &ProjectStructure) -> ImportGraph {
+        ImportGraph::build(&structure.root_path, &structure.files, &self.language_processor)
+    }
+
+    /// Statically extract the HTTP routes declared in the project
+    pub fn extract_routes(&self, structure: &ProjectStructure) -> ExtractedRoutes {
+        ExtractedRoutes::extract(&structure.root_path, &structure.files, &self.language_processor)
+    }
+
+    /// Index the symbols defined in the project and where they are referenced
+    pub fn build_symbol_index(&self, structure: &ProjectStructure, previous: Option<&SymbolIndex>) -> SymbolIndex {
+        SymbolIndexer::new().build(&structure.root_path, &structure.files, &self.language_processor, previous)
+    }
+
+    /// Map the project's tests to the source files they exercise
+    pub fn map_tests(&self, structure: &ProjectStructure) -> TestMapping {
+        TestMapper::new().map(&self.context.config, structure, &self.language_processor)
+    }
+
     async fn determine_code_purpose(&self, file: &FileInfo) -> CodePurpose {
         // Read file content
         let file_content = std::fs::read_to_string(&file.path).ok();
@@ -0,0 +1,338 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use regex::Regex;
+
+use crate::generator::preprocess::extractors::import_graph::normalize_path;
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::FileInfo;
+use crate::types::symbol_index::{FileStamp, SymbolDefinition, SymbolIndex, SymbolReference};
+
+/// Number of references stored per symbol, the total is still counted
+const MAX_STORED_REFERENCES: usize = 50;
+/// Shorter names are indexed but not cross-referenced, they match too many unrelated identifiers
+const MIN_REFERENCED_NAME_LEN: usize = 3;
+/// Lines scanned after a definition for its opening brace before falling back to indentation
+const MAX_BRACE_LOOKAHEAD: usize = 5;
+
+/// Languages whose blocks are delimited by indentation rather than braces
+const INDENTATION_BASED_EXTENSIONS: [&str; 2] = [".py", ".pyi"];
+
+/// Interface kinds that extend a definition made elsewhere (`impl Trait for Type`) rather than defining a symbol
+const NON_DEFINITION_KINDS: [&str; 1] = ["implementation"];
+
+/// Keywords introducing a definition on the same line, across the supported languages
+const DEFINITION_KEYWORDS: [&str; 24] = [
+    "fn", "def", "class", "struct", "trait", "enum", "interface", "function", "func", "type",
+    "impl", "record", "object", "protocol", "extension", "const", "let", "var", "val", "module",
+    "mod", "typealias", "init", "fun",
+];
+
+/// Builds the symbol index from the definitions reported by the language processors
+pub struct SymbolIndexer {
+    identifier_regex: Regex,
+}
+
+impl SymbolIndexer {
+    pub fn new() -> Self {
+        Self {
+            identifier_regex: Regex::new(r"[A-Za-z_$][A-Za-z0-9_$]*").unwrap(),
+        }
+    }
+
+    /// Index the definitions of the project files and their references.
+    /// Definitions of files unchanged since `previous` was built are reused.
+    pub fn build(
+        &self,
+        root_path: &Path,
+        files: &[FileInfo],
+        language_processor: &LanguageProcessorManager,
+        previous: Option<&SymbolIndex>,
+    ) -> SymbolIndex {
+        let mut previous_symbols: HashMap<&str, Vec<&SymbolDefinition>> = HashMap::new();
+        if let Some(previous) = previous {
+            for symbol in &previous.symbols {
+                previous_symbols.entry(symbol.file.as_str()).or_default().push(symbol);
+            }
+        }
+
+        let mut index = SymbolIndex::default();
+        let mut contents: Vec<(String, String)> = Vec::new();
+        for file in files {
+            let path = normalize_path(&file.path.to_string_lossy());
+            let full_path = root_path.join(&path);
+            if language_processor.get_processor(&full_path).is_none() {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&full_path) else {
+                continue;
+            };
+            let stamp = Self::stamp(&full_path);
+
+            let unchanged = previous
+                .and_then(|p| p.files.get(&path))
+                .is_some_and(|old| Some(old) == stamp.as_ref());
+            if unchanged {
+                index.symbols.extend(
+                    previous_symbols
+                        .get(path.as_str())
+                        .into_iter()
+                        .flatten()
+                        .map(|symbol| SymbolDefinition {
+                            references: Vec::new(),
+                            reference_count: 0,
+                            ..(*symbol).clone()
+                        }),
+                );
+            } else {
+                index.symbols.extend(self.definitions(&path, &full_path, &content, language_processor));
+            }
+
+            if let Some(stamp) = stamp {
+                index.files.insert(path.clone(), stamp);
+            }
+            contents.push((path, content));
+        }
+
+        self.cross_reference(&mut index, &contents);
+        index
+    }
+
+    /// Definitions of one file with their line spans
+    fn definitions(
+        &self,
+        path: &str,
+        full_path: &Path,
+        content: &str,
+        language_processor: &LanguageProcessorManager,
+    ) -> Vec<SymbolDefinition> {
+        let lines: Vec<&str> = content.lines().collect();
+        let indentation_based = INDENTATION_BASED_EXTENSIONS.iter().any(|ext| path.ends_with(ext));
+        let mut used_lines: HashSet<usize> = HashSet::new();
+        let mut definitions = Vec::new();
+
+        // Line and column of every identifier outside comments
+        let mut mentions: HashMap<&str, Vec<(usize, usize)>> = HashMap::new();
+        for (i, line) in lines.iter().enumerate().filter(|(_, line)| !Self::is_comment(line)) {
+            for m in self.identifier_regex.find_iter(line) {
+                mentions.entry(m.as_str()).or_default().push((i, m.start()));
+            }
+        }
+
+        for interface in language_processor.extract_interfaces(full_path, content) {
+            if interface.name.is_empty() || NON_DEFINITION_KINDS.contains(&interface.interface_type.as_str()) {
+                continue;
+            }
+            let Some(name_mentions) = mentions.get(interface.name.as_str()) else {
+                continue;
+            };
+            let Some(start) = Self::locate_definition(&lines, &interface.name, name_mentions, &used_lines) else {
+                continue;
+            };
+            used_lines.insert(start);
+            definitions.push(SymbolDefinition {
+                name: interface.name,
+                kind: interface.interface_type,
+                file: path.to_string(),
+                start_line: start + 1,
+                end_line: Self::definition_end(&lines, start, indentation_based) + 1,
+                visibility: interface.visibility,
+                references: Vec::new(),
+                reference_count: 0,
+            });
+        }
+
+        definitions
+    }
+
+    /// Line index of a definition: a keyword-introduced line first, then a declaration-like line, then any mention
+    fn locate_definition(
+        lines: &[&str],
+        name: &str,
+        mentions: &[(usize, usize)],
+        used_lines: &HashSet<usize>,
+    ) -> Option<usize> {
+        let mentions: Vec<(usize, &str)> = mentions
+            .iter()
+            .filter(|(i, _)| !used_lines.contains(i))
+            .map(|&(i, start)| (i, &lines[i][..start]))
+            .collect();
+
+        let keyword_line = mentions.iter().find(|(_, before)| {
+            before
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| DEFINITION_KEYWORDS.contains(&word))
+        });
+        let declaration_line = || {
+            mentions.iter().find(|(i, before)| {
+                let after = lines[*i][before.len() + name.len()..].trim_start();
+                !before.trim_start().starts_with("return")
+                    && !before.contains('=')
+                    && (after.starts_with('(') || after.starts_with('<') || after.starts_with(':'))
+            })
+        };
+
+        keyword_line
+            .or_else(declaration_line)
+            .or(mentions.first())
+            .map(|(i, _)| *i)
+    }
+
+    /// Last line index of the definition starting at `start`, by brace matching or else by indentation
+    fn definition_end(lines: &[&str], start: usize, indentation_based: bool) -> usize {
+        let mut depth = 0usize;
+        let mut opened = false;
+        let brace_lines = if indentation_based { 0 } else { lines.len() };
+        for (i, line) in lines.iter().enumerate().skip(start).take(brace_lines) {
+            for c in line.chars() {
+                match c {
+                    '{' => {
+                        depth += 1;
+                        opened = true;
+                    }
+                    '}' => depth = depth.saturating_sub(1),
+                    ';' if !opened && depth == 0 => return i,
+                    _ => {}
+                }
+            }
+            if opened && depth == 0 {
+                return i;
+            }
+            if !opened && i >= start + MAX_BRACE_LOOKAHEAD {
+                break;
+            }
+        }
+
+        // Indentation-based languages: the block ends before the next line indented no deeper than the definition
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let start_indent = indent(lines[start]);
+        let mut end = start;
+        for (i, line) in lines.iter().enumerate().skip(start + 1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if indent(line) <= start_indent {
+                break;
+            }
+            end = i;
+        }
+        end
+    }
+
+    /// Attach to each symbol the places its name is mentioned, definition lines excluded
+    fn cross_reference(&self, index: &mut SymbolIndex, contents: &[(String, String)]) {
+        let names: HashSet<&str> = index
+            .symbols
+            .iter()
+            .map(|s| s.name.as_str())
+            .filter(|name| name.len() >= MIN_REFERENCED_NAME_LEN)
+            .collect();
+        let definition_lines: HashSet<(&str, &str, usize)> = index
+            .symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.file.as_str(), s.start_line))
+            .collect();
+
+        let mut references: BTreeMap<&str, Vec<SymbolReference>> = BTreeMap::new();
+        for (path, content) in contents {
+            for (i, line) in content.lines().enumerate() {
+                if Self::is_comment(line) {
+                    continue;
+                }
+                let mut seen_on_line = HashSet::new();
+                for m in self.identifier_regex.find_iter(line) {
+                    let name = m.as_str();
+                    if names.contains(name)
+                        && seen_on_line.insert(name)
+                        && !definition_lines.contains(&(name, path.as_str(), i + 1))
+                    {
+                        references.entry(name).or_default().push(SymbolReference {
+                            file: path.clone(),
+                            line: i + 1,
+                        });
+                    }
+                }
+            }
+        }
+
+        let references: HashMap<String, Vec<SymbolReference>> = references
+            .into_iter()
+            .map(|(name, refs)| (name.to_string(), refs))
+            .collect();
+        for symbol in &mut index.symbols {
+            if let Some(refs) = references.get(&symbol.name) {
+                symbol.reference_count = refs.len();
+                symbol.references = refs.iter().take(MAX_STORED_REFERENCES).cloned().collect();
+            }
+        }
+    }
+
+    fn is_comment(line: &str) -> bool {
+        let trimmed = line.trim_start();
+        trimmed.starts_with("//") || trimmed.starts_with('#') && !trimmed.starts_with("#[") || trimmed.starts_with('*')
+    }
+
+    fn stamp(path: &Path) -> Option<FileStamp> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(FileStamp {
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::{TempProject, file_info};
+
+    #[test]
+    fn test_build_symbol_index() {
+        let root = TempProject::new("symbol_index");
+        root.write(
+            "src/store.rs",
+            "/// Order storage\npub struct OrderStore {\n    orders: Vec<u32>,\n}\n\nimpl OrderStore {\n    pub fn insert_order(&mut self, id: u32) {\n        self.orders.push(id);\n    }\n}\n",
+        );
+        root.write(
+            "src/service.py",
+            "from store import OrderStore\n\nclass OrderService:\n    def place(self, store):\n        store.insert_order(1)\n\n\ndef helper():\n    return OrderService()\n",
+        );
+
+        let files = vec![file_info("src/store.rs"), file_info("src/service.py")];
+        let indexer = SymbolIndexer::new();
+        let index = indexer.build(root.path(), &files, &LanguageProcessorManager::new(), None);
+
+        let store = index.lookup("OrderStore");
+        assert_eq!(store.len(), 1);
+        assert_eq!((store[0].start_line, store[0].end_line), (2, 4));
+        assert_eq!(store[0].visibility, "public");
+        assert_eq!(store[0].location(), "src/store.rs#L2-L4");
+        assert_eq!(
+            store[0].references,
+            vec![
+                SymbolReference { file: "src/store.rs".to_string(), line: 6 },
+                SymbolReference { file: "src/service.py".to_string(), line: 1 },
+            ]
+        );
+
+        let insert = index.lookup("insert_order");
+        assert_eq!((insert[0].start_line, insert[0].end_line), (7, 9));
+        assert_eq!(insert[0].reference_count, 1);
+
+        let service = index.lookup("orderservice");
+        assert_eq!(service.len(), 1);
+        assert_eq!((service[0].start_line, service[0].end_line), (3, 5));
+        assert_eq!(service[0].references[0].line, 9);
+
+        // Unchanged files keep their definitions when rebuilt from the persisted index
+        let path = root.join(SymbolIndex::FILE_NAME);
+        index.save(&path).unwrap();
+        let previous = SymbolIndex::load(&path).unwrap();
+        let rebuilt = indexer.build(root.path(), &files, &LanguageProcessorManager::new(), Some(&previous));
+        assert_eq!(rebuilt.symbols.len(), index.symbols.len());
+        assert_eq!(rebuilt.lookup("OrderStore")[0].reference_count, 2);
+
+    }
+}
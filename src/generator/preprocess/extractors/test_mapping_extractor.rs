@@ -0,0 +1,274 @@
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::config::Config;
+use crate::generator::preprocess::extractors::import_graph::{ImportGraph, normalize_path};
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::FileInfo;
+use crate::types::project_structure::ProjectStructure;
+use crate::types::test_mapping::{TestFile, TestMapping};
+use crate::utils::file_utils::{is_test_directory, is_test_file};
+use crate::utils::ignore_rules::IgnoreRules;
+
+/// Test name affixes stripped to find the file under test (`test_parser.py`, `parser.spec.ts`, `ParserTest.java`)
+const TEST_PREFIXES: [&str; 2] = ["test_", "test-"];
+const TEST_SUFFIXES: [&str; 10] = [
+    "_test", "_tests", "-test", "-spec", ".test", ".spec", "_spec", "tests", "test", "spec",
+];
+
+/// Scans test files and maps them to the source files they exercise
+pub struct TestMapper {
+    test_case_regex: Regex,
+}
+
+impl TestMapper {
+    pub fn new() -> Self {
+        Self {
+            test_case_regex: Regex::new(
+                r#"(?m)^\s*(?:#\[(?:tokio::|async_std::|rstest|test_case)?\w*test\b[^\]]*\]|(?:async\s+)?def\s+test_\w*|(?:it|test)(?:\.each\([^)]*\))?\s*\(\s*['"`]|@(?:Test|ParameterizedTest)\b|func\s+Test\w*\s*\(|\[(?:Fact|Theory|Test|TestMethod|TestCase)\b)"#,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Map the project's tests (test files and inline test modules) to the source files they exercise
+    pub fn map(
+        &self,
+        config: &Config,
+        structure: &ProjectStructure,
+        language_processor: &LanguageProcessorManager,
+    ) -> TestMapping {
+        let is_code = |path: &str| language_processor.get_processor(Path::new(path)).is_some();
+
+        let source_files: Vec<String> = structure
+            .files
+            .iter()
+            .map(|file| normalize_path(&file.path.to_string_lossy()))
+            .filter(|path| is_code(path) && !Self::is_test_path(path))
+            .collect();
+        let test_paths: Vec<String> = self
+            .find_test_files(config, &structure.root_path)
+            .into_iter()
+            .filter(|path| is_code(path))
+            .collect();
+
+        // Resolve test imports against the source files
+        let graph_files: Vec<FileInfo> = source_files
+            .iter()
+            .chain(&test_paths)
+            .map(|path| Self::file_info(path))
+            .collect();
+        let graph = ImportGraph::build(&structure.root_path, &graph_files, language_processor);
+        let test_set: HashSet<&str> = test_paths.iter().map(String::as_str).collect();
+        let mut imported: HashMap<&str, Vec<String>> = HashMap::new();
+        for edge in &graph.edges {
+            if test_set.contains(edge.from.as_str()) && !test_set.contains(edge.to.as_str()) {
+                imported.entry(edge.from.as_str()).or_default().push(edge.to.clone());
+            }
+        }
+
+        let sources_by_stem = Self::index_by_stem(&source_files);
+        let mut test_files = Vec::new();
+        for path in &test_paths {
+            let content = std::fs::read_to_string(structure.root_path.join(path)).unwrap_or_default();
+            let mut targets = imported.remove(path.as_str()).unwrap_or_default();
+            for target in Self::match_by_name(path, &sources_by_stem) {
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+            targets.sort();
+            test_files.push(TestFile {
+                path: path.clone(),
+                test_count: self.count_test_cases(&content),
+                targets,
+            });
+        }
+
+        let mut inline_tests = BTreeMap::new();
+        for path in source_files.iter().filter(|p| p.ends_with(".rs")) {
+            let content = std::fs::read_to_string(structure.root_path.join(path)).unwrap_or_default();
+            if content.contains("#[cfg(test)]") {
+                inline_tests.insert(path.clone(), self.count_test_cases(&content));
+            }
+        }
+
+        TestMapping {
+            source_files,
+            test_files,
+            inline_tests,
+        }
+    }
+
+    fn count_test_cases(&self, content: &str) -> usize {
+        self.test_case_regex.find_iter(content).count()
+    }
+
+    fn is_test_path(path: &str) -> bool {
+        let mut segments: Vec<&str> = path.split('/').collect();
+        segments.pop();
+        is_test_file(Path::new(path)) || segments.iter().any(|dir| is_test_directory(dir))
+    }
+
+    /// Walk the project for test files, which the structure extractor skips unless `include_tests` is set
+    fn find_test_files(&self, config: &Config, root: &Path) -> Vec<String> {
+        // Default exclusions such as `__tests__` must not hide the tests being mapped
+        let mut scan_config = config.clone();
+        scan_config.excluded_dirs.retain(|dir| !is_test_directory(dir));
+        let ignore_rules = IgnoreRules::load(&scan_config);
+
+        let mut test_files: Vec<String> = WalkDir::new(root)
+            .max_depth(config.max_depth.into())
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0 || !ignore_rules.is_ignored(entry.path(), entry.file_type().is_dir())
+            })
+            .flatten()
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let relative = entry.path().strip_prefix(root).ok()?;
+                let relative = normalize_path(&relative.to_string_lossy());
+                Self::is_test_path(&relative).then_some(relative)
+            })
+            .collect();
+        test_files.sort();
+        test_files
+    }
+
+    fn file_info(path: &str) -> FileInfo {
+        FileInfo {
+            path: PathBuf::from(path),
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            size: 0,
+            extension: path.rsplit_once('.').map(|(_, e)| e.to_string()),
+            is_core: false,
+            importance_score: 0.0,
+            complexity_score: 0.0,
+            last_modified: None,
+        }
+    }
+
+    fn stem(path: &str) -> String {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        name.split('.').next().unwrap_or(name).to_lowercase()
+    }
+
+    fn index_by_stem(source_files: &[String]) -> HashMap<String, Vec<&str>> {
+        let mut index: HashMap<String, Vec<&str>> = HashMap::new();
+        for path in source_files {
+            index.entry(Self::stem(path)).or_default().push(path);
+        }
+        index
+    }
+
+    /// Source files named like the test file without its test affixes, preferring the closest directories
+    fn match_by_name(test_path: &str, sources_by_stem: &HashMap<String, Vec<&str>>) -> Vec<String> {
+        let name = test_path.rsplit('/').next().unwrap_or(test_path).to_lowercase();
+        let mut stem = name.rsplit_once('.').map(|(s, _)| s).unwrap_or(&name);
+        for prefix in TEST_PREFIXES {
+            stem = stem.strip_prefix(prefix).unwrap_or(stem);
+        }
+        for suffix in TEST_SUFFIXES {
+            if let Some(stripped) = stem.strip_suffix(suffix)
+                && !stripped.is_empty()
+            {
+                stem = stripped;
+                break;
+            }
+        }
+        let stem = stem.split('.').next().unwrap_or(stem);
+
+        let Some(candidates) = sources_by_stem.get(stem) else {
+            return Vec::new();
+        };
+        let shared_dirs = |source: &str| {
+            let test_dirs: HashSet<&str> = test_path.split('/').collect();
+            source.split('/').filter(|segment| test_dirs.contains(segment)).count()
+        };
+        let best = candidates.iter().map(|c| shared_dirs(c)).max().unwrap_or_default();
+        candidates
+            .iter()
+            .filter(|c| shared_dirs(c) == best)
+            .map(|c| c.to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(paths: &[&'static str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_match_by_name() {
+        let source_files = sources(&[
+            "src/parser.py",
+            "app/models/user.py",
+            "lib/models/user.py",
+            "web/src/api/client.ts",
+            "src/main/java/com/acme/OrderService.java",
+        ]);
+        let index = TestMapper::index_by_stem(&source_files);
+
+        assert_eq!(TestMapper::match_by_name("tests/test_parser.py", &index), vec!["src/parser.py"]);
+        assert_eq!(
+            TestMapper::match_by_name("app/tests/models/test_user.py", &index),
+            vec!["app/models/user.py"]
+        );
+        assert_eq!(
+            TestMapper::match_by_name("web/src/api/client.spec.ts", &index),
+            vec!["web/src/api/client.ts"]
+        );
+        assert_eq!(
+            TestMapper::match_by_name("src/test/java/com/acme/OrderServiceTest.java", &index),
+            vec!["src/main/java/com/acme/OrderService.java"]
+        );
+        assert!(TestMapper::match_by_name("tests/test_unknown.py", &index).is_empty());
+    }
+
+    #[test]
+    fn test_count_test_cases() {
+        let mapper = TestMapper::new();
+        let content = r#"
+#[test]
+fn a() {}
+#[tokio::test]
+async fn b() {}
+def test_c(): pass
+it('does d', () => {});
+test("e", () => {});
+@Test
+func TestF(t *testing.T) {}
+[Fact]
+"#;
+        assert_eq!(mapper.count_test_cases(content), 8);
+        assert!(TestMapper::is_test_path("src/__tests__/a.ts"));
+        assert!(TestMapper::is_test_path("tests/integration.rs"));
+        assert!(!TestMapper::is_test_path("src/latest.rs"));
+    }
+
+    #[test]
+    fn test_coverage_of_paths() {
+        let mapping = TestMapping {
+            source_files: sources(&["src/a/x.rs", "src/a/y.rs", "src/ab/z.rs", "src/c.rs"]),
+            test_files: vec![TestFile {
+                path: "tests/x_test.rs".to_string(),
+                test_count: 2,
+                targets: vec!["src/a/x.rs".to_string()],
+            }],
+            inline_tests: BTreeMap::from([("src/c.rs".to_string(), 1)]),
+        };
+
+        let coverage = mapping.coverage_of_paths(&["src/a/".to_string(), "./src/c.rs".to_string()]);
+        assert_eq!(coverage.source_files, 3);
+        assert_eq!(coverage.tested_files, 2);
+        assert_eq!(coverage.untested_files, vec!["src/a/y.rs"]);
+        assert_eq!(coverage.level(), "Well tested");
+        assert_eq!(mapping.total_test_count(), 3);
+    }
+}
@@ -0,0 +1,461 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::types::project_structure::ProjectStructure;
+use crate::types::workspace::{WorkspaceInfo, WorkspaceMember, WorkspaceTool};
+
+/// Member discovered from a workspace manifest, with unresolved references to other members
+struct PendingMember {
+    member: WorkspaceMember,
+    /// Package names (or Gradle project paths) referenced by the member manifest
+    references: Vec<String>,
+}
+
+/// Detect workspace/monorepo members declared by root manifests of the project
+pub fn extract(project_structure: &ProjectStructure) -> Option<WorkspaceInfo> {
+    let root = project_structure.root_path.as_path();
+
+    let mut root_manifests = Vec::new();
+    let mut pending = Vec::new();
+
+    for (manifest, detect) in [
+        ("Cargo.toml", detect_cargo_members as fn(&Path, &str) -> Vec<PendingMember>),
+        ("package.json", detect_npm_members),
+        ("pnpm-workspace.yaml", detect_pnpm_members),
+        ("pom.xml", detect_maven_members),
+        ("settings.gradle", detect_gradle_members),
+        ("settings.gradle.kts", detect_gradle_members),
+        ("go.work", detect_go_members),
+    ] {
+        let Ok(content) = std::fs::read_to_string(root.join(manifest)) else {
+            continue;
+        };
+        let members = detect(root, &content);
+        if !members.is_empty() {
+            root_manifests.push(manifest.to_string());
+            pending.extend(members);
+        }
+    }
+
+    if pending.is_empty() {
+        return None;
+    }
+
+    // npm and pnpm may declare the same packages
+    let mut seen = HashSet::new();
+    pending.retain(|p| seen.insert(p.member.path.clone()));
+
+    let members = resolve_member_dependencies(pending)
+        .into_iter()
+        .map(|mut member| {
+            let prefix = format!("{}/", member.path);
+            member.file_count = project_structure
+                .files
+                .iter()
+                .filter(|file| file.path.to_string_lossy().replace('\\', "/").starts_with(&prefix))
+                .count();
+            member
+        })
+        .collect();
+
+    Some(WorkspaceInfo {
+        root_manifests,
+        members,
+    })
+}
+
+fn resolve_member_dependencies(pending: Vec<PendingMember>) -> Vec<WorkspaceMember> {
+    let lookup: Vec<(String, String, WorkspaceTool)> = pending
+        .iter()
+        .map(|p| (p.member.name.clone(), p.member.path.clone(), p.member.tool))
+        .collect();
+
+    pending
+        .into_iter()
+        .map(|PendingMember { mut member, references }| {
+            let mut depends_on: Vec<String> = lookup
+                .iter()
+                .filter(|(name, path, tool)| {
+                    *name != member.name
+                        && *tool == member.tool
+                        && references.iter().any(|reference| {
+                            reference == name
+                                // Gradle references projects by path, e.g. `:core:data`
+                                || (member.tool == WorkspaceTool::Gradle
+                                    && reference.trim_start_matches(':').replace(':', "/") == *path)
+                        })
+                })
+                .map(|(name, _, _)| name.clone())
+                .collect();
+            depends_on.sort();
+            depends_on.dedup();
+            member.depends_on = depends_on;
+            member
+        })
+        .collect()
+}
+
+/// Expand member glob patterns to directories containing the given manifest
+fn expand_member_patterns(root: &Path, patterns: &[String], manifest: &str) -> Vec<String> {
+    let excluded: Vec<glob::Pattern> = patterns
+        .iter()
+        .filter_map(|p| p.strip_prefix('!'))
+        .filter_map(|p| glob::Pattern::new(p.trim_start_matches("./")).ok())
+        .collect();
+
+    let mut directories = Vec::new();
+    for pattern in patterns.iter().filter(|p| !p.starts_with('!')) {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        let Ok(entries) = glob::glob(&root.join(pattern).to_string_lossy()) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            if !entry.join(manifest).is_file() {
+                continue;
+            }
+            let relative = entry
+                .strip_prefix(root)
+                .unwrap_or(&entry)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if relative.is_empty()
+                || relative.split('/').any(|segment| segment == "node_modules")
+                || excluded.iter().any(|e| e.matches(&relative))
+            {
+                continue;
+            }
+            if !directories.contains(&relative) {
+                directories.push(relative);
+            }
+        }
+    }
+
+    directories.sort();
+    directories
+}
+
+fn member_name_or_dir(name: Option<String>, directory: &str) -> String {
+    name.filter(|n| !n.trim().is_empty())
+        .unwrap_or_else(|| directory.rsplit('/').next().unwrap_or(directory).to_string())
+}
+
+// =========================== Cargo ===========================
+
+fn detect_cargo_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let Some(workspace) = manifest.get("workspace").and_then(|v| v.as_table()) else {
+        return Vec::new();
+    };
+
+    let string_list = |key: &str| -> Vec<String> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|i| i.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default()
+    };
+    let mut patterns = string_list("members");
+    patterns.extend(string_list("exclude").into_iter().map(|e| format!("!{}", e)));
+
+    expand_member_patterns(root, &patterns, "Cargo.toml")
+        .into_iter()
+        .filter_map(|directory| {
+            let manifest_path = format!("{}/Cargo.toml", directory);
+            let member: toml::Table = std::fs::read_to_string(root.join(&manifest_path))
+                .ok()?
+                .parse()
+                .ok()?;
+            let package = member.get("package");
+
+            let mut references = Vec::new();
+            for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+                if let Some(deps) = member.get(section).and_then(|v| v.as_table()) {
+                    for (key, spec) in deps {
+                        let name = spec.get("package").and_then(|p| p.as_str()).unwrap_or(key);
+                        references.push(name.to_string());
+                    }
+                }
+            }
+
+            Some(PendingMember {
+                member: WorkspaceMember {
+                    name: member_name_or_dir(
+                        package.and_then(|p| p.get("name")).and_then(|n| n.as_str()).map(|s| s.to_string()),
+                        &directory,
+                    ),
+                    description: package
+                        .and_then(|p| p.get("description"))
+                        .and_then(|d| d.as_str())
+                        .map(|s| s.to_string()),
+                    path: directory,
+                    manifest: manifest_path,
+                    tool: WorkspaceTool::Cargo,
+                    depends_on: Vec::new(),
+                    file_count: 0,
+                },
+                references,
+            })
+        })
+        .collect()
+}
+
+// =========================== JavaScript ===========================
+
+fn detect_npm_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let Ok(package) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    // `"workspaces": [...]` or yarn's `"workspaces": { "packages": [...] }`
+    let workspaces = package.get("workspaces");
+    let patterns: Vec<String> = workspaces
+        .and_then(|w| w.as_array().or_else(|| w.get("packages").and_then(|p| p.as_array())))
+        .map(|items| items.iter().filter_map(|i| i.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default();
+
+    npm_members(root, &patterns, WorkspaceTool::Npm)
+}
+
+fn detect_pnpm_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(' ') && !line.starts_with('-') {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages && let Some(item) = trimmed.strip_prefix('-') {
+            patterns.push(item.trim().trim_matches(|c| c == '"' || c == '\'').to_string());
+        }
+    }
+
+    npm_members(root, &patterns, WorkspaceTool::Pnpm)
+}
+
+fn npm_members(root: &Path, patterns: &[String], tool: WorkspaceTool) -> Vec<PendingMember> {
+    expand_member_patterns(root, patterns, "package.json")
+        .into_iter()
+        .filter_map(|directory| {
+            let manifest_path = format!("{}/package.json", directory);
+            let package: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(root.join(&manifest_path)).ok()?).ok()?;
+
+            let references = [
+                "dependencies",
+                "devDependencies",
+                "peerDependencies",
+                "optionalDependencies",
+            ]
+            .iter()
+            .filter_map(|section| package.get(section).and_then(|v| v.as_object()))
+            .flat_map(|deps| deps.keys().cloned())
+            .collect();
+
+            Some(PendingMember {
+                member: WorkspaceMember {
+                    name: member_name_or_dir(
+                        package.get("name").and_then(|n| n.as_str()).map(|s| s.to_string()),
+                        &directory,
+                    ),
+                    description: package
+                        .get("description")
+                        .and_then(|d| d.as_str())
+                        .map(|s| s.to_string()),
+                    path: directory,
+                    manifest: manifest_path,
+                    tool,
+                    depends_on: Vec::new(),
+                    file_count: 0,
+                },
+                references,
+            })
+        })
+        .collect()
+}
+
+// =========================== JVM ===========================
+
+fn detect_maven_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let module_regex = Regex::new(r"<module>\s*([^<]+?)\s*</module>").unwrap();
+    let dependency_regex = Regex::new(r"(?s)<dependency>.*?<artifactId>\s*([^<]+?)\s*</artifactId>.*?</dependency>").unwrap();
+    let parent_regex = Regex::new(r"(?s)<parent>.*?</parent>").unwrap();
+    let dependencies_regex = Regex::new(r"(?s)<dependencies>.*?</dependencies>|<build>.*</build>|<dependencyManagement>.*?</dependencyManagement>").unwrap();
+
+    module_regex
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let directory = captures[1].trim_start_matches("./").trim_end_matches('/').to_string();
+            let manifest_path = format!("{}/pom.xml", directory);
+            let pom = std::fs::read_to_string(root.join(&manifest_path)).ok()?;
+
+            // The module's own coordinates are outside <parent> and dependency blocks
+            let own = parent_regex.replace_all(&pom, "");
+            let own = dependencies_regex.replace_all(&own, "");
+            let artifact_id = xml_tag(&own, "artifactId");
+            let description = xml_tag(&own, "description");
+
+            let references = dependency_regex
+                .captures_iter(&pom)
+                .map(|c| c[1].to_string())
+                .collect();
+
+            Some(PendingMember {
+                member: WorkspaceMember {
+                    name: member_name_or_dir(artifact_id, &directory),
+                    description,
+                    path: directory,
+                    manifest: manifest_path,
+                    tool: WorkspaceTool::Maven,
+                    depends_on: Vec::new(),
+                    file_count: 0,
+                },
+                references,
+            })
+        })
+        .collect()
+}
+
+fn xml_tag(content: &str, tag: &str) -> Option<String> {
+    let start = content.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = content[start..].find(&format!("</{}>", tag))? + start;
+    Some(content[start..end].trim().to_string())
+}
+
+fn detect_gradle_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let include_regex = Regex::new(r#"(?m)^\s*include\s*\(?([^)\n]+)\)?"#).unwrap();
+    let project_regex = Regex::new(r#"["']([:\w\-.]+)["']"#).unwrap();
+    let reference_regex = Regex::new(r#"project\s*\(\s*(?:path\s*[:=]\s*)?["']([:\w\-.]+)["']"#).unwrap();
+
+    let mut project_paths = Vec::new();
+    for include in include_regex.captures_iter(content) {
+        for project in project_regex.captures_iter(&include[1]) {
+            project_paths.push(project[1].to_string());
+        }
+    }
+
+    project_paths
+        .into_iter()
+        .filter_map(|project_path| {
+            let directory = project_path.trim_start_matches(':').replace(':', "/");
+            let manifest_path = ["build.gradle.kts", "build.gradle"]
+                .iter()
+                .map(|file| format!("{}/{}", directory, file))
+                .find(|path| root.join(path).is_file())?;
+            let build = std::fs::read_to_string(root.join(&manifest_path)).unwrap_or_default();
+
+            let references = reference_regex
+                .captures_iter(&build)
+                .map(|c| c[1].to_string())
+                .collect();
+
+            Some(PendingMember {
+                member: WorkspaceMember {
+                    name: member_name_or_dir(None, &directory),
+                    description: None,
+                    path: directory,
+                    manifest: manifest_path,
+                    tool: WorkspaceTool::Gradle,
+                    depends_on: Vec::new(),
+                    file_count: 0,
+                },
+                references,
+            })
+        })
+        .collect()
+}
+
+// =========================== Go ===========================
+
+fn detect_go_members(root: &Path, content: &str) -> Vec<PendingMember> {
+    let mut directories = Vec::new();
+    let mut in_use_block = false;
+
+    for line in content.lines() {
+        let trimmed = line.split("//").next().unwrap_or("").trim();
+        if in_use_block {
+            if trimmed == ")" {
+                in_use_block = false;
+            } else if !trimmed.is_empty() {
+                directories.push(trimmed.to_string());
+            }
+        } else if trimmed == "use (" {
+            in_use_block = true;
+        } else if let Some(directory) = trimmed.strip_prefix("use ") {
+            directories.push(directory.trim().to_string());
+        }
+    }
+
+    directories
+        .into_iter()
+        .filter_map(|directory| {
+            let directory = directory.trim_start_matches("./").trim_end_matches('/').to_string();
+            if directory.is_empty() || directory == "." {
+                return None;
+            }
+            let manifest_path = format!("{}/go.mod", directory);
+            let go_mod = std::fs::read_to_string(root.join(&manifest_path)).ok()?;
+
+            let module = go_mod
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("module "))
+                .map(|m| m.trim().to_string());
+            let references = go_mod
+                .lines()
+                .map(|line| line.trim().trim_start_matches("require ").trim())
+                .filter(|line| !line.starts_with("module "))
+                .filter_map(|line| line.split_whitespace().next().map(|m| m.to_string()))
+                .collect();
+
+            Some(PendingMember {
+                member: WorkspaceMember {
+                    name: member_name_or_dir(module, &directory),
+                    description: None,
+                    path: directory,
+                    manifest: manifest_path,
+                    tool: WorkspaceTool::Go,
+                    depends_on: Vec::new(),
+                    file_count: 0,
+                },
+                references,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempProject;
+
+    #[test]
+    fn test_detect_cargo_members() {
+        let root = TempProject::new("workspace");
+        root.write(
+            "crates/core/Cargo.toml",
+            "[package]\nname = \"app-core\"\ndescription = \"Core library\"\n",
+        );
+        root.write(
+            "crates/cli/Cargo.toml",
+            "[package]\nname = \"app-cli\"\n\n[dependencies]\napp-core = { path = \"../core\" }\nclap = \"4\"\n",
+        );
+
+        let pending = detect_cargo_members(root.path(), "[workspace]\nmembers = [\"crates/*\"]\n");
+        let members = resolve_member_dependencies(pending);
+
+        assert_eq!(members.len(), 2);
+        let cli = members.iter().find(|m| m.name == "app-cli").unwrap();
+        assert_eq!(cli.path, "crates/cli");
+        assert_eq!(cli.depends_on, vec!["app-core".to_string()]);
+        let core = members.iter().find(|m| m.name == "app-core").unwrap();
+        assert_eq!(core.description.as_deref(), Some("Core library"));
+        assert!(core.depends_on.is_empty());
+    }
+}
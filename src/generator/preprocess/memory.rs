@@ -11,4 +11,16 @@ impl ScopedKeys {
     pub const PROJECT_STRUCTURE: &'static str = "project_structure";
     pub const CODE_INSIGHTS: &'static str = "code_insights";
     pub const RELATIONSHIPS: &'static str = "relationships";
+    pub const IMPORT_GRAPH: &'static str = "import_graph";
+    pub const ARCHITECTURE_HEALTH: &'static str = "architecture_health";
+    pub const DEPENDENCY_INVENTORY: &'static str = "dependency_inventory";
+    pub const WORKSPACE: &'static str = "workspace";
+    pub const CLI_DEFINITIONS: &'static str = "cli_definitions";
+    pub const ROUTES: &'static str = "routes";
+    pub const CONFIGURATION: &'static str = "configuration";
+    pub const TEST_MAPPING: &'static str = "test_mapping";
+    pub const SYMBOL_INDEX: &'static str = "symbol_index";
+    pub const VECTOR_INDEX: &'static str = "vector_index";
+    pub const HIERARCHICAL_SUMMARY: &'static str = "hierarchical_summary";
+    pub const ARCHITECTURE_META: &'static str = "architecture_meta";
 }
\ No newline at end of file
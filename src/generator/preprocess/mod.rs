@@ -2,14 +2,23 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use tokio::time::Instant;
 
-use crate::generator::preprocess::extractors::original_document_extractor;
+use crate::generator::preprocess::extractors::architecture_health::ArchitectureHealthChecker;
+use crate::generator::preprocess::extractors::{
+    cli_extractor, configuration_extractor, dependency_manifest_extractor, original_document_extractor, workspace_extractor,
+};
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
+use crate::types::architecture_meta::ArchitectureMeta;
 use crate::types::original_document::OriginalDocument;
+use crate::types::symbol_index::SymbolIndex;
+use crate::types::vector_index::VectorIndex;
 use crate::{
     generator::{
         context::GeneratorContext,
         preprocess::{
-            agents::{code_analyze::CodeAnalyze, relationships_analyze::RelationshipsAnalyze},
+            agents::{
+                code_analyze::CodeAnalyze, code_retrieval::CodeRetrieval, hierarchical_summarize::HierarchicalSummarize,
+                relationships_analyze::RelationshipsAnalyze,
+            },
             extractors::structure_extractor::StructureExtractor,
         },
         types::Generator,
@@ -58,6 +67,22 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
         // 1. Extract project original document materials
         println!("📁 Extracting project original document materials...");
         let original_document = original_document_extractor::extract(&context).await?;
+        println!(
+            "   📚 Ingested {} documentation sources",
+            original_document.documents.len()
+        );
+
+        if let Some(meta_path) = config.architecture_meta_file() {
+            let architecture_meta = ArchitectureMeta::load(&meta_path)?;
+            println!("   📐 Loaded architecture meta description from {:?}", meta_path);
+            context
+                .store_to_memory(
+                    MemoryScope::PREPROCESS,
+                    ScopedKeys::ARCHITECTURE_META,
+                    &architecture_meta,
+                )
+                .await?;
+        }
 
         // 2. Extract project structure
         println!("📁 Extracting project structure...");
@@ -70,10 +95,151 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
             project_structure.total_files, project_structure.total_directories
         );
 
+        // Detect workspace/monorepo members
+        let workspace = workspace_extractor::extract(&project_structure);
+        if let Some(workspace) = &workspace {
+            println!(
+                "   📦 Detected workspace with {} members ({})",
+                workspace.members.len(),
+                workspace.root_manifests.join(", ")
+            );
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::WORKSPACE, workspace)
+                .await?;
+        }
+
+        // Statically extract CLI definitions as ground truth for boundary analysis
+        let cli_definitions = cli_extractor::extract(&project_structure);
+        if !cli_definitions.is_empty() {
+            println!("   ⌨️ Extracted {} CLI command definitions", cli_definitions.len());
+            context
+                .store_to_memory(
+                    MemoryScope::PREPROCESS,
+                    ScopedKeys::CLI_DEFINITIONS,
+                    &cli_definitions,
+                )
+                .await?;
+        }
+
+        // Statically extract HTTP routes as ground truth for boundary analysis
+        let routes = structure_extractor.extract_routes(&project_structure);
+        if !routes.is_empty() {
+            println!("   🌐 Extracted {} HTTP route definitions", routes.len());
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::ROUTES, &routes)
+                .await?;
+        }
+
+        // Inventory env vars, typed config keys and config files
+        let configuration = configuration_extractor::extract(&project_structure);
+        if !configuration.is_empty() {
+            println!(
+                "   ⚙️ Found {} environment variables, {} config keys and {} config files",
+                configuration.env_variables.len(),
+                configuration.config_keys.len(),
+                configuration.config_files.len()
+            );
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::CONFIGURATION, &configuration)
+                .await?;
+        }
+
+        // Build the symbol index, persisted so unchanged files are not re-parsed on the next run
+        let symbol_index_path = config.internal_path.join(SymbolIndex::FILE_NAME);
+        let previous_symbol_index = SymbolIndex::load(&symbol_index_path).ok();
+        let symbol_index =
+            structure_extractor.build_symbol_index(&project_structure, previous_symbol_index.as_ref());
+        println!(
+            "   🔖 Indexed {} symbols in {} files",
+            symbol_index.symbols.len(),
+            symbol_index.files.len()
+        );
+        if let Err(e) = symbol_index.save(&symbol_index_path) {
+            eprintln!("⚠️ Failed to save symbol index: {}", e);
+        }
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::SYMBOL_INDEX, &symbol_index)
+            .await?;
+
+        // Map tests to the code they exercise
+        if config.analyze_tests {
+            println!("🧪 Mapping tests to source files...");
+            let test_mapping = structure_extractor.map_tests(&project_structure);
+            println!(
+                "   Found {} test files and {} files with inline tests ({} test cases)",
+                test_mapping.test_files.len(),
+                test_mapping.inline_tests.len(),
+                test_mapping.total_test_count()
+            );
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::TEST_MAPPING, &test_mapping)
+                .await?;
+        }
+
+        // Build import graph and check architecture rules
+        let (import_graph, architecture_health) = if config.analyze_dependencies
+            || config.architecture_rules.requires_import_graph()
+        {
+            println!("🧭 Checking import cycles and layer rules...");
+            let import_graph = structure_extractor.build_import_graph(&project_structure);
+            let report = ArchitectureHealthChecker::new(&config.architecture_rules).check(&import_graph);
+
+            println!(
+                "   Resolved {} imports, found {} cycles and {} layer violations",
+                report.resolved_imports,
+                report.cycles.len(),
+                report.layer_violations.len()
+            );
+
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::IMPORT_GRAPH, &import_graph)
+                .await?;
+            context
+                .store_to_memory(
+                    MemoryScope::PREPROCESS,
+                    ScopedKeys::ARCHITECTURE_HEALTH,
+                    &report,
+                )
+                .await?;
+
+            // Fail the run (e.g. in CI) before any model is called when architecture rules are broken
+            if config.architecture_rules.fail_on_violation && report.has_violations() {
+                anyhow::bail!(
+                    "Architecture check failed: {} circular dependencies, {} layer violations",
+                    report.cycles.len(),
+                    report.layer_violations.len()
+                );
+            }
+            (Some(import_graph), Some(report))
+        } else {
+            (None, None)
+        };
+
+        // Build third-party dependency inventory from package manifests
+        let dependency_inventory = if config.analyze_dependencies {
+            println!("📦 Parsing package manifests...");
+            let inventory = dependency_manifest_extractor::extract(&context, &project_structure)?;
+            println!(
+                "   Found {} third-party dependencies in {} manifests",
+                inventory.dependencies.len(),
+                inventory.manifests.len()
+            );
+            context
+                .store_to_memory(
+                    MemoryScope::PREPROCESS,
+                    ScopedKeys::DEPENDENCY_INVENTORY,
+                    &inventory,
+                )
+                .await?;
+            Some(inventory)
+        } else {
+            None
+        };
+
         // 3. Identify core components
         println!("🎯 Identifying main source code files...");
         let important_codes = structure_extractor
-            .identify_core_codes(&project_structure)
+            .identify_core_codes(&project_structure, import_graph.as_ref())
             .await?;
 
         println!("   Identified {} main source code files", important_codes.len());
@@ -81,17 +247,74 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
         // 4. Analyze core components using AI
         println!("🤖 Analyzing core files using AI...");
         let code_analyze = CodeAnalyze::new();
-        let core_code_insights = code_analyze
+        let mut core_code_insights = code_analyze
             .execute(&context, &important_codes, &project_structure)
             .await?;
+        if let Some(inventory) = &dependency_inventory {
+            inventory.annotate_versions(&mut core_code_insights);
+        }
+
+        // Index code chunks and insights for embedding retrieval
+        if config.retrieval.enabled {
+            println!("🧲 Building vector index of code chunks...");
+            let vector_index_path = config.internal_path.join(VectorIndex::FILE_NAME);
+            let previous_vector_index = VectorIndex::load(&vector_index_path).ok();
+            let vector_index = CodeRetrieval::new()
+                .build_index(
+                    &context,
+                    &project_structure,
+                    &core_code_insights,
+                    previous_vector_index.as_ref(),
+                )
+                .await?;
+            if let Err(e) = vector_index.save(&vector_index_path) {
+                eprintln!("⚠️ Failed to save vector index: {}", e);
+            }
+            context
+                .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::VECTOR_INDEX, &vector_index)
+                .await?;
+        }
+
+        // Roll insights up into directory and package summaries, so large projects are not truncated to the top files
+        let hierarchical_summary = if core_code_insights.len() > config.hierarchical_summary_threshold {
+            println!("🗂️ Summarizing code insights by directory and package...");
+            let summary = HierarchicalSummarize::new()
+                .execute(&context, &core_code_insights, workspace.as_ref())
+                .await?;
+            println!(
+                "   Summarized {} directories in {} packages",
+                summary.directories.len(),
+                summary.packages.len()
+            );
+            context
+                .store_to_memory(
+                    MemoryScope::PREPROCESS,
+                    ScopedKeys::HIERARCHICAL_SUMMARY,
+                    &summary,
+                )
+                .await?;
+            Some(summary)
+        } else {
+            None
+        };
 
         // 5. Analyze component relationships
         println!("🔗 Analyzing component relationships...");
         let relationships_analyze = RelationshipsAnalyze::new();
-        let relationships = relationships_analyze
-            .execute(&context, &core_code_insights, &project_structure)
+        let mut relationships = relationships_analyze
+            .execute(
+                &context,
+                &core_code_insights,
+                &project_structure,
+                hierarchical_summary.as_ref(),
+            )
             .await?;
 
+        // Statically detected findings are facts, add them to the LLM insights
+        if let Some(report) = &architecture_health {
+            relationships.key_insights.extend(report.to_insights());
+        }
+
         let processing_time = start_time.elapsed().as_secs_f64();
 
         println!("✅ Project preprocessing completed, took {:.2} seconds", processing_time);
@@ -23,6 +23,10 @@ impl StepForwardAgent for ArchitectureResearcher {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "architecture"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -30,8 +34,15 @@ impl StepForwardAgent for ArchitectureResearcher {
                 DataSource::ResearchResult(AgentType::DomainModulesDetector.to_string()),
             ],
             optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
                 DataSource::PROJECT_STRUCTURE,
                 DataSource::DEPENDENCY_ANALYSIS,
+                DataSource::WORKSPACE,
+                DataSource::HIERARCHICAL_SUMMARY,
+                DataSource::RelevantCode(
+                    "architecture layers, module wiring, application bootstrap and component interactions"
+                        .to_string(),
+                ),
             ],
         }
     }
@@ -48,7 +59,8 @@ impl StepForwardAgent for ArchitectureResearcher {
 ## Analysis Requirements:
 - Draw system architecture diagram based on the provided project information and research materials
 - Use mermaid format to represent architecture relationships
-- Highlight core components and interaction patterns"#
+- Highlight core components and interaction patterns
+- If workspace members are provided, model each member as its own container in the C4 container view and show the dependencies between members"#
                 .to_string(),
 
             llm_call_mode: LLMCallMode::PromptWithTools, // Use prompt mode
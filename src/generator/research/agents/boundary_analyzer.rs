@@ -1,5 +1,6 @@
+use crate::generator::preprocess::extractors::route_extractor::ExtractedRoutes;
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
-use crate::generator::research::types::{AgentType, BoundaryAnalysisReport};
+use crate::generator::research::types::{AgentType, BoundaryAnalysisReport, CLIBoundary};
 use crate::generator::{
     context::GeneratorContext,
     step_forward_agent::{
@@ -30,6 +31,10 @@ impl StepForwardAgent for BoundaryAnalyzer {
         crate::generator::research::memory::MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "boundary"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -37,7 +42,9 @@ impl StepForwardAgent for BoundaryAnalyzer {
                 DataSource::DEPENDENCY_ANALYSIS,
                 DataSource::ResearchResult(AgentType::SystemContextResearcher.to_string()),
             ],
-            optional_sources: vec![],
+            optional_sources: vec![DataSource::RelevantCode(
+                "CLI commands, HTTP API endpoints, routes, public interfaces and integration points".to_string(),
+            )],
         }
     }
 
@@ -70,6 +77,8 @@ Please return the analysis results in structured JSON format."#
 - Generate practical usage examples and integration suggestions
 - Identify potential security risks and provide mitigation strategies
 - Ensure analysis results are accurate, complete, and practical
+- Statically extracted CLI definitions are ground truth: keep their commands, options, short names, defaults, types and source locations exactly, only complete descriptions and examples
+- Statically extracted HTTP routes are ground truth: keep their methods, paths and source locations exactly, only complete descriptions, request/response formats and authentication
 - If a certain type of boundary interface does not exist, the corresponding array can be empty"#
                 .to_string(),
 
@@ -91,14 +100,28 @@ Please return the analysis results in structured JSON format."#
         // 1. Filter boundary-related code insights
         let boundary_insights = self.filter_boundary_code_insights(context).await?;
 
-        if boundary_insights.is_empty() {
-            return Ok(Some(
-                "### Boundary-Related Code Insights\nNo obvious boundary interface-related code found.\n\n".to_string(),
-            ));
+        // 2. Format boundary code insights
+        let mut formatted_content = if boundary_insights.is_empty() {
+            "### Boundary-Related Code Insights\nNo obvious boundary interface-related code found.\n\n".to_string()
+        } else {
+            self.format_boundary_insights(&boundary_insights)
+        };
+
+        // 3. Append statically extracted CLI definitions
+        if let Some(cli_definitions) = context
+            .get_from_memory::<Vec<CLIBoundary>>(MemoryScope::PREPROCESS, ScopedKeys::CLI_DEFINITIONS)
+            .await
+        {
+            formatted_content.push_str(&self.format_cli_definitions(&cli_definitions));
         }
 
-        // 2. Format boundary code insights
-        let formatted_content = self.format_boundary_insights(&boundary_insights);
+        // 4. Append statically extracted HTTP routes
+        if let Some(routes) = context
+            .get_from_memory::<ExtractedRoutes>(MemoryScope::PREPROCESS, ScopedKeys::ROUTES)
+            .await
+        {
+            formatted_content.push_str(&self.format_routes(&routes));
+        }
 
         Ok(Some(formatted_content))
     }
@@ -238,6 +261,78 @@ impl BoundaryAnalyzer {
         content
     }
 
+    /// Format statically extracted CLI definitions
+    fn format_cli_definitions(&self, cli_definitions: &[CLIBoundary]) -> String {
+        let mut content = String::from("### Statically Extracted CLI Definitions (Ground Truth)\n");
+        content.push_str("These commands were parsed from the CLI framework declarations in the source code:\n\n");
+
+        for cli in cli_definitions {
+            content.push_str(&format!("**Command**: `{}` (`{}`)\n", cli.command, cli.source_location));
+            if !cli.description.is_empty() {
+                content.push_str(&format!("- **Description**: {}\n", cli.description));
+            }
+            for arg in &cli.arguments {
+                content.push_str(&format!(
+                    "- **Argument** `{}`: type `{}`, {}{}{}\n",
+                    arg.name,
+                    arg.value_type,
+                    if arg.required { "required" } else { "optional" },
+                    arg.default_value
+                        .as_ref()
+                        .map(|v| format!(", default `{}`", v))
+                        .unwrap_or_default(),
+                    if arg.description.is_empty() { String::new() } else { format!(" - {}", arg.description) }
+                ));
+            }
+            for option in &cli.options {
+                content.push_str(&format!(
+                    "- **Option** `{}`{}: type `{}`, {}{}{}\n",
+                    option.name,
+                    option.short_name.as_ref().map(|s| format!(" (`{}`)", s)).unwrap_or_default(),
+                    option.value_type,
+                    if option.required { "required" } else { "optional" },
+                    option.default_value
+                        .as_ref()
+                        .map(|v| format!(", default `{}`", v))
+                        .unwrap_or_default(),
+                    if option.description.is_empty() { String::new() } else { format!(" - {}", option.description) }
+                ));
+            }
+            content.push('\n');
+        }
+
+        content
+    }
+
+    /// Format statically extracted HTTP routes
+    fn format_routes(&self, routes: &ExtractedRoutes) -> String {
+        let mut content = String::from("### Statically Extracted HTTP Routes (Ground Truth)\n");
+        content.push_str("These routes were parsed from the web framework declarations in the source code:\n\n");
+
+        for api in &routes.api_boundaries {
+            content.push_str(&format!(
+                "- `{} {}` (`{}`): {}{}\n",
+                api.method,
+                api.endpoint,
+                api.source_location,
+                api.description,
+                api.request_format
+                    .as_ref()
+                    .map(|f| format!("; {}", f))
+                    .unwrap_or_default()
+            ));
+        }
+        for router in &routes.router_boundaries {
+            content.push_str(&format!(
+                "- Page `{}` (`{}`): {}\n",
+                router.path, router.source_location, router.description
+            ));
+        }
+
+        content.push('\n');
+        content
+    }
+
     /// Add single boundary code insight item
     fn add_boundary_insight_item(&self, content: &mut String, insight: &CodeInsight) {
         content.push_str(&format!(
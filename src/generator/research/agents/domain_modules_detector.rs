@@ -28,6 +28,10 @@ impl StepForwardAgent for DomainModulesDetector {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "domain_modules"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -35,7 +39,14 @@ impl StepForwardAgent for DomainModulesDetector {
                 DataSource::DEPENDENCY_ANALYSIS,
                 DataSource::CODE_INSIGHTS,
             ],
-            optional_sources: vec![DataSource::PROJECT_STRUCTURE],
+            optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
+                DataSource::PROJECT_STRUCTURE,
+                DataSource::HIERARCHICAL_SUMMARY,
+                DataSource::RelevantCode(
+                    "core business domain logic, services, domain models and their responsibilities".to_string(),
+                ),
+            ],
         }
     }
 
@@ -1,3 +1,4 @@
+use crate::generator::preprocess::agents::code_retrieval::CodeRetrieval;
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::generator::research::types::{
@@ -36,6 +37,10 @@ impl StepForwardAgent for KeyModulesInsight {
         crate::generator::research::memory::MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "key_modules"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -222,8 +227,13 @@ impl KeyModulesInsight {
             .filter_code_insights_for_domain(domain, context)
             .await?;
 
-        // 2. Build domain-specific prompt
-        let (system_prompt, user_prompt) = self.build_domain_prompt(domain, &filtered_insights);
+        // 2. Build domain-specific prompt, with the code chunks most relevant to the domain when retrieval is enabled
+        let (system_prompt, mut user_prompt) = self.build_domain_prompt(domain, &filtered_insights);
+        let relevant_code =
+            CodeRetrieval::retrieve(context, &self.domain_topic(domain), context.config.retrieval.top_k).await?;
+        if !relevant_code.is_empty() {
+            user_prompt.push_str(&format!("\n{}", CodeRetrieval::format_chunks(&relevant_code)));
+        }
 
         // 3. Use agent_executor::extract for analysis
         let params = AgentExecuteParams {
@@ -236,6 +246,7 @@ impl KeyModulesInsight {
                 domain.name
             ),
             log_tag: format!("{} domain analysis", domain.name),
+            model_route: self.model_route().to_string(),
         };
 
         println!("🤖 Analyzing '{}' domain...", domain.name);
@@ -275,6 +286,16 @@ impl KeyModulesInsight {
         (system_prompt, user_prompt)
     }
 
+    // Retrieval query describing a domain module
+    fn domain_topic(&self, domain: &DomainModule) -> String {
+        let sub_modules: Vec<String> = domain
+            .sub_modules
+            .iter()
+            .map(|sub| format!("{}: {}", sub.name, sub.key_functions.join(", ")))
+            .collect();
+        format!("{}: {}\n{}", domain.name, domain.description, sub_modules.join("\n"))
+    }
+
     // Format submodule information
     fn format_sub_modules(&self, sub_modules: &[SubModule]) -> String {
         if sub_modules.is_empty() {
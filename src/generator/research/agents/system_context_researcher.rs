@@ -25,10 +25,23 @@ impl StepForwardAgent for SystemContextResearcher {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "system_context"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![DataSource::PROJECT_STRUCTURE, DataSource::CODE_INSIGHTS],
-            optional_sources: vec![DataSource::README_CONTENT],
+            optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
+                DataSource::README_CONTENT,
+                DataSource::WORKSPACE,
+                DataSource::HIERARCHICAL_SUMMARY,
+                DataSource::RelevantCode(
+                    "project purpose, main entry points, target users and external systems it integrates with"
+                        .to_string(),
+                ),
+            ],
         }
     }
 
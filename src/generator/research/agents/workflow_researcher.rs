@@ -24,6 +24,10 @@ impl StepForwardAgent for WorkflowResearcher {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn model_route(&self) -> &'static str {
+        "workflow"
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -31,7 +35,12 @@ impl StepForwardAgent for WorkflowResearcher {
                 DataSource::ResearchResult(AgentType::DomainModulesDetector.to_string()),
                 DataSource::CODE_INSIGHTS
             ],
-            optional_sources: vec![],
+            optional_sources: vec![
+                DataSource::HIERARCHICAL_SUMMARY,
+                DataSource::RelevantCode(
+                    "main business workflows, request handling and processing pipelines".to_string(),
+                ),
+            ],
         }
     }
     
@@ -5,13 +5,16 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::generator::agent_executor::{AgentExecuteParams, extract, prompt, prompt_with_tools};
+use crate::generator::preprocess::agents::code_retrieval::CodeRetrieval;
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::{
     generator::context::GeneratorContext,
     types::{
-        code::CodeInsight, code_releationship::RelationshipAnalysis,
-        project_structure::ProjectStructure,
+        architecture_meta::ArchitectureMeta, code::CodeInsight, code_releationship::RelationshipAnalysis,
+        hierarchical_summary::HierarchicalSummary,
+        original_document::{DocumentKind, OriginalDocument}, project_structure::ProjectStructure,
+        workspace::WorkspaceInfo,
     },
     utils::project_structure_formatter::ProjectStructureFormatter,
     utils::prompt_compressor::{CompressionConfig, PromptCompressor},
@@ -39,6 +42,8 @@ pub enum DataSource {
     },
     /// Research results from research agent
     ResearchResult(String),
+    /// Code chunks most relevant to the given topic, retrieved from the vector index when retrieval is enabled
+    RelevantCode(String),
 }
 
 impl DataSource {
@@ -59,6 +64,19 @@ impl DataSource {
         scope: MemoryScope::PREPROCESS,
         key: ScopedKeys::ORIGINAL_DOCUMENT,
     };
+    pub const WORKSPACE: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::WORKSPACE,
+    };
+    pub const HIERARCHICAL_SUMMARY: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::HIERARCHICAL_SUMMARY,
+    };
+    /// Architecture facts declared by the architects, placed ahead of the research materials as authoritative data
+    pub const ARCHITECTURE_META: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::ARCHITECTURE_META,
+    };
 }
 
 /// Agent data configuration - Declares required data sources
@@ -204,21 +222,56 @@ impl DataFormatter {
         content
     }
 
-    /// Format README content
-    pub fn format_readme_content(&self, readme: &str) -> String {
-        let content = if let Some(limit) = self.config.readme_truncate_length {
-            if readme.len() > limit {
-                format!("{}...(truncated)", &readme[..limit])
-            } else {
-                readme.to_string()
+    /// Format the project's own documentation, each source labeled with its kind and path.
+    /// Localized READMEs are skipped when the main README exists, and the total is cut to `readme_truncate_length`
+    pub fn format_original_document(&self, document: &OriginalDocument) -> String {
+        let main_readme = document.readme().map(|readme| readme.path.as_str());
+        let mut content = String::from(
+            "### Existing Project Documentation (Manually written, may not be accurate, for reference only)\n",
+        );
+        let mut omitted = 0;
+        for source in &document.documents {
+            let is_localized_copy = source.kind == DocumentKind::Readme
+                && source.language.is_some()
+                && main_readme.is_some_and(|main| main != source.path);
+            if is_localized_copy {
+                continue;
             }
-        } else {
-            readme.to_string()
-        };
-        format!(
-            "### Previous README Content (Manually entered information, may not be accurate, for reference only)\n{}\n\n",
-            content
-        )
+            if self
+                .config
+                .readme_truncate_length
+                .is_some_and(|limit| content.len() >= limit)
+            {
+                omitted += 1;
+                continue;
+            }
+
+            let mut label = format!("#### [{}] `{}`", source.kind, source.path);
+            if let Some(title) = &source.title {
+                label.push_str(&format!(" - {}", title));
+            }
+            if let Some(language) = &source.language {
+                label.push_str(&format!(" ({})", language));
+            }
+            content.push_str(&format!("{}\n{}\n", label, source.content));
+            if source.truncated {
+                content.push_str("...(truncated)\n");
+            }
+            content.push('\n');
+        }
+
+        if let Some(limit) = self.config.readme_truncate_length
+            && content.len() > limit
+        {
+            let end = (0..=limit).rev().find(|&i| content.is_char_boundary(i)).unwrap_or(0);
+            content.truncate(end);
+            content.push_str("...(truncated)\n");
+        }
+        if omitted > 0 {
+            content.push_str(&format!("({} more documents omitted)\n", omitted));
+        }
+        content.push('\n');
+        content
     }
 
     /// Format dependency relationship analysis
@@ -246,6 +299,133 @@ impl DataFormatter {
         content
     }
 
+    /// Format workspace/monorepo members
+    pub fn format_workspace(&self, workspace: &WorkspaceInfo) -> String {
+        let mut content = String::from("### Workspace Members\n");
+        content.push_str(&format!(
+            "The project is a monorepo declared by {}. Each member is an independently built unit and should be treated as a separate container:\n",
+            workspace.root_manifests.join(", ")
+        ));
+        for member in &workspace.members {
+            content.push_str(&format!("- `{}` at `{}` ({})", member.name, member.path, member.tool));
+            if let Some(description) = &member.description {
+                content.push_str(&format!(": {}", description));
+            }
+            if !member.depends_on.is_empty() {
+                content.push_str(&format!("; depends on {}", member.depends_on.join(", ")));
+            }
+            content.push('\n');
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the declared architecture facts as authoritative instructions
+    pub fn format_architecture_meta(&self, meta: &ArchitectureMeta) -> String {
+        let mut content = String::from(
+            "## Authoritative Architecture Declarations\nThe following facts were declared by the project's architects and are ground truth. Use these names and boundaries verbatim; where the code or other materials suggest otherwise, these declarations take precedence over your own conclusions.\n",
+        );
+
+        if meta.system_name.is_some() || meta.description.is_some() {
+            content.push_str("### System\n");
+            if let Some(name) = &meta.system_name {
+                content.push_str(&format!("- Name: {}\n", name));
+            }
+            if let Some(description) = &meta.description {
+                content.push_str(&format!("- Description: {}\n", description.trim()));
+            }
+        }
+        if !meta.containers.is_empty() {
+            content.push_str("### Containers\n");
+            for container in &meta.containers {
+                content.push_str(&format!("- **{}**", container.name));
+                if let Some(technology) = &container.technology {
+                    content.push_str(&format!(" ({})", technology));
+                }
+                if let Some(description) = &container.description {
+                    content.push_str(&format!(": {}", description.trim()));
+                }
+                Self::push_paths(&mut content, &container.paths);
+            }
+        }
+        if !meta.external_systems.is_empty() {
+            content.push_str("### External Systems\n");
+            for system in &meta.external_systems {
+                content.push_str(&format!("- **{}**", system.name));
+                if let Some(description) = &system.description {
+                    content.push_str(&format!(": {}", description.trim()));
+                }
+                if let Some(interaction) = &system.interaction {
+                    content.push_str(&format!("; interaction: {}", interaction.trim()));
+                }
+                content.push('\n');
+            }
+        }
+        for (title, boundaries) in [
+            ("Domain Boundaries", &meta.domains),
+            ("Layers (outermost first)", &meta.layers),
+        ] {
+            if boundaries.is_empty() {
+                continue;
+            }
+            content.push_str(&format!("### {}\n", title));
+            for boundary in boundaries {
+                content.push_str(&format!("- **{}**", boundary.name));
+                if let Some(description) = &boundary.description {
+                    content.push_str(&format!(": {}", description.trim()));
+                }
+                Self::push_paths(&mut content, &boundary.paths);
+            }
+        }
+        if !meta.glossary.is_empty() {
+            content.push_str("### Glossary\n");
+            for (term, definition) in &meta.glossary {
+                content.push_str(&format!("- **{}**: {}\n", term, definition.trim()));
+            }
+        }
+        content.push('\n');
+        content
+    }
+
+    fn push_paths(content: &mut String, paths: &[String]) {
+        if !paths.is_empty() {
+            let paths: Vec<String> = paths.iter().map(|p| format!("`{}`", p)).collect();
+            content.push_str(&format!(" [paths: {}]", paths.join(", ")));
+        }
+        content.push('\n');
+    }
+
+    /// Format directory and package summaries
+    pub fn format_hierarchical_summary(&self, summary: &HierarchicalSummary) -> String {
+        let mut content = String::from(
+            "### Package and Directory Summaries\nRoll-up of the insights of all analyzed files, including the ones not listed individually.\n#### Packages\n",
+        );
+        for package in &summary.packages {
+            content.push_str(&package.outline.to_markdown(&format!(
+                "`{}` at `{}` ({} files)",
+                package.name, package.path, package.file_count
+            )));
+        }
+
+        // Most important directories first
+        let mut directories: Vec<_> = summary.directories.iter().collect();
+        directories.sort_by(|a, b| {
+            b.max_importance
+                .partial_cmp(&a.max_importance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        content.push_str("#### Directories\n");
+        for directory in directories {
+            content.push_str(&directory.outline.to_markdown(&format!(
+                "`{}` ({} files)",
+                directory.path,
+                directory.files.len()
+            )));
+        }
+        content.push('\n');
+        content
+    }
+
     /// Get dependency type priority
     fn get_dependency_priority(
         &self,
@@ -265,6 +445,9 @@ impl DataFormatter {
     /// Format research results
     pub fn format_research_results(&self, results: &HashMap<String, serde_json::Value>) -> String {
         let mut content = String::from("### Existing Research Results\n");
+        // Sorted so that the same results always give the same prompt, and with it the same cache and fixture keys
+        let mut results: Vec<_> = results.iter().collect();
+        results.sort_by(|a, b| a.0.cmp(b.0));
         for (key, value) in results {
             content.push_str(&format!(
                 "#### {}：\n{}\n\n",
@@ -353,6 +536,16 @@ impl GeneratorPromptBuilder {
             );
         }
 
+        // Declared architecture facts come first so that the research materials are read in their light
+        if data_sources.contains(&DataSource::ARCHITECTURE_META)
+            && let Some(meta) = context
+                .get_from_memory::<ArchitectureMeta>(MemoryScope::PREPROCESS, ScopedKeys::ARCHITECTURE_META)
+                .await
+            && !meta.is_empty()
+        {
+            prompt.push_str(&self.formatter.format_architecture_meta(&meta));
+        }
+
         // Research materials reference section
         prompt.push_str("## Research Materials Reference\n");
 
@@ -395,11 +588,35 @@ impl GeneratorPromptBuilder {
                         }
                     }
                     ScopedKeys::ORIGINAL_DOCUMENT => {
-                        if let Some(readme) = context.get_from_memory::<String>(scope, key).await {
-                            let formatted = self.formatter.format_readme_content(&readme);
+                        if let Some(document) =
+                            context.get_from_memory::<OriginalDocument>(scope, key).await
+                            && !document.is_empty()
+                        {
+                            let formatted = self.formatter.format_original_document(&document);
                             let compressed = self
                                 .formatter
-                                .compress_content_if_needed(context, &formatted, "README Document")
+                                .compress_content_if_needed(context, &formatted, "Project Documentation")
+                                .await?;
+                            prompt.push_str(&compressed);
+                        }
+                    }
+                    ScopedKeys::WORKSPACE => {
+                        if let Some(workspace) =
+                            context.get_from_memory::<WorkspaceInfo>(scope, key).await
+                        {
+                            prompt.push_str(&self.formatter.format_workspace(&workspace));
+                        }
+                    }
+                    ScopedKeys::HIERARCHICAL_SUMMARY => {
+                        if let Some(summary) = context
+                            .get_from_memory::<HierarchicalSummary>(scope, key)
+                            .await
+                            && !summary.is_empty()
+                        {
+                            let formatted = self.formatter.format_hierarchical_summary(&summary);
+                            let compressed = self
+                                .formatter
+                                .compress_content_if_needed(context, &formatted, "Directory Summaries")
                                 .await?;
                             prompt.push_str(&compressed);
                         }
@@ -424,6 +641,18 @@ impl GeneratorPromptBuilder {
                         research_results.insert(agent_type.clone(), result);
                     }
                 }
+                DataSource::RelevantCode(topic) => {
+                    let chunks =
+                        CodeRetrieval::retrieve(context, topic, context.config.retrieval.top_k).await?;
+                    if !chunks.is_empty() {
+                        let formatted = CodeRetrieval::format_chunks(&chunks);
+                        let compressed = self
+                            .formatter
+                            .compress_content_if_needed(context, &formatted, "Relevant Code")
+                            .await?;
+                        prompt.push_str(&compressed);
+                    }
+                }
             }
         }
 
@@ -463,6 +692,9 @@ pub trait StepForwardAgent: Send + Sync {
 
     fn memory_scope_key(&self) -> String;
 
+    /// Key of the agent in the `[llm.routes]` model routing
+    fn model_route(&self) -> &'static str;
+
     /// Data source configuration
     fn data_config(&self) -> AgentDataConfig;
 
@@ -504,6 +736,8 @@ pub trait StepForwardAgent: Send + Sync {
                         return Err(anyhow!("Required research result {} is not available", agent_type));
                     }
                 }
+                // Retrieval is optional, agents fall back to their other sources when it is disabled
+                DataSource::RelevantCode(_) => {}
             }
         }
 
@@ -544,6 +778,7 @@ pub trait StepForwardAgent: Send + Sync {
             prompt_user: user_prompt,
             cache_scope: format!("{}/{}", self.memory_scope_key(), self.agent_type()),
             log_tag,
+            model_route: self.model_route().to_string(),
         };
 
         let result_value = match template.llm_call_mode {
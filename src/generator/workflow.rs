@@ -50,10 +50,12 @@ pub async fn launch(c: &Config) -> Result<()> {
     let cache_manager = Arc::new(RwLock::new(CacheManager::new(
         config.cache.clone(),
         config.target_language.clone(),
+        llm_client.usage_tracker().pricing().clone(),
     )));
     let memory = Arc::new(RwLock::new(Memory::new()));
 
     let context = GeneratorContext {
+        ignore_rules: llm_client.ignore_rules(),
         llm_client,
         config,
         cache_manager,
@@ -62,6 +64,7 @@ pub async fn launch(c: &Config) -> Result<()> {
 
     // Preprocessing stage
     let preprocess_start = Instant::now();
+    context.llm_client.usage_tracker().set_stage(TimingKeys::PREPROCESS);
     let preprocess_agent = PreProcessAgent::new();
     preprocess_agent.execute(context.clone()).await?;
     let preprocess_time = preprocess_start.elapsed().as_secs_f64();
@@ -75,6 +78,7 @@ pub async fn launch(c: &Config) -> Result<()> {
 
     // Execute multi-agent research stage
     let research_start = Instant::now();
+    context.llm_client.usage_tracker().set_stage(TimingKeys::RESEARCH);
     let research_orchestrator = ResearchOrchestrator::default();
     research_orchestrator
         .execute_research_pipeline(&context)
@@ -87,6 +91,7 @@ pub async fn launch(c: &Config) -> Result<()> {
 
     // Execute document generation process
     let compose_start = Instant::now();
+    context.llm_client.usage_tracker().set_stage(TimingKeys::COMPOSE);
     let mut doc_tree = DocTree::new(&context.config.target_language);
     let documentation_orchestrator = DocumentationComposer::default();
     documentation_orchestrator
@@ -100,6 +105,7 @@ pub async fn launch(c: &Config) -> Result<()> {
 
     // Execute document storage
     let output_start = Instant::now();
+    context.llm_client.usage_tracker().set_stage(TimingKeys::OUTPUT);
     let outlet = DiskOutlet::new(doc_tree);
     outlet.save(&context).await?;
 
@@ -123,3 +129,201 @@ pub async fn launch(c: &Config) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LLMProvider;
+    use crate::llm::client::replay::synthesize_schema;
+    use crate::utils::test_support::TempProject;
+    use std::path::Path;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Write the shop project into the `shop` directory of the root
+    fn write_shop_project(root: &TempProject) {
+        let files = [
+            ("Cargo.toml", "[package]\nname = \"shop\"\nversion = \"0.1.0\"\n"),
+            ("README.md", "# Shop\nA tiny order service.\n"),
+            ("src/main.rs", "mod store;\n\nfn main() {\n    let mut store = store::OrderStore::new();\n    store.place(\"book\");\n}\n"),
+            ("src/store.rs", "/// Keeps placed orders\npub struct OrderStore {\n    orders: Vec<String>,\n}\n\nimpl OrderStore {\n    pub fn new() -> Self {\n        Self { orders: Vec::new() }\n    }\n\n    pub fn place(&mut self, item: &str) {\n        self.orders.push(item.to_string());\n    }\n}\n"),
+        ];
+        for (path, content) in files {
+            root.write(&format!("shop/{}", path), content);
+        }
+    }
+
+    /// OpenAI-compatible server answering text prompts with a fixed reply and `submit` tool calls with data
+    /// synthesized from the tool's schema, returning its address
+    async fn serve_openai_compatible() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 8192];
+                    let body = loop {
+                        let read = stream.read(&mut buffer).await.unwrap_or(0);
+                        if read == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buffer[..read]);
+                        let text = String::from_utf8_lossy(&request);
+                        let Some(header_end) = text.find("\r\n\r\n") else {
+                            continue;
+                        };
+                        let content_length = text[..header_end]
+                            .lines()
+                            .find_map(|line| {
+                                let line = line.to_lowercase();
+                                line.strip_prefix("content-length:")?.trim().parse::<usize>().ok()
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= header_end + 4 + content_length {
+                            break serde_json::from_slice::<serde_json::Value>(&request[header_end + 4..])
+                                .unwrap_or_default();
+                        }
+                    };
+
+                    let submit_schema = body["tools"].as_array().and_then(|tools| {
+                        tools
+                            .iter()
+                            .find(|tool| tool["function"]["name"] == "submit")
+                            .map(|tool| tool["function"]["parameters"].clone())
+                    });
+                    let message = match submit_schema {
+                        Some(schema) => serde_json::json!({
+                            "role": "assistant",
+                            "content": "",
+                            "tool_calls": [{
+                                "id": "call_1",
+                                "type": "function",
+                                "function": {"name": "submit", "arguments": synthesize_schema(&schema).to_string()}
+                            }]
+                        }),
+                        None => serde_json::json!({"role": "assistant", "content": "Recorded reply of the shop project."}),
+                    };
+                    let reply = serde_json::json!({
+                        "id": "chatcmpl-1",
+                        "object": "chat.completion",
+                        "created": 0,
+                        "model": body["model"],
+                        "choices": [{"index": 0, "message": message, "finish_reason": "stop"}],
+                        "usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15}
+                    })
+                    .to_string();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        reply.len(),
+                        reply
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        format!("http://{}", address)
+    }
+
+    fn generated_documents(docs: &Path) -> Vec<walkdir::DirEntry> {
+        walkdir::WalkDir::new(docs)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_launch_offline_with_replay_provider() {
+        let root = TempProject::new("launch_replay");
+        write_shop_project(&root);
+        let project = root.join("shop");
+
+        let mut config = Config {
+            project_path: project.clone(),
+            output_path: root.join("docs"),
+            internal_path: project.join(".litho"),
+            ..Default::default()
+        };
+        config.llm.provider = LLMProvider::Replay;
+        config.llm.replay.fixtures_dir = root.join("fixtures");
+        config.llm.replay.synthesize_missing = true;
+        config.cache.enabled = false;
+        config.git_history.enabled = false;
+
+        launch(&config).await.unwrap();
+
+        assert!(!generated_documents(&root.join("docs")).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_launch_stops_on_architecture_violation_before_calling_models() {
+        let root = TempProject::new("launch_violation");
+        root.write("cyclic/src/main.rs", "mod a;\nmod b;\n\nfn main() {}\n");
+        root.write("cyclic/src/a.rs", "use crate::b::B;\n\npub struct A(B);\n");
+        root.write("cyclic/src/b.rs", "use crate::a::A;\n\npub struct B(Box<A>);\n");
+        let project = root.join("cyclic");
+
+        let mut config = Config {
+            project_path: project.clone(),
+            output_path: root.join("docs"),
+            internal_path: project.join(".litho"),
+            analyze_dependencies: false,
+            ..Default::default()
+        };
+        config.architecture_rules.fail_on_violation = true;
+        // No fixtures and no synthesized answers: any model call would fail with another error
+        config.llm.provider = LLMProvider::Replay;
+        config.llm.replay.fixtures_dir = root.join("fixtures");
+        config.llm.replay.synthesize_missing = false;
+        config.cache.enabled = false;
+        config.git_history.enabled = false;
+
+        let error = launch(&config).await.unwrap_err();
+        assert!(
+            error.to_string().starts_with("Architecture check failed: 1 circular dependencies"),
+            "{:#}",
+            error
+        );
+        assert!(!root.join("docs").exists());
+    }
+
+    #[tokio::test]
+    async fn test_launch_replays_recorded_fixtures() {
+        let root = TempProject::new("launch_record");
+        write_shop_project(&root);
+        let project = root.join("shop");
+
+        let mut config = Config {
+            project_path: project.clone(),
+            output_path: root.join("docs"),
+            internal_path: project.join(".litho"),
+            ..Default::default()
+        };
+        config.llm.provider = LLMProvider::Replay;
+        config.llm.replay.fixtures_dir = root.join("fixtures");
+        config.llm.replay.record_with = Some(LLMProvider::OpenAICompatible);
+        config.llm.api_base_url = serve_openai_compatible().await;
+        config.llm.retry_attempts = 1;
+        config.cache.enabled = false;
+        config.git_history.enabled = false;
+
+        // Record every response of the live provider, then replay them without it nor synthesized answers
+        launch(&config).await.unwrap();
+        let recorded = std::fs::read_dir(root.join("fixtures")).unwrap().count();
+        assert!(recorded > 0);
+
+        std::fs::remove_dir_all(root.join("docs")).unwrap();
+        config.llm.replay.record_with = None;
+        config.llm.replay.synthesize_missing = false;
+        launch(&config).await.unwrap();
+
+        assert_eq!(std::fs::read_dir(root.join("fixtures")).unwrap().count(), recorded);
+        let documents = generated_documents(&root.join("docs"));
+        assert!(!documents.is_empty());
+        let overview = documents
+            .iter()
+            .map(|entry| std::fs::read_to_string(entry.path()).unwrap())
+            .collect::<String>();
+        assert!(overview.contains("Recorded reply of the shop project."));
+    }
+}
@@ -95,48 +95,56 @@ impl TargetLanguage {
             TargetLanguage::Chinese => {
                 match dir_type {
                     "deep_exploration" => "4、深入探索".to_string(),
+                    "workspace_members" => "8、工作区成员".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::English => {
                 match dir_type {
                     "deep_exploration" => "4.Deep-Exploration".to_string(),
+                    "workspace_members" => "8.Workspace-Members".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::Japanese => {
                 match dir_type {
                     "deep_exploration" => "4-詳細探索".to_string(),
+                    "workspace_members" => "8-ワークスペースメンバー".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::Korean => {
                 match dir_type {
                     "deep_exploration" => "4-심층-탐색".to_string(),
+                    "workspace_members" => "8-워크스페이스-멤버".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::German => {
                 match dir_type {
                     "deep_exploration" => "4-Tiefere-Erkundung".to_string(),
+                    "workspace_members" => "8-Arbeitsbereich-Mitglieder".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::French => {
                 match dir_type {
                     "deep_exploration" => "4-Exploration-Approfondie".to_string(),
+                    "workspace_members" => "8-Membres-de-l'Espace-de-Travail".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::Russian => {
                 match dir_type {
                     "deep_exploration" => "4-Глубокое-Исследование".to_string(),
+                    "workspace_members" => "8-Участники-Рабочего-Пространства".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
             TargetLanguage::Vietnamese => {
                 match dir_type {
                     "deep_exploration" => "4-Khám-phá-chi-tiết".to_string(),
+                    "workspace_members" => "8-Thành-viên-Không-gian-làm-việc".to_string(),
                     _ => dir_type.to_string(),
                 }
             }
@@ -152,6 +160,11 @@ impl TargetLanguage {
                     "architecture" => "2、架构概览.md".to_string(),
                     "workflow" => "3、工作流程.md".to_string(),
                     "boundary" => "5、边界调用.md".to_string(),
+                    "architecture_health" => "6、架构健康度.md".to_string(),
+                    "tech_stack" => "7、技术栈与依赖.md".to_string(),
+                    "workspace" => "8、工作区成员.md".to_string(),
+                    "configuration" => "9、配置参考.md".to_string(),
+                    "test_strategy" => "10、测试策略.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -161,6 +174,11 @@ impl TargetLanguage {
                     "architecture" => "2.Architecture.md".to_string(),
                     "workflow" => "3.Workflow.md".to_string(),
                     "boundary" => "5.Boundary-Interfaces.md".to_string(),
+                    "architecture_health" => "6.Architecture-Health.md".to_string(),
+                    "tech_stack" => "7.Technology-Stack.md".to_string(),
+                    "workspace" => "8.Workspace.md".to_string(),
+                    "configuration" => "9.Configuration-Reference.md".to_string(),
+                    "test_strategy" => "10.Test-Strategy.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -170,6 +188,11 @@ impl TargetLanguage {
                     "architecture" => "2-アーキテクチャ概要.md".to_string(),
                     "workflow" => "3-ワークフロー.md".to_string(),
                     "boundary" => "5-境界インターフェース.md".to_string(),
+                    "architecture_health" => "6-アーキテクチャ健全性.md".to_string(),
+                    "tech_stack" => "7-技術スタックと依存関係.md".to_string(),
+                    "workspace" => "8-ワークスペース.md".to_string(),
+                    "configuration" => "9-設定リファレンス.md".to_string(),
+                    "test_strategy" => "10-テスト戦略.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -179,6 +202,11 @@ impl TargetLanguage {
                     "architecture" => "2-아키텍처-개요.md".to_string(),
                     "workflow" => "3-워크플로우.md".to_string(),
                     "boundary" => "5-경계-인터페이스.md".to_string(),
+                    "architecture_health" => "6-아키텍처-건전성.md".to_string(),
+                    "tech_stack" => "7-기술-스택-및-의존성.md".to_string(),
+                    "workspace" => "8-워크스페이스.md".to_string(),
+                    "configuration" => "9-설정-참조.md".to_string(),
+                    "test_strategy" => "10-테스트-전략.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -188,6 +216,11 @@ impl TargetLanguage {
                     "architecture" => "2-Architekturübersicht.md".to_string(),
                     "workflow" => "3-Arbeitsablauf.md".to_string(),
                     "boundary" => "5-Grenzschnittstellen.md".to_string(),
+                    "architecture_health" => "6-Architekturgesundheit.md".to_string(),
+                    "tech_stack" => "7-Technologie-Stack.md".to_string(),
+                    "workspace" => "8-Arbeitsbereich.md".to_string(),
+                    "configuration" => "9-Konfigurationsreferenz.md".to_string(),
+                    "test_strategy" => "10-Teststrategie.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -197,6 +230,11 @@ impl TargetLanguage {
                     "architecture" => "2-Aperçu-de-l'Architecture.md".to_string(),
                     "workflow" => "3-Flux-de-Travail.md".to_string(),
                     "boundary" => "5-Interfaces-de-Frontière.md".to_string(),
+                    "architecture_health" => "6-Santé-de-l'Architecture.md".to_string(),
+                    "tech_stack" => "7-Pile-Technologique.md".to_string(),
+                    "workspace" => "8-Espace-de-Travail.md".to_string(),
+                    "configuration" => "9-Référence-de-Configuration.md".to_string(),
+                    "test_strategy" => "10-Stratégie-de-Test.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -206,6 +244,11 @@ impl TargetLanguage {
                     "architecture" => "2-Обзор-Архитектуры.md".to_string(),
                     "workflow" => "3-Рабочий-Процесс.md".to_string(),
                     "boundary" => "5-Граничные-Интерфейсы.md".to_string(),
+                    "architecture_health" => "6-Здоровье-Архитектуры.md".to_string(),
+                    "tech_stack" => "7-Технологический-Стек.md".to_string(),
+                    "workspace" => "8-Рабочее-Пространство.md".to_string(),
+                    "configuration" => "9-Справочник-Конфигурации.md".to_string(),
+                    "test_strategy" => "10-Стратегия-Тестирования.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
@@ -215,6 +258,11 @@ impl TargetLanguage {
                     "architecture" => "2-Kiến-trúc.md".to_string(),
                     "workflow" => "3-Luồng-xử-lý.md".to_string(),
                     "boundary" => "5-Lớp-giao-tiếp-biên.md".to_string(),
+                    "architecture_health" => "6-Sức-khỏe-Kiến-trúc.md".to_string(),
+                    "tech_stack" => "7-Công-nghệ-và-Phụ-thuộc.md".to_string(),
+                    "workspace" => "8-Không-gian-làm-việc.md".to_string(),
+                    "configuration" => "9-Tham-chiếu-Cấu-hình.md".to_string(),
+                    "test_strategy" => "10-Chiến-lược-Kiểm-thử.md".to_string(),
                     _ => format!("{}.md", doc_type),
                 }
             }
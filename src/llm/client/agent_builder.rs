@@ -1,21 +1,41 @@
 //! Agent builder - Responsible for building and configuring LLM Agent
 
+use std::sync::Arc;
+
 use crate::{
     config::Config,
     llm::client::providers::{ProviderAgent, ProviderClient},
-    llm::tools::{file_explorer::AgentToolFileExplorer, file_reader::AgentToolFileReader},
+    llm::tools::{
+        file_explorer::AgentToolFileExplorer, file_reader::AgentToolFileReader,
+        symbol_lookup::AgentToolSymbolLookup,
+    },
+    utils::ignore_rules::IgnoreRules,
 };
 
 /// Agent builder
 pub struct AgentBuilder<'a> {
     client: &'a ProviderClient,
     config: &'a Config,
+    ignore_rules: &'a Arc<IgnoreRules>,
 }
 
 impl<'a> AgentBuilder<'a> {
     /// Create a new Agent builder
-    pub fn new(client: &'a ProviderClient, config: &'a Config) -> Self {
-        Self { client, config }
+    pub fn new(
+        client: &'a ProviderClient,
+        config: &'a Config,
+        ignore_rules: &'a Arc<IgnoreRules>,
+    ) -> Self {
+        Self {
+            client,
+            config,
+            ignore_rules,
+        }
+    }
+
+    /// Model the agents are built with, the routed one when the agent has a route
+    pub fn model(&self) -> &str {
+        &self.config.llm.model_efficient
     }
 
     /// Build Agent with built-in preset tools
@@ -23,31 +43,34 @@ impl<'a> AgentBuilder<'a> {
         let llm_config = &self.config.llm;
 
         if !llm_config.disable_preset_tools {
-            let file_explorer = AgentToolFileExplorer::new(self.config.clone());
-            let file_reader = AgentToolFileReader::new(self.config.clone());
+            let file_explorer =
+                AgentToolFileExplorer::new(self.config.clone(), self.ignore_rules.clone());
+            let file_reader =
+                AgentToolFileReader::new(self.config.clone(), self.ignore_rules.clone());
+            let symbol_lookup = AgentToolSymbolLookup::new(self.config.clone());
 
             let system_prompt_with_tools = format!(
-                "{}\nDo not fabricate non-existent code. If you need to learn more about the project structure and source code content, actively call tools to obtain more contextual information",
+                "{}\nDo not fabricate non-existent code. If you need to learn more about the project structure and source code content, actively call tools to obtain more contextual information, use symbol_lookup to find where a function or type is defined",
                 system_prompt
             );
 
             self.client.create_agent_with_tools(
-                &llm_config.model_efficient,
+                self.model(),
                 &system_prompt_with_tools,
                 llm_config,
                 &file_explorer,
                 &file_reader,
+                &symbol_lookup,
             )
         } else {
             self.client
-                .create_agent(&llm_config.model_efficient, system_prompt, llm_config)
+                .create_agent(self.model(), system_prompt, llm_config)
         }
     }
 
     /// Build Agent without tools
     pub fn build_agent_without_tools(&self, system_prompt: &str) -> ProviderAgent {
-        let llm_config = &self.config.llm;
         self.client
-            .create_agent(&llm_config.model_efficient, system_prompt, llm_config)
+            .create_agent(self.model(), system_prompt, &self.config.llm)
     }
 }
@@ -0,0 +1,59 @@
+//! Azure OpenAI provider - Deployments of an Azure OpenAI resource, authenticated with the `api-key` header
+
+use anyhow::{Result, bail};
+use rig::providers::azure::{AzureOpenAIAuth, Client};
+
+use crate::config::{AzureOpenAIConfig, LLMConfig};
+
+/// Azure client resolving model names to deployment names
+#[derive(Clone)]
+pub struct AzureOpenAIClient {
+    pub client: Client,
+    config: AzureOpenAIConfig,
+}
+
+impl AzureOpenAIClient {
+    pub fn new(config: &LLMConfig) -> Result<Self> {
+        if config.api_key.is_empty() {
+            bail!("The azure-openai provider needs the resource's API key in api_key");
+        }
+        let endpoint = config.api_base_url.trim_end_matches('/');
+        let client = Client::builder(AzureOpenAIAuth::ApiKey(config.api_key.clone()), endpoint)
+            .api_version(&config.azure_openai.api_version)
+            .build();
+
+        Ok(Self {
+            client,
+            config: config.azure_openai.clone(),
+        })
+    }
+
+    /// Deployment serving the model, requests address deployments rather than models
+    pub fn deployment<'a>(&'a self, model: &'a str) -> &'a str {
+        self.config.deployment(model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deployment_resolution() {
+        let mut config = LLMConfig {
+            api_key: String::new(),
+            api_base_url: "https://contoso.openai.azure.com/".to_string(),
+            ..LLMConfig::default()
+        };
+        assert!(AzureOpenAIClient::new(&config).is_err());
+
+        config.api_key = "secret".to_string();
+        config
+            .azure_openai
+            .deployments
+            .insert("gpt-4o".to_string(), "docs-gpt4o".to_string());
+        let azure = AzureOpenAIClient::new(&config).unwrap();
+        assert_eq!(azure.deployment("gpt-4o"), "docs-gpt4o");
+        assert_eq!(azure.deployment("gpt-4o-mini"), "gpt-4o-mini");
+    }
+}
@@ -0,0 +1,197 @@
+//! Error classification - Sorts model service errors to decide whether and how long to wait before retrying
+//!
+//! rig flattens the provider errors into messages, so the classification reads the HTTP status and the wording
+//! the providers use for each kind of error.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use rand::Rng;
+use regex::Regex;
+
+/// Upper bound of the backoff delay between two attempts
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// HTTP status in the message, a bare number possibly being a token count or a position in a reply
+const STATUS: &str = r"(?:status(?:[ _]code)?|http(?:/[\d.]+)?|error code)[:= ]+";
+
+/// Classification rules, checked in order: the first matching kind wins. Deserialization errors come first, as
+/// they quote the field names of the expected data (e.g. ``missing field `authentication` ``), then the wording of
+/// the providers before the HTTP status, a rate limit reply for instance quoting the requested token count
+static RULES: LazyLock<Vec<(LLMErrorKind, Regex)>> = LazyLock::new(|| {
+    [
+        (
+            LLMErrorKind::SchemaMismatch,
+            r"no data extracted|deserializ|missing field|unknown (?:field|variant)|invalid type|expected (?:value|json|a |an |`)|invalid json|json (?:parse|decode|syntax) error|eof while parsing|trailing characters".to_string(),
+        ),
+        (
+            LLMErrorKind::RateLimit,
+            r"rate[-_ ]?limit|too many requests|quota exceeded|resource[-_ ]exhausted".to_string(),
+        ),
+        (
+            LLMErrorKind::ContextOverflow,
+            r"context[-_ ]length[-_ ]exceeded|maximum context length|context window|prompt is too long|input is too long|exceeds the maximum number of tokens|too many (?:input )?tokens|reduce the length of the messages".to_string(),
+        ),
+        (
+            LLMErrorKind::Authentication,
+            r"unauthori[sz]ed|authentication[_ ](?:error|failed|required)|permission[-_ ]denied|\b403 forbidden|(?:invalid|incorrect|missing) (?:x-)?api[-_ ]key|api key not valid".to_string(),
+        ),
+        (
+            LLMErrorKind::Timeout,
+            r"timed? ?out|timeout|deadline exceeded".to_string(),
+        ),
+        (
+            LLMErrorKind::ServerError,
+            r"internal server error|bad gateway|service unavailable|overloaded|connection (?:reset|refused|closed)|error sending request|broken pipe".to_string(),
+        ),
+        (
+            LLMErrorKind::InvalidRequest,
+            r"invalid[-_ ]request|bad request|does not exist|not supported|unsupported|no recorded llm response".to_string(),
+        ),
+        (LLMErrorKind::RateLimit, format!(r"{}429\b", STATUS)),
+        (LLMErrorKind::Authentication, format!(r"{}40[13]\b", STATUS)),
+        (LLMErrorKind::Timeout, format!(r"{}(?:408|504)\b", STATUS)),
+        (LLMErrorKind::ServerError, format!(r"{}5\d\d\b", STATUS)),
+        (LLMErrorKind::InvalidRequest, format!(r"{}(?:400|404|422)\b", STATUS)),
+    ]
+    .into_iter()
+    .map(|(kind, pattern)| (kind, Regex::new(&format!("(?i){}", pattern)).unwrap()))
+    .collect()
+});
+
+/// Kind of a model service error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LLMErrorKind {
+    /// Invalid or missing credentials
+    Authentication,
+    /// Request or token budget of the provider exhausted
+    RateLimit,
+    /// Prompt larger than the context window of the model
+    ContextOverflow,
+    /// Request timed out
+    Timeout,
+    /// Provider or network failure
+    ServerError,
+    /// Reply not matching the expected schema
+    SchemaMismatch,
+    /// Request the provider rejects, such as an unknown model or unsupported parameter
+    InvalidRequest,
+    /// Error of unknown kind
+    Other,
+}
+
+impl LLMErrorKind {
+    pub fn classify(error: &anyhow::Error) -> Self {
+        Self::classify_message(&format!("{:#}", error))
+    }
+
+    pub fn classify_message(message: &str) -> Self {
+        RULES
+            .iter()
+            .find(|(_, regex)| regex.is_match(message))
+            .map(|(kind, _)| *kind)
+            .unwrap_or(Self::Other)
+    }
+
+    /// Whether trying the same request again can succeed, the other kinds failing immediately
+    pub fn is_retryable(self) -> bool {
+        !matches!(
+            self,
+            Self::Authentication | Self::ContextOverflow | Self::InvalidRequest
+        )
+    }
+
+    /// Whether the error comes from the provider itself, another provider then being able to serve the same request.
+    /// Errors of the request (prompt too long, invalid parameters, unexpected reply) would fail on every provider
+    pub fn is_provider_outage(self) -> bool {
+        matches!(
+            self,
+            Self::ServerError | Self::Timeout | Self::RateLimit | Self::Authentication
+        )
+    }
+
+    /// Exponential delay before the attempt following `attempt` (from 1), with jitter so that concurrent
+    /// agents do not retry in lockstep
+    pub fn backoff(attempt: u32, base: Duration) -> Duration {
+        let delay = base
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(MAX_BACKOFF.max(base));
+        delay.mul_f64(rand::rng().random_range(0.5..=1.0))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Authentication => "authentication error",
+            Self::RateLimit => "rate limited",
+            Self::ContextOverflow => "context length exceeded",
+            Self::Timeout => "timeout",
+            Self::ServerError => "server error",
+            Self::SchemaMismatch => "schema mismatch",
+            Self::InvalidRequest => "invalid request",
+            Self::Other => "error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_provider_errors() {
+        let cases = [
+            ("HttpError: 401 Unauthorized: Incorrect API key provided", LLMErrorKind::Authentication),
+            ("ProviderError: Rate limit reached for gpt-4o, please try again in 20s", LLMErrorKind::RateLimit),
+            (
+                "ProviderError: This model's maximum context length is 128000 tokens (context_length_exceeded)",
+                LLMErrorKind::ContextOverflow,
+            ),
+            ("invalid_request_error: prompt is too long: 210000 tokens > 200000 maximum", LLMErrorKind::ContextOverflow),
+            ("HttpError: error sending request: operation timed out", LLMErrorKind::Timeout),
+            ("ProviderError: 529 Overloaded", LLMErrorKind::ServerError),
+            ("missing field `summary` at line 1 column 20", LLMErrorKind::SchemaMismatch),
+            ("No data extracted, the model did not call the `submit` tool", LLMErrorKind::SchemaMismatch),
+            ("ProviderError: The model `gpt-6` does not exist", LLMErrorKind::InvalidRequest),
+            ("something odd happened", LLMErrorKind::Other),
+            (
+                "ProviderError: Rate limit reached for gpt-4o on tokens per min (TPM): Limit 30000, Used 29800, Requested 403.",
+                LLMErrorKind::RateLimit,
+            ),
+            ("invalid type: integer `3`, expected a string at line 1 column 401", LLMErrorKind::SchemaMismatch),
+            ("expected `,` or `}` at line 12 column 403", LLMErrorKind::SchemaMismatch),
+            ("CompletionError: HTTP 403: {\"error\": \"region blocked\"}", LLMErrorKind::Authentication),
+            ("HttpError: status code: 401", LLMErrorKind::Authentication),
+            ("HttpError: status: 429, retry later", LLMErrorKind::RateLimit),
+            ("HttpError: status 503", LLMErrorKind::ServerError),
+            ("HttpError: HTTP 404 Not Found", LLMErrorKind::InvalidRequest),
+            ("Wrote 500 lines to the JSON report", LLMErrorKind::Other),
+            ("ResponseError: the reply was truncated after 404 tokens", LLMErrorKind::Other),
+            ("missing field `authentication` at line 4 column 3", LLMErrorKind::SchemaMismatch),
+            ("unknown variant `forbidden`, expected one of `public`, `internal`", LLMErrorKind::SchemaMismatch),
+            (
+                "ProviderError: {\"type\":\"error\",\"error\":{\"type\":\"authentication_error\",\"message\":\"invalid x-api-key\"}}",
+                LLMErrorKind::Authentication,
+            ),
+            ("ProviderError: 403 Forbidden", LLMErrorKind::Authentication),
+        ];
+        for (message, kind) in cases {
+            assert_eq!(LLMErrorKind::classify_message(message), kind, "{}", message);
+        }
+        assert!(LLMErrorKind::RateLimit.is_retryable());
+        assert!(!LLMErrorKind::ContextOverflow.is_retryable());
+        assert!(LLMErrorKind::Authentication.is_provider_outage());
+        assert!(!LLMErrorKind::ContextOverflow.is_provider_outage());
+        assert!(!LLMErrorKind::SchemaMismatch.is_provider_outage());
+    }
+
+    #[test]
+    fn test_backoff_grows_with_jitter() {
+        let base = Duration::from_millis(1000);
+        for attempt in 1..=4 {
+            let delay = LLMErrorKind::backoff(attempt, base);
+            let full = base * 2u32.pow(attempt - 1);
+            assert!(delay >= full / 2 && delay <= full, "attempt {}: {:?}", attempt, delay);
+        }
+        assert!(LLMErrorKind::backoff(30, base) <= MAX_BACKOFF);
+    }
+}
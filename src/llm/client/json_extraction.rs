@@ -0,0 +1,552 @@
+//! JSON extraction - Structured output from the text replies of any provider
+//!
+//! The output schema is put in the prompt and the reply parsed leniently: JSON found in code blocks or prose,
+//! trailing commas, single quotes and unquoted values repaired. The value is then conformed to the schema (enum
+//! values in another case, numbers and booleans as strings, single values for arrays) and validated, the
+//! validation errors being sent back to the model until the reply conforms. Tool-calling extraction goes through
+//! the same conforming step.
+
+use std::marker::PhantomData;
+use std::sync::LazyLock;
+
+use anyhow::{Context, Result, anyhow};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::providers::ProviderAgent;
+use super::types::TokenUsage;
+
+/// Replies requested before giving up on a model that does not follow the schema
+const REPAIR_ATTEMPTS: u32 = 3;
+
+/// Validation errors sent back to the model, the first ones being enough to fix the reply
+const MAX_REPORTED_ERRORS: usize = 20;
+
+static JSON_CODE_BLOCK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"```(?:json|JSON)?\s*([\s\S]*?)```").unwrap());
+
+/// Extractor asking for a JSON reply following the output schema
+pub struct JsonExtractor<T> {
+    agent: ProviderAgent,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> JsonExtractor<T>
+where
+    T: JsonSchema + DeserializeOwned,
+{
+    pub fn new(agent: ProviderAgent) -> Self {
+        Self {
+            agent,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Execute structured extraction, the usage covering every reply
+    pub async fn extract(&self, prompt: &str) -> Result<(T, TokenUsage)> {
+        let schema = serde_json::to_string_pretty(&schemars::schema_for!(T))?;
+        let mut usage = TokenUsage::default();
+        let mut last_error = String::new();
+
+        for attempt in 1..=REPAIR_ATTEMPTS {
+            let previous_error = (attempt > 1).then_some(last_error.as_str());
+            let (reply, reply_usage) = self.agent.prompt(&build_prompt(prompt, &schema, previous_error)).await?;
+            usage.add(&reply_usage);
+
+            match parse::<T>(&reply) {
+                Ok(output) => return Ok((output, usage)),
+                Err(e) => last_error = format!("{:#}", e),
+            }
+        }
+
+        Err(anyhow!(
+            "JSON reply still does not match the output schema after {} attempts: {}",
+            REPAIR_ATTEMPTS,
+            last_error
+        ))
+    }
+}
+
+/// Prompt with the output schema and, when re-prompting, the errors of the previous reply
+fn build_prompt(prompt: &str, schema: &str, previous_error: Option<&str>) -> String {
+    let mut request = format!(
+        "{}\n\n**Return the result as a single JSON object following this JSON schema:**\n\n```json\n{}\n```\n\n",
+        prompt, schema
+    );
+    request.push_str("Requirements:\n");
+    request.push_str("1. Return only the JSON object, without any text before or after it\n");
+    request.push_str("2. Include every required field, with the types of the schema\n");
+    request.push_str("3. Use exactly the enum values listed in the schema\n\n");
+    if let Some(error) = previous_error {
+        request.push_str(&format!(
+            "**Your previous reply was rejected:**\n{}\nReturn the corrected JSON object.\n",
+            error
+        ));
+    }
+    request
+}
+
+/// Parse a text reply into `T`, repairing and conforming it to the output schema
+pub fn parse<T: JsonSchema + DeserializeOwned>(reply: &str) -> Result<T> {
+    conform(parse_json(reply)?)
+}
+
+/// Conform a JSON value to the output schema of `T` and validate it before deserializing
+pub fn conform<T: JsonSchema + DeserializeOwned>(mut value: Value) -> Result<T> {
+    let schema = schemars::schema_for!(T);
+    let root = schema.as_value();
+    coerce(&mut value, root, root);
+
+    // Serde has the last word, accepting the aliases the schema does not list
+    let deserialize_error = match serde_json::from_value(value.clone()) {
+        Ok(output) => return Ok(output),
+        Err(e) => e,
+    };
+    let mut errors = Vec::new();
+    validate(&value, root, root, "$", &mut errors);
+    if errors.is_empty() {
+        return Err(deserialize_error).context("JSON reply does not match the output schema");
+    }
+    errors.truncate(MAX_REPORTED_ERRORS);
+    Err(anyhow!(
+        "JSON reply does not match the output schema:\n- {}",
+        errors.join("\n- ")
+    ))
+}
+
+/// JSON value of the reply: the whole reply, a code block or the first object or array, repaired when needed
+fn parse_json(reply: &str) -> Result<Value> {
+    let mut candidates = vec![reply.trim()];
+    candidates.extend(
+        JSON_CODE_BLOCK_REGEX
+            .captures_iter(reply)
+            .filter_map(|captures| captures.get(1))
+            .map(|block| block.as_str().trim()),
+    );
+    candidates.extend(first_json_value(reply));
+
+    for candidate in &candidates {
+        if let Ok(value) = serde_json::from_str::<Value>(candidate) {
+            return Ok(value);
+        }
+    }
+    for candidate in &candidates {
+        if let Ok(value) = serde_json::from_str::<Value>(&repair_syntax(candidate)) {
+            return Ok(value);
+        }
+    }
+
+    let preview: String = reply.chars().take(200).collect();
+    Err(anyhow!("No JSON object found in the reply: {}", preview))
+}
+
+/// First balanced JSON object or array of the text
+fn first_json_value(text: &str) -> Option<&str> {
+    let start = text.find(['{', '['])?;
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (offset, c) in text[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[start..start + offset + c.len_utf8()]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Repair the JSON syntax models get wrong: trailing commas, single-quoted strings, unquoted keys and values,
+/// and Python literals
+fn repair_syntax(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut repaired = String::with_capacity(text.len() + 16);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' | '\'' => {
+                // Copy the string, as a double-quoted one
+                repaired.push('"');
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    match chars[i] {
+                        '\\' if i + 1 < chars.len() => {
+                            if chars[i + 1] == '\'' {
+                                repaired.push('\'');
+                            } else {
+                                repaired.push('\\');
+                                repaired.push(chars[i + 1]);
+                            }
+                            i += 1;
+                        }
+                        '"' => repaired.push_str("\\\""),
+                        other => repaired.push(other),
+                    }
+                    i += 1;
+                }
+                repaired.push('"');
+            }
+            ',' => {
+                let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                if !matches!(next, Some('}') | Some(']') | None) {
+                    repaired.push(',');
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i + 1 < chars.len() && (chars[i + 1].is_alphanumeric() || matches!(chars[i + 1], '_' | '-' | '.')) {
+                    i += 1;
+                }
+                let word: String = chars[start..=i].iter().collect();
+                match word.as_str() {
+                    "true" | "True" => repaired.push_str("true"),
+                    "false" | "False" => repaired.push_str("false"),
+                    "null" | "None" => repaired.push_str("null"),
+                    _ => repaired.push_str(&format!("\"{}\"", word)),
+                }
+            }
+            other => repaired.push(other),
+        }
+        i += 1;
+    }
+    repaired
+}
+
+/// Resolve `#` and `#/$defs/Name` references
+fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    match schema.get("$ref").and_then(Value::as_str) {
+        Some(reference) => reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .map(|resolved| resolve(resolved, root))
+            .unwrap_or(schema),
+        None => schema,
+    }
+}
+
+/// String values allowed by `enum` and `const`, including those of the alternatives of documented enums
+fn allowed_strings<'a>(schema: &'a Value, root: &'a Value) -> Option<Vec<&'a str>> {
+    let schema = resolve(schema, root);
+    if let Some(constant) = schema.get("const") {
+        return constant.as_str().map(|constant| vec![constant]);
+    }
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return values.iter().map(Value::as_str).collect();
+    }
+    let alternatives = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(Value::as_array)?;
+    let mut allowed = Vec::new();
+    for alternative in alternatives {
+        allowed.extend(allowed_strings(alternative, root)?);
+    }
+    Some(allowed)
+}
+
+fn types(schema: &Value) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(schema_type)) => vec![schema_type.as_str()],
+        Some(Value::Array(schema_types)) => schema_types.iter().filter_map(Value::as_str).collect(),
+        _ if schema.get("properties").is_some() => vec!["object"],
+        _ => Vec::new(),
+    }
+}
+
+fn matches_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+/// Alternative of `oneOf` / `anyOf` the value is meant for: a null one for null, otherwise the first object
+/// alternative whose required fields are present, or the first non-null one
+fn pick_alternative<'a>(value: &Value, alternatives: &'a [Value], root: &'a Value) -> Option<&'a Value> {
+    let is_null = |alternative: &Value| types(resolve(alternative, root)) == ["null"];
+    if value.is_null() {
+        return alternatives.iter().find(|alternative| is_null(alternative));
+    }
+    let has_required = |alternative: &Value| {
+        let alternative = resolve(alternative, root);
+        let Some(object) = value.as_object() else {
+            return false;
+        };
+        alternative
+            .get("required")
+            .and_then(Value::as_array)
+            .is_some_and(|required| required.iter().filter_map(Value::as_str).all(|name| object.contains_key(name)))
+    };
+    alternatives
+        .iter()
+        .find(|alternative| has_required(alternative))
+        .or_else(|| alternatives.iter().find(|alternative| !is_null(alternative)))
+}
+
+/// Comparable form of an enum value: lowercase letters and digits
+fn normalize(value: &str) -> String {
+    value.chars().filter(char::is_ascii_alphanumeric).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+/// Conform the value to the schema where the intent is unambiguous
+fn coerce(value: &mut Value, schema: &Value, root: &Value) {
+    let schema = resolve(schema, root);
+
+    if let (Some(allowed), Value::String(text)) = (allowed_strings(schema, root), &*value) {
+        if !allowed.contains(&text.as_str()) {
+            let normalized = normalize(text);
+            if let Some(matching) = allowed.iter().find(|allowed| normalize(allowed) == normalized) {
+                *value = Value::String(matching.to_string());
+            } else if allowed.iter().all(|allowed| *allowed == allowed.to_lowercase()) {
+                // Lets the lowercase serde aliases (`configuration` for `config`) match
+                *value = Value::String(text.to_lowercase());
+            }
+        }
+        return;
+    }
+    if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+        for part in parts {
+            coerce(value, part, root);
+        }
+    }
+    for combinator in ["oneOf", "anyOf"] {
+        if let Some(alternatives) = schema.get(combinator).and_then(Value::as_array)
+            && let Some(alternative) = pick_alternative(value, alternatives, root)
+        {
+            coerce(value, alternative, root);
+            return;
+        }
+    }
+
+    let schema_types = types(schema);
+    if !schema_types.iter().any(|schema_type| matches_type(value, schema_type))
+        && let Some(converted) = convert(value, &schema_types)
+    {
+        *value = converted;
+    }
+
+    match value {
+        Value::Object(object) => {
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    if let Some(field) = object.get_mut(name) {
+                        coerce(field, property, root);
+                    }
+                }
+                // A required list left out is an empty one
+                for name in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+                    if let Some(name) = name.as_str()
+                        && !object.contains_key(name)
+                        && properties.get(name).is_some_and(|property| types(resolve(property, root)) == ["array"])
+                    {
+                        object.insert(name.to_string(), Value::Array(Vec::new()));
+                    }
+                }
+            }
+            if let Some(additional) = schema.get("additionalProperties").filter(|additional| additional.is_object()) {
+                let properties = schema.get("properties").and_then(Value::as_object);
+                for (name, field) in object.iter_mut() {
+                    if properties.is_none_or(|properties| !properties.contains_key(name)) {
+                        coerce(field, additional, root);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for item in items {
+                    coerce(item, item_schema, root);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Value converted to one of the schema types: numbers and booleans from strings and back, arrays from single
+/// values, empty arrays from null
+fn convert(value: &Value, schema_types: &[&str]) -> Option<Value> {
+    schema_types.iter().find_map(|schema_type| match (*schema_type, value) {
+        ("array", Value::Null) => Some(Value::Array(Vec::new())),
+        ("array", single) => Some(Value::Array(vec![single.clone()])),
+        ("integer", Value::String(text)) => text.trim().parse::<i64>().ok().map(Value::from),
+        ("integer", Value::Number(number)) => number
+            .as_f64()
+            .filter(|number| number.fract() == 0.0)
+            .map(|number| Value::from(number as i64)),
+        ("number", Value::String(text)) => text.trim().parse::<f64>().ok().map(Value::from),
+        ("boolean", Value::String(text)) => match text.trim().to_lowercase().as_str() {
+            "true" | "yes" => Some(Value::Bool(true)),
+            "false" | "no" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        ("string", Value::Number(number)) => Some(Value::String(number.to_string())),
+        ("string", Value::Bool(flag)) => Some(Value::String(flag.to_string())),
+        _ => None,
+    })
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Collect the differences between the value and the schema, with the JSON path of each
+fn validate(value: &Value, schema: &Value, root: &Value, path: &str, errors: &mut Vec<String>) {
+    let schema = resolve(schema, root);
+
+    if let Some(allowed) = allowed_strings(schema, root) {
+        if !value.as_str().is_some_and(|text| allowed.contains(&text)) {
+            errors.push(format!("{}: {} is not one of {}", path, value, allowed.join(", ")));
+        }
+        return;
+    }
+    if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+        for part in parts {
+            validate(value, part, root, path, errors);
+        }
+    }
+    for combinator in ["oneOf", "anyOf"] {
+        if let Some(alternatives) = schema.get(combinator).and_then(Value::as_array) {
+            // The errors of the closest alternative
+            let closest = alternatives
+                .iter()
+                .map(|alternative| {
+                    let mut alternative_errors = Vec::new();
+                    validate(value, alternative, root, path, &mut alternative_errors);
+                    alternative_errors
+                })
+                .min_by_key(Vec::len);
+            errors.extend(closest.unwrap_or_default());
+            return;
+        }
+    }
+
+    let schema_types = types(schema);
+    if !schema_types.is_empty() && !schema_types.iter().any(|schema_type| matches_type(value, schema_type)) {
+        errors.push(format!("{}: expected {}, found {}", path, schema_types.join(" or "), kind(value)));
+        return;
+    }
+
+    match value {
+        Value::Object(object) => validate_object(object, schema, root, path, errors),
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate(item, item_schema, root, &format!("{}[{}]", path, index), errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn validate_object(object: &Map<String, Value>, schema: &Value, root: &Value, path: &str, errors: &mut Vec<String>) {
+    for name in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+        if let Some(name) = name.as_str()
+            && !object.contains_key(name)
+        {
+            errors.push(format!("{}: missing required field `{}`", path, name));
+        }
+    }
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties").filter(|additional| additional.is_object());
+    for (name, field) in object {
+        let field_schema = properties.and_then(|properties| properties.get(name)).or(additional);
+        if let Some(field_schema) = field_schema {
+            validate(field, field_schema, root, &format!("{}.{}", path, name), errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::code::CodePurpose;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, JsonSchema, PartialEq)]
+    struct Insight {
+        name: String,
+        purpose: CodePurpose,
+        importance: f64,
+        interfaces: Vec<String>,
+        #[serde(default)]
+        exported: Option<bool>,
+    }
+
+    #[test]
+    fn test_parse_repairs_and_conforms_replies() {
+        let reply = r#"Here is the analysis:
+```json
+{
+  'name': 'cli',
+  purpose: Config,
+  "importance": "0.8",
+  "interfaces": "parse_args",
+  "exported": "yes",
+}
+```"#;
+        let insight: Insight = parse(reply).unwrap();
+        assert_eq!(
+            insight,
+            Insight {
+                name: "cli".to_string(),
+                purpose: CodePurpose::Config,
+                importance: 0.8,
+                interfaces: vec!["parse_args".to_string()],
+                exported: Some(true),
+            }
+        );
+
+        // Serde aliases match once lowercased, required lists default to empty
+        let insight: Insight =
+            parse(r#"The result is {"name": "docs", "purpose": "Documentation", "importance": 1}. Done."#).unwrap();
+        assert_eq!(insight.purpose, CodePurpose::Doc);
+        assert!(insight.interfaces.is_empty());
+    }
+
+    #[test]
+    fn test_validation_errors_name_the_fields() {
+        let error = parse::<Insight>(r#"{"purpose": "spaceship", "importance": "high", "interfaces": []}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("$: missing required field `name`"), "{}", error);
+        assert!(error.contains("$.purpose: \"spaceship\" is not one of entry"), "{}", error);
+        assert!(error.contains("$.importance: expected number, found string"), "{}", error);
+
+        assert!(parse::<Insight>("I could not analyze this file").is_err());
+    }
+}
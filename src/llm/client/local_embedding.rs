@@ -0,0 +1,74 @@
+//! Local embedding stand-in - Hashed bag-of-words vectors for providers without an embedding API
+
+/// Name recorded in vector indexes built with the local embedder
+pub const LOCAL_EMBEDDING_MODEL: &str = "local-hashing";
+
+/// Dimensions of the local embedding vectors
+const DIMENSIONS: usize = 256;
+
+/// Embed a text as a signed feature-hashing vector of its identifier sub-words, L2-normalized
+pub fn embed(text: &str) -> Vec<f32> {
+    let mut vector = vec![0f32; DIMENSIONS];
+    let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for word in sub_words(text) {
+        *counts.entry(word).or_default() += 1;
+    }
+
+    for (word, count) in counts {
+        let hash = fnv1a(word.as_bytes());
+        let slot = (hash % DIMENSIONS as u64) as usize;
+        let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[slot] += sign * (1.0 + (count as f32).ln());
+    }
+
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+/// Lowercase words of a text, identifiers split at `snake_case` and `camelCase` boundaries
+fn sub_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for token in text.split(|c: char| !c.is_alphanumeric()) {
+        let mut current = String::new();
+        let mut previous_lower = false;
+        for c in token.chars() {
+            if c.is_uppercase() && previous_lower && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = c.is_lowercase() || c.is_ascii_digit();
+            current.extend(c.to_lowercase());
+        }
+        words.push(current);
+    }
+    words.retain(|word| word.chars().count() >= 2);
+    words
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_local_embedding() {
+        assert_eq!(sub_words("parseHTTPRequest user_id"), vec!["parse", "httprequest", "user", "id"]);
+
+        let query = embed("order payment checkout");
+        let related = embed("fn checkout_order(payment: Payment) -> OrderReceipt");
+        let unrelated = embed("fn render_sidebar(theme: Theme) -> Html");
+        assert!((cosine(&query, &query) - 1.0).abs() < 1e-5);
+        assert!(cosine(&query, &related) > cosine(&query, &unrelated));
+    }
+}
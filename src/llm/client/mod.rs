@@ -1,48 +1,192 @@
 //! LLM client - Provides unified LLM service interface
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::future::Future;
+use std::sync::Arc;
 
-use crate::{config::Config, llm::client::utils::evaluate_befitting_model};
+use crate::{
+    config::Config,
+    llm::{client::utils::evaluate_befitting_model, pricing::PricingTable},
+    utils::{ignore_rules::IgnoreRules, token_estimator::TokenEstimator},
+};
 
 mod agent_builder;
-mod ollama_extractor;
+mod json_extraction;
+mod local_embedding;
+mod azure_openai;
+mod errors;
+mod openai_compatible;
 mod providers;
+mod rate_limiter;
 mod react;
+pub mod replay;
 mod react_executor;
 mod summary_reasoner;
 pub mod types;
 pub mod utils;
 
+pub use errors::LLMErrorKind;
 pub use react::{ReActConfig, ReActResponse};
 
 use agent_builder::AgentBuilder;
 use providers::ProviderClient;
+use rate_limiter::RateLimiter;
 use react_executor::ReActExecutor;
 use summary_reasoner::SummaryReasoner;
+use types::{LLMResponse, UsageTracker};
 
 /// LLM client - Provides unified LLM service interface
 #[derive(Clone)]
 pub struct LLMClient {
     config: Config,
     client: ProviderClient,
+    usage_tracker: UsageTracker,
+    /// Shared by the clones, so that concurrent agents draw from the same budgets
+    rate_limiter: RateLimiter,
+    /// Clients of the `[[llm.fallbacks]]` providers, tried in order when this one fails
+    fallbacks: Vec<LLMClient>,
+    /// Ignore rules of the project, loaded once for the file tools of every agent
+    ignore_rules: Arc<IgnoreRules>,
 }
 
 impl LLMClient {
     /// Create a new LLM client
     pub fn new(config: Config) -> Result<Self> {
+        let usage_tracker = UsageTracker::new(PricingTable::new(&config.llm));
+        let ignore_rules = Arc::new(IgnoreRules::load(&config));
+        let fallbacks = config
+            .llm
+            .fallbacks
+            .iter()
+            .map(|entry| {
+                let mut fallback_config = config.clone();
+                fallback_config.llm = config.llm.for_fallback(entry);
+                Self::for_provider(fallback_config, usage_tracker.clone(), ignore_rules.clone()).with_context(|| {
+                    format!("Failed to create fallback provider {} / {}", entry.provider, entry.model)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut client = Self::for_provider(config, usage_tracker, ignore_rules)?;
+        client.fallbacks = fallbacks;
+        Ok(client)
+    }
+
+    /// Client of the configured provider alone
+    fn for_provider(
+        config: Config,
+        usage_tracker: UsageTracker,
+        ignore_rules: Arc<IgnoreRules>,
+    ) -> Result<Self> {
         let client = ProviderClient::new(&config.llm)?;
-        Ok(Self { client, config })
+        let rate_limiter = RateLimiter::new(
+            &config.llm.rate_limit,
+            std::time::Duration::from_millis(config.llm.retry_delay_ms),
+        );
+        Ok(Self {
+            client,
+            config,
+            usage_tracker,
+            rate_limiter,
+            fallbacks: Vec::new(),
+            ignore_rules,
+        })
+    }
+
+    /// Client for the calls of an agent, with the model and parameters routed to it in `[llm.routes]`
+    pub fn routed(&self, model_route: &str) -> Self {
+        let mut client = self.clone();
+        if let Some(llm_config) = self.config.llm.routed(model_route) {
+            client.config.llm = llm_config;
+        }
+        for fallback in &mut client.fallbacks {
+            if let Some(mut llm_config) = fallback.config.llm.routed(model_route) {
+                // The routed model belongs to the configured provider, fallbacks keep theirs
+                llm_config.model_efficient = fallback.config.llm.model_efficient.clone();
+                llm_config.model_powerful = fallback.config.llm.model_powerful.clone();
+                fallback.config.llm = llm_config;
+            }
+        }
+        client
+    }
+
+    /// Run the call on this provider, then on the fallback providers in order as long as the provider is failing.
+    /// Errors of the request itself go back to the caller, which may shrink the prompt on a context overflow
+    async fn with_fallbacks<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        F: Fn(LLMClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut result = call(self.clone()).await;
+        let mut failed = self;
+        for fallback in &self.fallbacks {
+            let Err(e) = &result else {
+                break;
+            };
+            if !LLMErrorKind::classify(e).is_provider_outage() {
+                break;
+            }
+            eprintln!(
+                "⚠️ Provider {} / {} failed, falling back to {} / {}: {}",
+                failed.config.llm.provider,
+                failed.config.llm.model_efficient,
+                fallback.config.llm.provider,
+                fallback.config.llm.model_efficient,
+                e
+            );
+            result = call(fallback.clone()).await;
+            failed = fallback;
+        }
+        result
+    }
+
+    /// Ignore rules of the project, shared with the generation stages
+    pub fn ignore_rules(&self) -> Arc<IgnoreRules> {
+        self.ignore_rules.clone()
+    }
+
+    /// Token usage of the calls made through this client and its clones
+    pub fn usage_tracker(&self) -> &UsageTracker {
+        &self.usage_tracker
     }
 
     /// Get Agent builder
     fn get_agent_builder(&self) -> AgentBuilder<'_> {
-        AgentBuilder::new(&self.client, &self.config)
+        AgentBuilder::new(&self.client, &self.config, &self.ignore_rules)
+    }
+
+    /// Run one model service call within the rate limits, `used_tokens` reading the usage reported by the provider
+    ///
+    /// A ReAct dialogue counts as one request, its tool turns being driven by rig.
+    async fn rate_limited<T, Fut>(
+        &self,
+        prompt: &str,
+        call: Fut,
+        used_tokens: impl FnOnce(&T) -> Option<usize>,
+    ) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        let estimated_tokens = TokenEstimator::new().estimate_tokens(prompt).estimated_tokens;
+        let permit = self.rate_limiter.acquire(estimated_tokens).await;
+        let result = call.await;
+        match &result {
+            Ok(output) => {
+                // Providers reporting no usage keep the estimate
+                if let Some(used) = used_tokens(output).filter(|used| *used > 0) {
+                    permit.settle(used);
+                }
+            }
+            Err(e) => self.rate_limiter.observe_error(&format!("{:#}", e)),
+        }
+        result
     }
 
     /// Generic retry logic for handling async operation retry mechanism
+    ///
+    /// Retryable errors are retried with exponential backoff from `retry_delay_ms`, the other ones fail immediately.
     async fn retry_with_backoff<T, F, Fut>(&self, operation: F) -> Result<T>
     where
         F: Fn() -> Fut,
@@ -50,29 +194,79 @@ impl LLMClient {
     {
         let llm_config = &self.config.llm;
         let max_retries = llm_config.retry_attempts;
-        let retry_delay_ms = llm_config.retry_delay_ms;
+        let retry_delay = std::time::Duration::from_millis(llm_config.retry_delay_ms);
         let mut retries = 0;
 
         loop {
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(err) => {
+                    let kind = LLMErrorKind::classify(&err);
+                    if !kind.is_retryable() {
+                        eprintln!("❌ Model service call failed ({}), not retrying: {}", kind.label(), err);
+                        return Err(err);
+                    }
                     retries += 1;
                     eprintln!(
-                        "❌ Model service call error, retrying (attempt {} / {}): {}",
-                        retries, max_retries, err
+                        "❌ Model service call error ({}), retrying (attempt {} / {}): {}",
+                        kind.label(),
+                        retries,
+                        max_retries,
+                        err
                     );
                     if retries >= max_retries {
                         return Err(err);
                     }
-                    tokio::time::sleep(std::time::Duration::from_millis(retry_delay_ms)).await;
+                    tokio::time::sleep(LLMErrorKind::backoff(retries, retry_delay)).await;
                 }
             }
         }
     }
 
+    /// Name of the embedding model used by `embed`
+    pub fn embedding_model(&self) -> String {
+        match &self.config.retrieval.embedding_model {
+            Some(model) if self.client.supports_embeddings() => model.clone(),
+            _ => local_embedding::LOCAL_EMBEDDING_MODEL.to_string(),
+        }
+    }
+
+    /// Embed texts with the configured embedding model, or with the local hashing embedder
+    pub async fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let model = self.embedding_model();
+        if model == local_embedding::LOCAL_EMBEDDING_MODEL {
+            return Ok(texts.iter().map(|text| local_embedding::embed(text)).collect());
+        }
+
+        let vectors = self
+            .retry_with_backoff(|| async {
+                let call = async {
+                    self.client
+                        .embed_texts(&model, texts.clone())
+                        .await
+                        .unwrap_or_else(|| Err(anyhow::anyhow!("Provider has no embedding API")))
+                };
+                self.rate_limited(&texts.concat(), call, |_| None).await
+            })
+            .await?;
+        Ok(vectors
+            .into_iter()
+            .map(|vector| vector.into_iter().map(|v| v as f32).collect())
+            .collect())
+    }
+
     /// Data extraction method
-    pub async fn extract<T>(&self, system_prompt: &str, user_prompt: &str) -> Result<T>
+    pub async fn extract<T>(&self, system_prompt: &str, user_prompt: &str) -> Result<LLMResponse<T>>
+    where
+        T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+    {
+        self.with_fallbacks(|client| async move {
+            client.extract_on_provider(system_prompt, user_prompt).await
+        })
+        .await
+    }
+
+    async fn extract_on_provider<T>(&self, system_prompt: &str, user_prompt: &str) -> Result<LLMResponse<T>>
     where
         T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
     {
@@ -89,7 +283,7 @@ impl LLMClient {
         user_prompt: &str,
         befitting_model: String,
         fallover_model: Option<String>,
-    ) -> Result<T>
+    ) -> Result<LLMResponse<T>>
     where
         T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
     {
@@ -99,44 +293,60 @@ impl LLMClient {
             self.client
                 .create_extractor::<T>(&befitting_model, system_prompt, llm_config);
 
-        self.retry_with_backoff(|| async {
-            match extractor.extract(user_prompt).await {
-                Ok(r) => Ok(r),
-                Err(e) => match fallover_model {
-                    Some(ref model) => {
-                        let msg = self.config.target_language.msg_ai_service_error()
-                            .replace("{}", &llm_config.retry_attempts.to_string())
-                            .replace("{}", &format!(" trying fallback model {}...{}", model, e));
-                        eprintln!("{}", msg);
-                        let user_prompt_with_fixer = format!("{}\n\n**Notice** There was an error during my previous LLM call, error message: \"{}\". Please ensure you avoid this error this time", user_prompt, e);
-                        Box::pin(self.extract_inner(
-                            system_prompt,
-                            &user_prompt_with_fixer,
-                            model.clone(),
-                            None,
-                        ))
-                        .await
-                    }
-                    None => {
-                        let msg = self.config.target_language.msg_ai_service_error()
-                            .replace("{}", &llm_config.retry_attempts.to_string())
-                            .replace("{}", &e.to_string());
-                        eprintln!("{}", msg);
-                        Err(e.into())
-                    }
-                },
+        let result = self
+            .retry_with_backoff(|| async {
+                let call = extractor.extract(user_prompt);
+                let prompt = format!("{}{}", system_prompt, user_prompt);
+                self.rate_limited(&prompt, call, |(_, usage)| Some(usage.total_tokens))
+                    .await
+            })
+            .await;
+
+        // Fall over to the powerful model once the retries of the efficient one are exhausted
+        match (result, fallover_model) {
+            (Ok((output, usage)), _) => Ok(LLMResponse {
+                output,
+                usage,
+                model: befitting_model,
+                provider: llm_config.provider.to_string(),
+            }),
+            (Err(e), Some(model)) => {
+                let msg = self.config.target_language.msg_ai_service_error()
+                    .replace("{}", &llm_config.retry_attempts.to_string())
+                    .replace("{}", &format!(" trying fallback model {}...{}", model, e));
+                eprintln!("{}", msg);
+                let user_prompt_with_fixer = format!("{}\n\n**Notice** There was an error during my previous LLM call, error message: \"{}\". Please ensure you avoid this error this time", user_prompt, e);
+                Box::pin(self.extract_inner(system_prompt, &user_prompt_with_fixer, model, None)).await
+            }
+            (Err(e), None) => {
+                let msg = self.config.target_language.msg_ai_service_error()
+                    .replace("{}", &llm_config.retry_attempts.to_string())
+                    .replace("{}", &e.to_string());
+                eprintln!("{}", msg);
+                Err(e)
             }
+        }
+    }
+
+    /// Intelligent dialogue method (using default ReAct configuration)
+    pub async fn prompt(&self, system_prompt: &str, user_prompt: &str) -> Result<LLMResponse<String>> {
+        self.with_fallbacks(|client| async move {
+            client.prompt_on_provider(system_prompt, user_prompt).await
         })
         .await
     }
 
-    /// Intelligent dialogue method (using default ReAct configuration)
-    pub async fn prompt(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+    async fn prompt_on_provider(&self, system_prompt: &str, user_prompt: &str) -> Result<LLMResponse<String>> {
         let react_config = ReActConfig::default();
         let response = self
             .prompt_with_react(system_prompt, user_prompt, react_config)
             .await?;
-        Ok(response.content)
+        Ok(LLMResponse {
+            output: response.content,
+            usage: response.usage,
+            model: self.get_agent_builder().model().to_string(),
+            provider: self.config.llm.provider.to_string(),
+        })
     }
 
     /// Multi-turn dialogue using ReAct mode
@@ -151,9 +361,11 @@ impl LLMClient {
 
         let response = self
             .retry_with_backoff(|| async {
-                ReActExecutor::execute(&agent, user_prompt, &react_config, &self.config.target_language)
+                let call =
+                    ReActExecutor::execute(&agent, user_prompt, &react_config, &self.config.target_language);
+                let prompt = format!("{}{}", system_prompt, user_prompt);
+                self.rate_limited(&prompt, call, |response| Some(response.usage.total_tokens))
                     .await
-                    .map_err(|e| e.into())
             })
             .await?;
 
@@ -204,17 +416,18 @@ impl LLMClient {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Missing chat history"))?;
 
-        let summary_result = self
+        let (summary_result, usage) = self
             .retry_with_backoff(|| async {
-                SummaryReasoner::summarize_and_reason(
+                let call = SummaryReasoner::summarize_and_reason(
                     &agent_without_tools,
                     system_prompt,
                     user_prompt,
                     chat_history,
                     &original_response.tool_calls_history,
-                )
-                .await
-                .map_err(|e| e.into())
+                );
+                let prompt = format!("{}{}", system_prompt, user_prompt);
+                self.rate_limited(&prompt, call, |(_, usage)| Some(usage.total_tokens))
+                    .await
             })
             .await?;
 
@@ -223,6 +436,7 @@ impl LLMClient {
             original_response.iterations_used,
             original_response.tool_calls_history.clone(),
             chat_history.clone(),
+            usage,
         ))
     }
 
@@ -231,11 +445,202 @@ impl LLMClient {
         &self,
         system_prompt: &str,
         user_prompt: &str,
-    ) -> Result<String> {
+    ) -> Result<LLMResponse<String>> {
+        self.with_fallbacks(|client| async move {
+            client.prompt_without_react_on_provider(system_prompt, user_prompt).await
+        })
+        .await
+    }
+
+    async fn prompt_without_react_on_provider(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<LLMResponse<String>> {
         let agent_builder = self.get_agent_builder();
         let agent = agent_builder.build_agent_without_tools(system_prompt);
 
-        self.retry_with_backoff(|| async { agent.prompt(user_prompt).await.map_err(|e| e.into()) })
-            .await
+        let (output, usage) = self
+            .retry_with_backoff(|| async {
+                let prompt = format!("{}{}", system_prompt, user_prompt);
+                self.rate_limited(&prompt, agent.prompt(user_prompt), |(_, usage)| {
+                    Some(usage.total_tokens)
+                })
+                .await
+            })
+            .await?;
+        Ok(LLMResponse {
+            output,
+            usage,
+            model: agent_builder.model().to_string(),
+            provider: self.config.llm.provider.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FallbackEntry, LLMProvider, ModelRoute};
+    use crate::utils::test_support::TempProject;
+
+    #[tokio::test]
+    async fn test_fallback_chain_serves_failed_calls() {
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::Ollama;
+        // Nothing listens on the discard port, the calls fail with a connection error
+        config.llm.api_base_url = "http://127.0.0.1:9".to_string();
+        config.llm.retry_attempts = 1;
+        config.llm.retry_delay_ms = 1;
+        config.llm.replay.synthesize_missing = true;
+        config.llm.fallbacks.push(FallbackEntry {
+            provider: LLMProvider::Replay,
+            model: "recorded".to_string(),
+            api_key: None,
+            api_base_url: None,
+            retry_attempts: None,
+        });
+
+        let client = LLMClient::new(config).unwrap();
+        let response = client.prompt_without_react("system", "hello").await.unwrap();
+        assert_eq!(response.provider, "replay");
+        assert_eq!(response.model, "recorded");
+    }
+
+    #[tokio::test]
+    async fn test_responses_report_the_model_of_the_agent() {
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::Replay;
+        config.llm.model_efficient = "small".to_string();
+        config.llm.model_powerful = "large".to_string();
+        config.llm.replay.synthesize_missing = true;
+        config.llm.routes.insert(
+            "documentation".to_string(),
+            ModelRoute {
+                model: Some("routed".to_string()),
+                temperature: None,
+                max_tokens: None,
+            },
+        );
+
+        let client = LLMClient::new(config).unwrap();
+        let response = client.prompt_without_react("system", "hello").await.unwrap();
+        assert_eq!(response.model, "small");
+        let routed = client.routed("documentation");
+        assert_eq!(routed.prompt_without_react("system", "hello").await.unwrap().model, "routed");
+        assert_eq!(routed.prompt("system", "hello").await.unwrap().model, "routed");
+    }
+
+    #[tokio::test]
+    async fn test_request_errors_skip_the_fallback_chain() {
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::Replay;
+        let fixtures = TempProject::new("no_fixtures");
+        config.llm.replay.fixtures_dir = fixtures.path().to_path_buf();
+        config.llm.replay.synthesize_missing = false;
+        config.llm.retry_attempts = 1;
+        config.llm.fallbacks.push(FallbackEntry {
+            provider: LLMProvider::Ollama,
+            model: "llama3".to_string(),
+            api_key: None,
+            api_base_url: Some("http://127.0.0.1:9".to_string()),
+            retry_attempts: None,
+        });
+
+        // A missing fixture is an invalid request, which no other provider can serve
+        let client = LLMClient::new(config).unwrap();
+        let error = client.prompt_without_react("system", "hello").await.unwrap_err();
+        assert_eq!(LLMErrorKind::classify(&error), LLMErrorKind::InvalidRequest, "{:#}", error);
+    }
+
+    #[tokio::test]
+    async fn test_extraction_falls_over_to_the_powerful_model_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Server failing every request with a 503, counting them
+        let requests = Arc::new(AtomicUsize::new(0));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 8192];
+                while let Ok(read) = stream.read(&mut buffer).await {
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_lowercase();
+                    let complete = text.split_once("\r\n\r\n").is_some_and(|(headers, body)| {
+                        let length = headers
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length:")?.trim().parse().ok())
+                            .unwrap_or(0);
+                        body.len() >= length
+                    });
+                    if read == 0 || complete {
+                        break;
+                    }
+                }
+                let reply = "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+                let _ = stream.write_all(reply.as_bytes()).await;
+            }
+        });
+
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::OpenAICompatible;
+        config.llm.api_base_url = format!("http://{}", address);
+        config.llm.model_efficient = "small".to_string();
+        config.llm.model_powerful = "large".to_string();
+        config.llm.retry_attempts = 2;
+        config.llm.retry_delay_ms = 1;
+
+        let client = LLMClient::new(config).unwrap();
+        let result = client.extract::<String>("system", "hello").await;
+        assert!(result.is_err());
+        // Two attempts on the efficient model, then two on the powerful one
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_cross_provider_fallbacks_get_their_endpoint_and_key() {
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::OpenAI;
+        config.llm.api_key = "sk-openai".to_string();
+        config.llm.api_base_url = "https://gateway.example.com/v1".to_string();
+        let anthropic = FallbackEntry {
+            provider: LLMProvider::Anthropic,
+            model: "claude-sonnet-4-5".to_string(),
+            api_key: Some("sk-ant".to_string()),
+            api_base_url: None,
+            retry_attempts: None,
+        };
+        let deepseek = FallbackEntry {
+            provider: LLMProvider::DeepSeek,
+            model: "deepseek-chat".to_string(),
+            api_key: None,
+            ..anthropic.clone()
+        };
+
+        let anthropic_config = config.llm.for_fallback(&anthropic);
+        assert_eq!(anthropic_config.api_base_url, "https://api.anthropic.com");
+        assert_eq!(anthropic_config.api_key, "sk-ant");
+        let deepseek_config = config.llm.for_fallback(&deepseek);
+        assert_eq!(deepseek_config.api_base_url, "https://api.deepseek.com");
+        assert_eq!(deepseek_config.api_key, std::env::var("DEEPSEEK_API_KEY").unwrap_or_default());
+
+        config.llm.fallbacks = vec![anthropic.clone()];
+        let client = LLMClient::new(config.clone()).unwrap();
+        assert_eq!(client.fallbacks[0].config.llm.provider, LLMProvider::Anthropic);
+
+        // A gateway has no public endpoint to fall back on
+        config.llm.fallbacks.push(FallbackEntry {
+            provider: LLMProvider::OpenAICompatible,
+            model: "qwen2.5-coder".to_string(),
+            api_key: None,
+            ..anthropic
+        });
+        let error = LLMClient::new(config).err().unwrap();
+        assert!(format!("{:#}", error).contains("needs api_base_url"), "{:#}", error);
     }
 }
@@ -0,0 +1,107 @@
+//! OpenAI-compatible provider - Gateways and local servers (vLLM, LM Studio, llama.cpp server) speaking the OpenAI API
+
+use anyhow::{Result, bail};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
+use serde_json::Value;
+
+use crate::config::{LLMConfig, OpenAIApi, OpenAICompatibleConfig};
+
+/// OpenAI client configured with the gateway's headers, authentication and base path
+#[derive(Clone)]
+pub struct OpenAICompatibleClient {
+    pub client: rig::providers::openai::Client,
+    /// Whether to call the responses endpoint instead of chat completions
+    pub responses_api: bool,
+    /// Parameters merged into every request body
+    pub extra_body: Option<Value>,
+}
+
+impl OpenAICompatibleClient {
+    pub fn new(config: &LLMConfig) -> Result<Self> {
+        let compatible = &config.openai_compatible;
+        let (headers, bearer_key) = request_headers(compatible, &config.api_key)?;
+        let http_client = reqwest::Client::builder().default_headers(headers).build()?;
+        let base_url = base_url(&config.api_base_url, &compatible.path_prefix);
+
+        let client = rig::providers::openai::Client::builder(&bearer_key)
+            .base_url(&base_url)
+            .with_client(http_client)
+            .build();
+
+        Ok(Self {
+            client,
+            responses_api: compatible.api == OpenAIApi::Responses,
+            extra_body: (!compatible.extra_body.is_empty())
+                .then(|| Value::Object(compatible.extra_body.clone())),
+        })
+    }
+}
+
+/// Default headers of every request, and the key sent as `Authorization: Bearer` (empty when a custom auth header carries it)
+fn request_headers(compatible: &OpenAICompatibleConfig, api_key: &str) -> Result<(HeaderMap, String)> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &compatible.headers {
+        headers.insert(HeaderName::from_bytes(name.as_bytes())?, HeaderValue::from_str(value)?);
+    }
+
+    let Some(auth_header) = &compatible.auth_header else {
+        return Ok((headers, api_key.to_string()));
+    };
+    let auth_header = HeaderName::from_bytes(auth_header.as_bytes())?;
+    if auth_header == AUTHORIZATION {
+        // The OpenAI client always writes `Authorization: Bearer <key>` itself
+        return match compatible.auth_scheme.as_deref() {
+            None | Some("Bearer") => Ok((headers, api_key.to_string())),
+            Some(scheme) => bail!(
+                "auth_scheme `{}` needs a custom auth_header, the Authorization header always uses Bearer",
+                scheme
+            ),
+        };
+    }
+
+    let credential = match &compatible.auth_scheme {
+        Some(scheme) => format!("{} {}", scheme, api_key),
+        None => api_key.to_string(),
+    };
+    let mut value = HeaderValue::from_str(&credential)?;
+    value.set_sensitive(true);
+    headers.insert(auth_header, value);
+    Ok((headers, String::new()))
+}
+
+fn base_url(api_base_url: &str, path_prefix: &str) -> String {
+    let base_url = api_base_url.trim_end_matches('/');
+    let path_prefix = path_prefix.trim_matches('/');
+    if path_prefix.is_empty() {
+        base_url.to_string()
+    } else {
+        format!("{}/{}", base_url, path_prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_headers_and_base_url() {
+        let mut compatible = OpenAICompatibleConfig::default();
+        compatible.headers.insert("X-Team".to_string(), "docs".to_string());
+
+        let (headers, bearer_key) = request_headers(&compatible, "secret").unwrap();
+        assert_eq!(headers["x-team"], "docs");
+        assert_eq!(bearer_key, "secret");
+
+        compatible.auth_header = Some("X-API-Key".to_string());
+        compatible.auth_scheme = Some("Token".to_string());
+        let (headers, bearer_key) = request_headers(&compatible, "secret").unwrap();
+        assert_eq!(headers["x-api-key"], "Token secret");
+        assert!(bearer_key.is_empty());
+
+        compatible.auth_header = Some("Authorization".to_string());
+        assert!(request_headers(&compatible, "secret").is_err());
+
+        assert_eq!(base_url("http://localhost:8000/", "/v1/"), "http://localhost:8000/v1");
+        assert_eq!(base_url("http://localhost:8000", ""), "http://localhost:8000");
+    }
+}
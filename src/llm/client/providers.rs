@@ -1,10 +1,11 @@
 //! LLM Provider support module
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use rig::{
     agent::Agent,
-    client::CompletionClient,
-    completion::{Prompt, PromptError},
+    client::{CompletionClient, EmbeddingsClient},
+    completion::{AssistantContent, Completion, CompletionModel, Message, Prompt, PromptError},
+    embeddings::EmbeddingModel,
     extractor::Extractor,
     providers::gemini::completion::gemini_api_types::{AdditionalParameters, GenerationConfig},
 };
@@ -16,7 +17,14 @@ use crate::{
     llm::tools::time::AgentToolTime,
 };
 
-use super::ollama_extractor::OllamaExtractorWrapper;
+use super::azure_openai::AzureOpenAIClient;
+use super::types::TokenUsage;
+use super::json_extraction::{self, JsonExtractor};
+use super::openai_compatible::OpenAICompatibleClient;
+use super::replay::{FixtureStore, ReplayAgent, ReplayClient, ReplayExtractor};
+
+/// Name of the tool rig's extractors register for submitting the structured data
+const SUBMIT_TOOL_NAME: &str = "submit";
 
 /// Unified Provider client enum
 #[derive(Clone)]
@@ -29,11 +37,26 @@ pub enum ProviderClient {
     Anthropic(rig::providers::anthropic::Client),
     Gemini(rig::providers::gemini::Client),
     Ollama(rig::providers::ollama::Client),
+    OpenAICompatible(OpenAICompatibleClient),
+    AzureOpenAI(AzureOpenAIClient),
+    Replay(ReplayClient),
 }
 
 impl ProviderClient {
     /// Create corresponding provider client based on configuration
     pub fn new(config: &LLMConfig) -> Result<Self> {
+        if config.provider.uses_api_base_url() && config.api_base_url.trim().is_empty() {
+            anyhow::bail!("The {} provider needs api_base_url", config.provider);
+        }
+        if let Some(env_name) = config.provider.api_key_env()
+            && config.api_key.trim().is_empty()
+        {
+            anyhow::bail!(
+                "The {} provider needs api_key, set it in the configuration or in {}",
+                config.provider,
+                env_name
+            );
+        }
         match config.provider {
             LLMProvider::OpenAI => {
                 let client = rig::providers::openai::Client::builder(&config.api_key)
@@ -77,6 +100,61 @@ impl ProviderClient {
                     .build();
                 Ok(ProviderClient::Ollama(client))
             }
+            LLMProvider::OpenAICompatible => {
+                Ok(ProviderClient::OpenAICompatible(OpenAICompatibleClient::new(config)?))
+            }
+            LLMProvider::AzureOpenAI => Ok(ProviderClient::AzureOpenAI(AzureOpenAIClient::new(config)?)),
+            LLMProvider::Replay => {
+                let live = match &config.replay.record_with {
+                    Some(LLMProvider::Replay) => {
+                        anyhow::bail!("The replay provider cannot record itself")
+                    }
+                    Some(provider) => Some(Box::new(ProviderClient::new(&LLMConfig {
+                        provider: provider.clone(),
+                        ..config.clone()
+                    })?)),
+                    None => None,
+                };
+                Ok(ProviderClient::Replay(ReplayClient {
+                    store: FixtureStore::new(&config.replay),
+                    live,
+                }))
+            }
+        }
+    }
+
+    /// Whether the provider offers an embedding API
+    pub fn supports_embeddings(&self) -> bool {
+        matches!(
+            self,
+            ProviderClient::OpenAI(_)
+                | ProviderClient::Mistral(_)
+                | ProviderClient::Gemini(_)
+                | ProviderClient::Ollama(_)
+                | ProviderClient::OpenAICompatible(_)
+                | ProviderClient::AzureOpenAI(_)
+        )
+    }
+
+    /// Embed texts with the provider's embedding API, `None` when the provider has none
+    pub async fn embed_texts(&self, model: &str, texts: Vec<String>) -> Option<Result<Vec<Vec<f64>>>> {
+        match self {
+            ProviderClient::OpenAI(client) => Some(embed_with(client.embedding_model(model), texts).await),
+            ProviderClient::Mistral(client) => Some(embed_with(client.embedding_model(model), texts).await),
+            ProviderClient::Gemini(client) => Some(embed_with(client.embedding_model(model), texts).await),
+            ProviderClient::Ollama(client) => Some(embed_with(client.embedding_model(model), texts).await),
+            ProviderClient::OpenAICompatible(compatible) => {
+                Some(embed_with(compatible.client.embedding_model(model), texts).await)
+            }
+            ProviderClient::AzureOpenAI(azure) => {
+                let deployment = azure.deployment(model);
+                Some(embed_with(azure.client.embedding_model(deployment), texts).await)
+            }
+            ProviderClient::Moonshot(_)
+            | ProviderClient::DeepSeek(_)
+            | ProviderClient::OpenRouter(_)
+            | ProviderClient::Anthropic(_)
+            | ProviderClient::Replay(_) => None,
         }
     }
 
@@ -195,6 +273,61 @@ impl ProviderClient {
                 let agent = builder.build();
                 ProviderAgent::Ollama(agent)
             }
+            ProviderClient::AzureOpenAI(azure) => {
+                let mut builder = azure
+                    .client
+                    .agent(azure.deployment(model))
+                    .preamble(system_prompt)
+                    .max_tokens(config.max_tokens.into());
+
+                if let Some(temp) = config.temperature {
+                    builder = builder.temperature(temp);
+                }
+
+                ProviderAgent::AzureOpenAI(builder.build())
+            }
+            ProviderClient::OpenAICompatible(compatible) => {
+                if compatible.responses_api {
+                    let mut builder = compatible
+                        .client
+                        .agent(model)
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+
+                    if let Some(temp) = config.temperature {
+                        builder = builder.temperature(temp);
+                    }
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+
+                    ProviderAgent::OpenAIResponses(builder.build())
+                } else {
+                    let mut builder = compatible
+                        .client
+                        .completion_model(model)
+                        .completions_api()
+                        .into_agent_builder()
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+
+                    if let Some(temp) = config.temperature {
+                        builder = builder.temperature(temp);
+                    }
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+
+                    ProviderAgent::OpenAI(builder.build())
+                }
+            }
+            ProviderClient::Replay(client) => {
+                let live = client
+                    .live
+                    .as_ref()
+                    .map(|live| live.create_agent(model, system_prompt, config));
+                ProviderAgent::Replay(ReplayAgent::new(client.store.clone(), model, system_prompt, live))
+            }
         }
     }
 
@@ -206,6 +339,7 @@ impl ProviderClient {
         config: &LLMConfig,
         file_explorer: &crate::llm::tools::file_explorer::AgentToolFileExplorer,
         file_reader: &crate::llm::tools::file_reader::AgentToolFileReader,
+        symbol_lookup: &crate::llm::tools::symbol_lookup::AgentToolSymbolLookup,
     ) -> ProviderAgent {
         let tool_time = AgentToolTime::new();
 
@@ -225,6 +359,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::OpenAI(agent)
@@ -242,6 +377,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::Moonshot(agent)
@@ -259,6 +395,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::DeepSeek(agent)
@@ -275,6 +412,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::Mistral(agent)
@@ -291,6 +429,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::OpenRouter(agent)
@@ -308,6 +447,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::Anthropic(agent)
@@ -328,6 +468,7 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .additional_params(serde_json::to_value(cfg).unwrap())
                     .build();
@@ -346,10 +487,90 @@ impl ProviderClient {
                 let agent = builder
                     .tool(file_explorer.clone())
                     .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
                     .tool(tool_time)
                     .build();
                 ProviderAgent::Ollama(agent)
             }
+            ProviderClient::AzureOpenAI(azure) => {
+                let mut builder = azure
+                    .client
+                    .agent(azure.deployment(model))
+                    .preamble(system_prompt)
+                    .max_tokens(config.max_tokens.into());
+
+                if let Some(temp) = config.temperature {
+                    builder = builder.temperature(temp);
+                }
+
+                let agent = builder
+                    .tool(file_explorer.clone())
+                    .tool(file_reader.clone())
+                    .tool(symbol_lookup.clone())
+                    .tool(tool_time)
+                    .build();
+                ProviderAgent::AzureOpenAI(agent)
+            }
+            ProviderClient::OpenAICompatible(compatible) => {
+                if compatible.responses_api {
+                    let mut builder = compatible
+                        .client
+                        .agent(model)
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+
+                    if let Some(temp) = config.temperature {
+                        builder = builder.temperature(temp);
+                    }
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+
+                    let agent = builder
+                        .tool(file_explorer.clone())
+                        .tool(file_reader.clone())
+                        .tool(symbol_lookup.clone())
+                        .tool(tool_time)
+                        .build();
+                    ProviderAgent::OpenAIResponses(agent)
+                } else {
+                    let mut builder = compatible
+                        .client
+                        .completion_model(model)
+                        .completions_api()
+                        .into_agent_builder()
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+
+                    if let Some(temp) = config.temperature {
+                        builder = builder.temperature(temp);
+                    }
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+
+                    let agent = builder
+                        .tool(file_explorer.clone())
+                        .tool(file_reader.clone())
+                        .tool(symbol_lookup.clone())
+                        .tool(tool_time)
+                        .build();
+                    ProviderAgent::OpenAI(agent)
+                }
+            }
+            ProviderClient::Replay(client) => {
+                let live = client.live.as_ref().map(|live| {
+                    live.create_agent_with_tools(
+                        model,
+                        system_prompt,
+                        config,
+                        file_explorer,
+                        file_reader,
+                        symbol_lookup,
+                    )
+                });
+                ProviderAgent::Replay(ReplayAgent::new(client.store.clone(), model, system_prompt, live))
+            }
         }
     }
 
@@ -363,6 +584,12 @@ impl ProviderClient {
     where
         T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
     {
+        // Replay serves the recorded extractions whatever the mode they were recorded with
+        let reliable_tool_calling = !matches!(self, ProviderClient::Ollama(_));
+        if config.extraction.uses_json_replies(reliable_tool_calling) && !matches!(self, ProviderClient::Replay(_)) {
+            return ProviderExtractor::Json(JsonExtractor::new(self.create_agent(model, system_prompt, config)));
+        }
+
         match self {
             ProviderClient::OpenAI(client) => {
                 let extractor = client
@@ -425,30 +652,75 @@ impl ProviderClient {
                 ProviderExtractor::Gemini(extractor)
             }
             ProviderClient::Ollama(client) => {
-                // Create standard agent for Ollama
-                let mut builder = client
-                    .agent(model)
+                let extractor = client
+                    .extractor::<T>(model)
                     .preamble(system_prompt)
-                    .max_tokens(config.max_tokens.into());
-                
-                if let Some(temp) = config.temperature {
-                    builder = builder.temperature(temp);
+                    .max_tokens(config.max_tokens.into())
+                    .build();
+                ProviderExtractor::Ollama(extractor)
+            }
+            ProviderClient::AzureOpenAI(azure) => {
+                let extractor = azure
+                    .client
+                    .extractor::<T>(azure.deployment(model))
+                    .preamble(system_prompt)
+                    .max_tokens(config.max_tokens.into())
+                    .build();
+                ProviderExtractor::AzureOpenAI(extractor)
+            }
+            ProviderClient::OpenAICompatible(compatible) => {
+                if compatible.responses_api {
+                    let mut builder = compatible
+                        .client
+                        .extractor::<T>(model)
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+                    ProviderExtractor::OpenAIResponses(builder.build())
+                } else {
+                    let mut builder = compatible
+                        .client
+                        .extractor_completions_api::<T>(model)
+                        .preamble(system_prompt)
+                        .max_tokens(config.max_tokens.into());
+                    if let Some(extra_body) = &compatible.extra_body {
+                        builder = builder.additional_params(extra_body.clone());
+                    }
+                    ProviderExtractor::OpenAI(builder.build())
                 }
-                
-                let agent = builder.build();
-
-                // Wrap with OllamaExtractorWrapper to handle structured output
-                let wrapper = OllamaExtractorWrapper::new(agent, config.retry_attempts);
-
-                ProviderExtractor::Ollama(wrapper)
+            }
+            ProviderClient::Replay(client) => {
+                let live = client
+                    .live
+                    .as_ref()
+                    .map(|live| live.create_extractor::<T>(model, system_prompt, config));
+                ProviderExtractor::Replay(ReplayExtractor::new(
+                    client.store.clone(),
+                    model,
+                    system_prompt,
+                    live,
+                ))
             }
         }
     }
 }
 
+/// Embed texts in requests of at most the model's document limit
+async fn embed_with<M: EmbeddingModel>(model: M, texts: Vec<String>) -> Result<Vec<Vec<f64>>> {
+    let mut vectors = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(M::MAX_DOCUMENTS.max(1)) {
+        let embeddings = model.embed_texts(batch.to_vec()).await?;
+        vectors.extend(embeddings.into_iter().map(|embedding| embedding.vec));
+    }
+    Ok(vectors)
+}
+
 /// Unified Agent enum
 pub enum ProviderAgent {
     OpenAI(Agent<rig::providers::openai::CompletionModel>),
+    OpenAIResponses(Agent<rig::providers::openai::responses_api::ResponsesCompletionModel>),
     Mistral(Agent<rig::providers::mistral::CompletionModel>),
     OpenRouter(Agent<rig::providers::openrouter::CompletionModel>),
     Anthropic(Agent<rig::providers::anthropic::completion::CompletionModel>),
@@ -456,44 +728,83 @@ pub enum ProviderAgent {
     Moonshot(Agent<rig::providers::moonshot::CompletionModel>),
     DeepSeek(Agent<rig::providers::deepseek::CompletionModel>),
     Ollama(Agent<rig::providers::ollama::CompletionModel<reqwest::Client>>),
+    AzureOpenAI(Agent<rig::providers::azure::CompletionModel>),
+    Replay(ReplayAgent),
 }
 
 impl ProviderAgent {
     /// Execute prompt
-    pub async fn prompt(&self, prompt: &str) -> Result<String> {
-        match self {
-            ProviderAgent::OpenAI(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::Moonshot(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::DeepSeek(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::Mistral(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::OpenRouter(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::Anthropic(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::Gemini(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-            ProviderAgent::Ollama(agent) => agent.prompt(prompt).await.map_err(|e| e.into()),
-        }
+    pub async fn prompt(&self, prompt: &str) -> Result<(String, TokenUsage)> {
+        self.multi_turn(prompt, 0).await.map_err(|e| e.into())
     }
 
-    /// Execute multi-turn dialogue
+    /// Execute multi-turn dialogue, the usage being summed over all turns
     pub async fn multi_turn(
         &self,
         prompt: &str,
         max_iterations: usize,
-    ) -> Result<String, PromptError> {
+    ) -> Result<(String, TokenUsage), PromptError> {
         match self {
-            ProviderAgent::OpenAI(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
-            ProviderAgent::Moonshot(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
-            ProviderAgent::DeepSeek(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
-            ProviderAgent::Mistral(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
+            ProviderAgent::OpenAI(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
+            ProviderAgent::OpenAIResponses(agent) => {
+                prompt_with_usage(agent, prompt, max_iterations).await
+            }
+            ProviderAgent::Moonshot(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
+            ProviderAgent::DeepSeek(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
+            ProviderAgent::Mistral(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
             ProviderAgent::OpenRouter(agent) => {
-                agent.prompt(prompt).multi_turn(max_iterations).await
+                prompt_with_usage(agent, prompt, max_iterations).await
             }
             ProviderAgent::Anthropic(agent) => {
-                agent.prompt(prompt).multi_turn(max_iterations).await
+                prompt_with_usage(agent, prompt, max_iterations).await
+            }
+            ProviderAgent::Gemini(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
+            ProviderAgent::Ollama(agent) => prompt_with_usage(agent, prompt, max_iterations).await,
+            ProviderAgent::AzureOpenAI(agent) => {
+                prompt_with_usage(agent, prompt, max_iterations).await
+            }
+            ProviderAgent::Replay(agent) => agent.multi_turn(prompt, max_iterations).await,
+        }
+    }
+}
+
+/// Prompt a rig agent, returning the reply with the token usage reported by the provider
+async fn prompt_with_usage<M>(
+    agent: &Agent<M>,
+    prompt: &str,
+    max_iterations: usize,
+) -> Result<(String, TokenUsage), PromptError>
+where
+    M: CompletionModel,
+{
+    if max_iterations == 0 {
+        // A single completion keeps the raw reply and with it the cached input tokens
+        let response = agent.completion(prompt, vec![]).await?.send().await?;
+        let usage = TokenUsage::from(response.usage).with_cached_from(&response.raw_response);
+        let mut texts = Vec::new();
+        for content in response.choice {
+            match content {
+                AssistantContent::Text(text) => texts.push(text.text),
+                // No turn left to run the tool, as rig's multi-turn loop would report
+                AssistantContent::ToolCall(_) => {
+                    return Err(PromptError::MaxDepthError {
+                        max_depth: 0,
+                        chat_history: Box::default(),
+                        prompt: Message::user(prompt),
+                    });
+                }
+                _ => {}
             }
-            ProviderAgent::Gemini(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
-            ProviderAgent::Ollama(agent) => agent.prompt(prompt).multi_turn(max_iterations).await,
         }
+        return Ok((texts.join("\n"), usage));
     }
+
+    let response = agent
+        .prompt(prompt)
+        .multi_turn(max_iterations)
+        .extended_details()
+        .await?;
+    Ok((response.output, response.total_usage.into()))
 }
 
 /// Unified Extractor enum
@@ -502,13 +813,18 @@ where
     T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
 {
     OpenAI(Extractor<rig::providers::openai::CompletionModel, T>),
+    OpenAIResponses(Extractor<rig::providers::openai::responses_api::ResponsesCompletionModel, T>),
     Mistral(Extractor<rig::providers::mistral::CompletionModel, T>),
     OpenRouter(Extractor<rig::providers::openrouter::CompletionModel, T>),
     Anthropic(Extractor<rig::providers::anthropic::completion::CompletionModel, T>),
     Gemini(Extractor<rig::providers::gemini::completion::CompletionModel, T>),
     Moonshot(Extractor<rig::providers::moonshot::CompletionModel, T>),
     DeepSeek(Extractor<rig::providers::deepseek::CompletionModel, T>),
-    Ollama(OllamaExtractorWrapper<T>),
+    Ollama(Extractor<rig::providers::ollama::CompletionModel<reqwest::Client>, T>),
+    AzureOpenAI(Extractor<rig::providers::azure::CompletionModel, T>),
+    Replay(ReplayExtractor<T>),
+    /// Schema in the prompt and JSON replies, for any provider
+    Json(JsonExtractor<T>),
 }
 
 impl<T> ProviderExtractor<T>
@@ -516,32 +832,63 @@ where
     T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
 {
     /// Execute extraction
-    pub async fn extract(&self, prompt: &str) -> Result<T> {
+    pub async fn extract(&self, prompt: &str) -> Result<(T, TokenUsage)> {
         match self {
             ProviderExtractor::OpenAI(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
+            }
+            ProviderExtractor::OpenAIResponses(extractor) => {
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::Moonshot(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::DeepSeek(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::Mistral(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::OpenRouter(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::Anthropic(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::Gemini(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
             }
             ProviderExtractor::Ollama(extractor) => {
-                extractor.extract(prompt).await.map_err(|e| e.into())
+                extract_with_usage(extractor, prompt).await
+            }
+            ProviderExtractor::AzureOpenAI(extractor) => {
+                extract_with_usage(extractor, prompt).await
             }
+            ProviderExtractor::Replay(extractor) => extractor.extract(prompt).await,
+            ProviderExtractor::Json(extractor) => extractor.extract(prompt).await,
         }
     }
 }
+
+/// Run the extractor's `submit` tool call once, returning the data with the token usage reported by the provider
+async fn extract_with_usage<M, T>(extractor: &Extractor<M, T>, prompt: &str) -> Result<(T, TokenUsage)>
+where
+    M: CompletionModel,
+    T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+{
+    let agent = extractor.get_inner().await;
+    let response = agent.completion(prompt, vec![]).await?.send().await?;
+    let usage = TokenUsage::from(response.usage).with_cached_from(&response.raw_response);
+
+    let arguments = response
+        .choice
+        .into_iter()
+        .find_map(|content| match content {
+            AssistantContent::ToolCall(call) if call.function.name == SUBMIT_TOOL_NAME => {
+                Some(call.function.arguments)
+            }
+            _ => None,
+        })
+        .ok_or_else(|| anyhow!("No data extracted, the model did not call the `{}` tool", SUBMIT_TOOL_NAME))?;
+    Ok((json_extraction::conform(arguments)?, usage))
+}
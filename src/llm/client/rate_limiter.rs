@@ -0,0 +1,226 @@
+//! Rate limiter - Requests-per-minute, tokens-per-minute and concurrency budgets shared by every call of an LLM client
+//!
+//! Budgets are enforced over a sliding one-minute window. Token reservations use the estimated prompt size and are
+//! settled with the usage reported by the provider once the call returns. When the provider answers with a rate
+//! limit error, every caller pauses for the delay it asks for: rig does not expose response headers, so the delay
+//! is read from the `Retry-After` value or the "try again in" hint echoed in the error message.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use regex::Regex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+use super::errors::LLMErrorKind;
+use crate::config::RateLimitConfig;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// `Retry-After: 20`, `retry after 1.5 seconds`, `Please try again in 850ms`
+static RETRY_HINT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:retry[-_ ]after|try again in)[^0-9]{0,3}(\d+(?:\.\d+)?)\s*(ms|milliseconds?|s|secs?|seconds?|m|mins?|minutes?)?\b")
+        .unwrap()
+});
+
+struct WindowEntry {
+    id: u64,
+    at: Instant,
+    tokens: usize,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    window: VecDeque<WindowEntry>,
+    next_id: u64,
+    paused_until: Option<Instant>,
+}
+
+/// Shared limiter, cloned with the LLM client
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    /// Pause after a rate limit error without a delay hint
+    default_pause: Duration,
+    state: Arc<Mutex<LimiterState>>,
+    concurrency: Option<Arc<Semaphore>>,
+}
+
+/// Reservation of one request, releasing its concurrency slot when dropped
+pub struct RateLimitPermit {
+    limiter: RateLimiter,
+    id: u64,
+    _concurrency: Option<OwnedSemaphorePermit>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig, default_pause: Duration) -> Self {
+        Self {
+            config: config.clone(),
+            default_pause,
+            state: Arc::new(Mutex::new(LimiterState::default())),
+            concurrency: config
+                .max_concurrent_requests
+                .map(|limit| Arc::new(Semaphore::new(limit.max(1)))),
+        }
+    }
+
+    /// Wait until a request estimated at `estimated_tokens` fits all budgets, then reserve it
+    pub async fn acquire(&self, estimated_tokens: usize) -> RateLimitPermit {
+        let concurrency = match &self.concurrency {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("rate limiter semaphore is never closed"),
+            ),
+            None => None,
+        };
+
+        loop {
+            match self.try_reserve(estimated_tokens, Instant::now()) {
+                Ok(id) => {
+                    return RateLimitPermit {
+                        limiter: self.clone(),
+                        id,
+                        _concurrency: concurrency,
+                    };
+                }
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Reserve the request, or return how long to wait before trying again
+    fn try_reserve(&self, estimated_tokens: usize, now: Instant) -> Result<u64, Duration> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(paused_until) = state.paused_until {
+            if paused_until > now {
+                return Err(paused_until - now);
+            }
+            state.paused_until = None;
+        }
+        while state
+            .window
+            .front()
+            .is_some_and(|entry| now.duration_since(entry.at) >= WINDOW)
+        {
+            state.window.pop_front();
+        }
+
+        // Waiting for the oldest request to leave the window frees budget
+        let oldest_expiry = state
+            .window
+            .front()
+            .map(|entry| (entry.at + WINDOW).saturating_duration_since(now));
+        if let Some(rpm) = self.config.requests_per_minute
+            && state.window.len() >= rpm.max(1) as usize
+            && let Some(wait) = oldest_expiry
+        {
+            return Err(wait);
+        }
+        if let Some(tpm) = self.config.tokens_per_minute {
+            let used: usize = state.window.iter().map(|entry| entry.tokens).sum();
+            // A request larger than the whole budget runs alone rather than never
+            if used + estimated_tokens > tpm as usize
+                && let Some(wait) = oldest_expiry
+            {
+                return Err(wait);
+            }
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.window.push_back(WindowEntry {
+            id,
+            at: now,
+            tokens: estimated_tokens,
+        });
+        Ok(id)
+    }
+
+    /// Pause every caller when the error is a rate limit error, for the delay the provider asked for
+    pub fn observe_error(&self, error: &str) {
+        if LLMErrorKind::classify_message(error) != LLMErrorKind::RateLimit {
+            return;
+        }
+        let pause = retry_after(error).unwrap_or(self.default_pause);
+        let until = Instant::now() + pause;
+
+        let mut state = self.state.lock().unwrap();
+        if state.paused_until.is_none_or(|paused_until| paused_until < until) {
+            state.paused_until = Some(until);
+        }
+        eprintln!(
+            "⏳ Rate limited by the model service, pausing requests for {:.1}s",
+            pause.as_secs_f64()
+        );
+    }
+}
+
+impl RateLimitPermit {
+    /// Replace the estimated tokens of the request with the tokens the provider reported
+    pub fn settle(self, used_tokens: usize) {
+        let mut state = self.limiter.state.lock().unwrap();
+        if let Some(entry) = state.window.iter_mut().find(|entry| entry.id == self.id) {
+            entry.tokens = used_tokens;
+        }
+    }
+}
+
+/// Delay requested in a rate limit error message
+fn retry_after(error: &str) -> Option<Duration> {
+    let captures = RETRY_HINT_REGEX.captures(error)?;
+    let value: f64 = captures[1].parse().ok()?;
+    let unit = captures.get(2).map(|m| m.as_str().to_lowercase()).unwrap_or_default();
+    let seconds = if unit.starts_with("ms") || unit.starts_with("milli") {
+        value / 1000.0
+    } else if unit.starts_with('m') {
+        value * 60.0
+    } else {
+        value
+    };
+    Some(Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_after_hints() {
+        assert_eq!(retry_after("429 Too Many Requests, Retry-After: 20"), Some(Duration::from_secs(20)));
+        assert_eq!(
+            retry_after("Rate limit reached for gpt-4o. Please try again in 850ms."),
+            Some(Duration::from_millis(850))
+        );
+        assert_eq!(retry_after("Please retry after 2 minutes"), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after("Invalid API key"), None);
+    }
+
+    #[tokio::test]
+    async fn test_budgets_and_pause() {
+        let config = RateLimitConfig {
+            requests_per_minute: Some(2),
+            tokens_per_minute: Some(1000),
+            max_concurrent_requests: Some(1),
+        };
+        let limiter = RateLimiter::new(&config, Duration::from_secs(5));
+
+        limiter.acquire(100).await.settle(900);
+        let start = Instant::now();
+        // The token budget is exhausted by the settled usage, the request budget by the next request
+        assert!(limiter.try_reserve(200, start).is_err());
+        assert!(limiter.try_reserve(50, start).is_ok());
+        assert!(limiter.try_reserve(0, start).is_err());
+        assert!(limiter.try_reserve(50, start + WINDOW).is_ok());
+
+        limiter.observe_error("Invalid request");
+        assert!(limiter.try_reserve(0, start + WINDOW * 2).is_ok());
+        limiter.observe_error("HTTP 429: rate limit exceeded, retry after 3 seconds");
+        let wait = limiter.try_reserve(0, Instant::now()).unwrap_err();
+        assert!(wait > Duration::from_secs(2) && wait <= Duration::from_secs(3));
+    }
+}
@@ -2,6 +2,8 @@
 
 use rig::completion::Message;
 
+use super::types::TokenUsage;
+
 /// ReAct mode configuration
 #[derive(Debug, Clone)]
 pub struct ReActConfig {
@@ -39,6 +41,8 @@ pub struct ReActResponse {
     pub tool_calls_history: Vec<String>,
     /// Chat history (only included when max depth reached)
     pub chat_history: Option<Vec<Message>>,
+    /// Token usage reported by the provider (unknown when interrupted at max depth)
+    pub usage: TokenUsage,
 }
 
 impl ReActResponse {
@@ -56,12 +60,16 @@ impl ReActResponse {
             stopped_by_max_depth,
             tool_calls_history,
             chat_history,
+            usage: TokenUsage::default(),
         }
     }
 
     /// Create successfully completed response
-    pub fn success(content: String, iterations_used: usize) -> Self {
-        Self::new(content, iterations_used, false, Vec::new(), None)
+    pub fn success(content: String, iterations_used: usize, usage: TokenUsage) -> Self {
+        Self {
+            usage,
+            ..Self::new(content, iterations_used, false, Vec::new(), None)
+        }
     }
 
     /// Create response stopped by max depth (with chat history)
@@ -86,13 +94,17 @@ impl ReActResponse {
         max_depth: usize,
         tool_calls_history: Vec<String>,
         chat_history: Vec<Message>,
+        usage: TokenUsage,
     ) -> Self {
-        Self::new(
-            content,
-            max_depth,
-            true,
-            tool_calls_history,
-            Some(chat_history),
-        )
+        Self {
+            usage,
+            ..Self::new(
+                content,
+                max_depth,
+                true,
+                tool_calls_history,
+                Some(chat_history),
+            )
+        }
     }
 }
@@ -28,12 +28,12 @@ impl ReActExecutor {
         let mut tool_calls_history = Vec::new();
 
         match agent.multi_turn(user_prompt, config.max_iterations).await {
-            Ok(response) => {
+            Ok((response, usage)) => {
                 if config.verbose {
                     println!("   ✅ ReAct Agent task completed");
                 }
 
-                Ok(ReActResponse::success(response, config.max_iterations))
+                Ok(ReActResponse::success(response, config.max_iterations, usage))
             }
             Err(PromptError::MaxDepthError {
                 max_depth,
@@ -0,0 +1,347 @@
+//! Replay provider - Serves recorded LLM responses from a fixtures directory for offline, deterministic runs
+//!
+//! Fixtures are keyed by the MD5 of `system|user|reply-prompt` for text prompts and of `system|user` for structured
+//! extraction, `system` being the system prompt the agent is built with: agents given the file tools get the tool
+//! instructions appended to it. The keys therefore differ from the cache ones, which are built from the prompt
+//! before the agent is (`reply-prompt+tool` for tool agents), and a fixture is only found again by the same agent.
+//! With `record_with` set, prompts without a fixture are answered by that live provider and its responses are
+//! written to the fixtures directory. Recorded responses report no token usage, nothing being spent to serve them.
+
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, anyhow};
+use rig::completion::{CompletionError, PromptError};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::providers::{ProviderAgent, ProviderExtractor};
+use super::types::TokenUsage;
+use crate::cache::prompt_hash;
+use crate::config::ReplayConfig;
+
+/// Reply to text prompts without a fixture when synthesizing
+const SYNTHESIZED_REPLY: &str = "Synthesized reply of the replay provider: no response was recorded for this prompt.";
+/// Nesting depth down to which synthesized arrays receive one element
+const SYNTHESIZED_ARRAY_DEPTH: usize = 3;
+
+/// Recorded response file
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    /// `prompt` or `extract`
+    kind: String,
+    /// Model that produced the response
+    model: String,
+    response: Value,
+}
+
+/// Fixtures directory access
+#[derive(Clone)]
+pub struct FixtureStore {
+    dir: PathBuf,
+    synthesize_missing: bool,
+}
+
+impl FixtureStore {
+    pub fn new(config: &ReplayConfig) -> Self {
+        Self {
+            dir: config.fixtures_dir.clone(),
+            synthesize_missing: config.synthesize_missing,
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", prompt_hash(key)))
+    }
+
+    fn load(&self, key: &str) -> Result<Option<Value>> {
+        let path = self.path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .context(format!("Failed to read LLM fixture: {:?}", path))?;
+        let fixture: Fixture = serde_json::from_str(&content)
+            .context(format!("Failed to parse LLM fixture: {:?}", path))?;
+        Ok(Some(fixture.response))
+    }
+
+    fn save(&self, key: &str, kind: &str, model: &str, response: Value) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(key);
+        let fixture = Fixture {
+            kind: kind.to_string(),
+            model: model.to_string(),
+            response,
+        };
+        std::fs::write(&path, serde_json::to_string_pretty(&fixture)?)
+            .context(format!("Failed to write LLM fixture: {:?}", path))
+    }
+
+    fn missing(&self, key: &str) -> anyhow::Error {
+        anyhow!(
+            "No recorded LLM response {:?}, record it with --record-llm-fixtures <provider>",
+            self.path(key)
+        )
+    }
+}
+
+/// Replay client, wrapping the live client when recording
+#[derive(Clone)]
+pub struct ReplayClient {
+    pub store: FixtureStore,
+    pub live: Option<Box<super::providers::ProviderClient>>,
+}
+
+/// Agent answering text prompts from fixtures
+pub struct ReplayAgent {
+    store: FixtureStore,
+    model: String,
+    system_prompt: String,
+    live: Option<Box<ProviderAgent>>,
+}
+
+impl ReplayAgent {
+    pub fn new(store: FixtureStore, model: &str, system_prompt: &str, live: Option<ProviderAgent>) -> Self {
+        Self {
+            store,
+            model: model.to_string(),
+            system_prompt: system_prompt.to_string(),
+            live: live.map(Box::new),
+        }
+    }
+
+    fn key(&self, prompt: &str) -> String {
+        format!("{}|{}|reply-prompt", self.system_prompt, prompt)
+    }
+
+    /// Recorded or synthesized reply, `None` when the live provider has to answer
+    fn recorded(&self, key: &str) -> Result<Option<String>> {
+        if let Some(response) = self.store.load(key)? {
+            return Ok(Some(match response {
+                Value::String(reply) => reply,
+                other => other.to_string(),
+            }));
+        }
+        match &self.live {
+            Some(_) => Ok(None),
+            None if self.store.synthesize_missing => Ok(Some(SYNTHESIZED_REPLY.to_string())),
+            None => Err(self.store.missing(key)),
+        }
+    }
+
+    fn record(&self, key: &str, reply: &str) -> Result<()> {
+        self.store
+            .save(key, "prompt", &self.model, Value::String(reply.to_string()))
+    }
+
+    pub async fn multi_turn(
+        &self,
+        prompt: &str,
+        max_iterations: usize,
+    ) -> Result<(String, TokenUsage), PromptError> {
+        let provider_error = |e: anyhow::Error| {
+            PromptError::CompletionError(CompletionError::ProviderError(e.to_string()))
+        };
+        let key = self.key(prompt);
+        if let Some(reply) = self.recorded(&key).map_err(provider_error)? {
+            return Ok((reply, TokenUsage::default()));
+        }
+        let Some(live) = &self.live else {
+            return Err(provider_error(self.store.missing(&key)));
+        };
+        let (reply, usage) = Box::pin(live.multi_turn(prompt, max_iterations)).await?;
+        self.record(&key, &reply).map_err(provider_error)?;
+        Ok((reply, usage))
+    }
+}
+
+/// Extractor answering structured prompts from fixtures
+pub struct ReplayExtractor<T>
+where
+    T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+{
+    store: FixtureStore,
+    model: String,
+    system_prompt: String,
+    live: Option<Box<ProviderExtractor<T>>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> ReplayExtractor<T>
+where
+    T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+{
+    pub fn new(store: FixtureStore, model: &str, system_prompt: &str, live: Option<ProviderExtractor<T>>) -> Self {
+        Self {
+            store,
+            model: model.to_string(),
+            system_prompt: system_prompt.to_string(),
+            live: live.map(Box::new),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub async fn extract(&self, prompt: &str) -> Result<(T, TokenUsage)> {
+        let key = format!("{}|{}", self.system_prompt, prompt);
+        if let Some(response) = self.store.load(&key)? {
+            let result = serde_json::from_value(response)
+                .context(format!("LLM fixture {:?} does not match the expected structure", self.store.path(&key)))?;
+            return Ok((result, TokenUsage::default()));
+        }
+        if let Some(live) = &self.live {
+            let (result, usage) = Box::pin(live.extract(prompt)).await?;
+            self.store
+                .save(&key, "extract", &self.model, serde_json::to_value(&result)?)?;
+            return Ok((result, usage));
+        }
+        if self.store.synthesize_missing {
+            let result = serde_json::from_value(synthesize::<T>())
+                .context("Failed to synthesize a response from the output schema")?;
+            return Ok((result, TokenUsage::default()));
+        }
+        Err(self.store.missing(&key))
+    }
+}
+
+/// Placeholder value conforming to the JSON schema of `T`
+pub fn synthesize<T: JsonSchema>() -> Value {
+    synthesize_schema(schemars::schema_for!(T).as_value())
+}
+
+/// Placeholder value conforming to a JSON schema, its `$defs` being resolved against itself
+pub fn synthesize_schema(schema: &Value) -> Value {
+    synthesize_value(schema, schema, 0)
+}
+
+fn synthesize_value(schema: &Value, root: &Value, depth: usize) -> Value {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        // `#` refers to the root schema of recursive types, `#/$defs/Name` to a definition
+        let resolved = reference.strip_prefix('#').and_then(|pointer| root.pointer(pointer));
+        return match resolved {
+            Some(resolved) => synthesize_value(resolved, root, depth),
+            None => Value::Null,
+        };
+    }
+    if let Some(constant) = schema.get("const") {
+        return constant.clone();
+    }
+    if let Some(first) = schema.get("enum").and_then(Value::as_array).and_then(|values| values.first()) {
+        return first.clone();
+    }
+    for combinator in ["oneOf", "anyOf", "allOf"] {
+        if let Some(alternatives) = schema.get(combinator).and_then(Value::as_array) {
+            let alternative = alternatives
+                .iter()
+                .find(|alternative| alternative.get("type").and_then(Value::as_str) != Some("null"))
+                .or(alternatives.first());
+            if let Some(alternative) = alternative {
+                return synthesize_value(alternative, root, depth);
+            }
+        }
+    }
+
+    let schema_type = match schema.get("type") {
+        Some(Value::String(schema_type)) => schema_type.as_str(),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        _ if schema.get("properties").is_some() => "object",
+        _ => "null",
+    };
+    let minimum = schema.get("minimum").and_then(Value::as_f64).unwrap_or(0.0).max(0.0);
+
+    match schema_type {
+        "object" => {
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|names| names.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            let mut object = Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    if required.contains(&name.as_str()) {
+                        object.insert(name.clone(), synthesize_value(property, root, depth + 1));
+                    }
+                }
+            }
+            Value::Object(object)
+        }
+        "array" => {
+            let min_items = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0) as usize;
+            let count = if depth < SYNTHESIZED_ARRAY_DEPTH { min_items.max(1) } else { min_items };
+            if count == 0 {
+                return Value::Array(Vec::new());
+            }
+            let item = schema
+                .get("items")
+                .map(|items| synthesize_value(items, root, depth + 1))
+                .unwrap_or(Value::Null);
+            Value::Array(vec![item; count])
+        }
+        "string" => Value::String("placeholder".to_string()),
+        "integer" => Value::from(minimum.ceil() as i64),
+        "number" => Value::from(minimum),
+        "boolean" => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempProject;
+
+    #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+    enum Kind {
+        Service,
+        Library,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, JsonSchema)]
+    struct Module {
+        name: String,
+        kind: Kind,
+        importance: f64,
+        children: Vec<Module>,
+        note: Option<String>,
+    }
+
+    #[tokio::test]
+    async fn test_replay_and_synthesize() {
+        let dir = TempProject::new("replay");
+        let config = ReplayConfig {
+            fixtures_dir: dir.path().to_path_buf(),
+            record_with: None,
+            synthesize_missing: false,
+        };
+        let store = FixtureStore::new(&config);
+
+        let agent = ReplayAgent::new(store.clone(), "model", "system", None);
+        assert!(agent.multi_turn("hello", 0).await.is_err());
+        store
+            .save("system|hello|reply-prompt", "prompt", "model", Value::String("recorded".to_string()))
+            .unwrap();
+        let (reply, usage) = agent.multi_turn("hello", 3).await.unwrap();
+        assert_eq!(reply, "recorded");
+        assert_eq!(usage.total_tokens, 0);
+
+        let extractor = ReplayExtractor::<Module>::new(
+            FixtureStore {
+                synthesize_missing: true,
+                ..store
+            },
+            "model",
+            "system",
+            None,
+        );
+        let (module, _) = extractor.extract("describe").await.unwrap();
+        assert_eq!(module.kind, Kind::Service);
+        assert_eq!(module.children.len(), 1);
+        assert!(module.note.is_none());
+    }
+}
@@ -4,6 +4,7 @@ use anyhow::Result;
 use rig::completion::Message;
 
 use super::providers::ProviderAgent;
+use super::types::TokenUsage;
 
 /// Summary reasoner
 pub struct SummaryReasoner;
@@ -16,7 +17,7 @@ impl SummaryReasoner {
         original_user_prompt: &str,
         chat_history: &[Message],
         tool_calls_history: &[String],
-    ) -> Result<String> {
+    ) -> Result<(String, TokenUsage)> {
         // Build summary reasoning prompt
         let summary_prompt = Self::build_summary_prompt(
             original_system_prompt,
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use crate::llm::pricing::PricingTable;
 
 /// Token usage information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TokenUsage {
     /// Number of input tokens
     pub input_tokens: usize,
@@ -9,6 +13,11 @@ pub struct TokenUsage {
     pub output_tokens: usize,
     /// Total number of tokens
     pub total_tokens: usize,
+    /// Input tokens served from the provider's prompt cache (part of `input_tokens`), read from the raw reply of
+    /// single completions (Anthropic, DeepSeek, Gemini, OpenAI responses API). rig drops the count of the OpenAI chat
+    /// completions API and only sums the plain counts over multi-turn dialogues, which then report no cached tokens
+    #[serde(default)]
+    pub cached_input_tokens: usize,
 }
 
 impl TokenUsage {
@@ -17,14 +26,211 @@ impl TokenUsage {
             input_tokens,
             output_tokens,
             total_tokens: input_tokens + output_tokens,
+            cached_input_tokens: 0,
+        }
+    }
+
+    /// Take the cached input tokens from the raw reply of a provider, the usage of rig only carrying the plain counts
+    pub fn with_cached_from<R: Serialize>(mut self, raw_response: &R) -> Self {
+        if let Ok(raw) = serde_json::to_value(raw_response) {
+            self.cached_input_tokens = cached_tokens_in(&raw).min(self.input_tokens);
         }
+        self
+    }
+
+    /// Accumulate the usage of another call
+    pub fn add(&mut self, other: &TokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.total_tokens += other.total_tokens;
+        self.cached_input_tokens += other.cached_input_tokens;
+    }
+}
+
+impl From<rig::completion::Usage> for TokenUsage {
+    fn from(usage: rig::completion::Usage) -> Self {
+        let mut token_usage = Self::new(usage.input_tokens as usize, usage.output_tokens as usize);
+        // Some providers only report the two counts
+        token_usage.total_tokens = token_usage.total_tokens.max(usage.total_tokens as usize);
+        token_usage
+    }
+}
+
+/// Fields in which the providers report the prompt cache hits
+const CACHED_TOKEN_FIELDS: [&str; 5] = [
+    "cached_tokens",
+    "cache_read_input_tokens",
+    "prompt_cache_hit_tokens",
+    "cached_content_token_count",
+    "cachedContentTokenCount",
+];
+
+/// Largest cached token count found in a raw reply, DeepSeek reporting the same hits under two fields
+fn cached_tokens_in(raw: &serde_json::Value) -> usize {
+    match raw {
+        serde_json::Value::Object(fields) => fields
+            .iter()
+            .map(|(name, value)| match value.as_u64() {
+                Some(count) if CACHED_TOKEN_FIELDS.contains(&name.as_str()) => count as usize,
+                _ => cached_tokens_in(value),
+            })
+            .max()
+            .unwrap_or_default(),
+        serde_json::Value::Array(values) => values.iter().map(cached_tokens_in).max().unwrap_or_default(),
+        _ => 0,
     }
+}
+
+/// Result of an LLM call with the token usage reported by the provider
+#[derive(Debug, Clone)]
+pub struct LLMResponse<T> {
+    pub output: T,
+    pub usage: TokenUsage,
+    /// Model that answered
+    pub model: String,
+    /// Provider that answered, a fallback one when the configured provider failed
+    pub provider: String,
+}
+
+/// Calls, tokens and cost of one stage, agent or model
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub calls: usize,
+    pub usage: TokenUsage,
+    /// Cost in USD of the calls to priced models
+    pub cost: f64,
+    /// Calls served by each provider
+    #[serde(default)]
+    pub providers: BTreeMap<String, usize>,
+}
+
+impl UsageRecord {
+    fn add(&mut self, provider: &str, usage: &TokenUsage, cost: f64) {
+        self.calls += 1;
+        self.usage.add(usage);
+        self.cost += cost;
+        *self.providers.entry(provider.to_string()).or_default() += 1;
+    }
+}
+
+/// Token usage and cost of the run, aggregated per stage, per agent and per model
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageReport {
+    pub total: UsageRecord,
+    pub by_stage: BTreeMap<String, UsageRecord>,
+    pub by_agent: BTreeMap<String, UsageRecord>,
+    pub by_model: BTreeMap<String, UsageRecord>,
+    #[serde(default)]
+    pub by_provider: BTreeMap<String, UsageRecord>,
+    /// Models missing from the pricing table, their calls being left out of the costs
+    pub unpriced_models: BTreeSet<String>,
+}
+
+#[derive(Default)]
+struct UsageState {
+    stage: String,
+    report: UsageReport,
+}
+
+/// Collects the token usage of the LLM calls, shared by the clones of the LLM client
+#[derive(Clone)]
+pub struct UsageTracker {
+    pricing: Arc<PricingTable>,
+    state: Arc<Mutex<UsageState>>,
+}
+
+impl UsageTracker {
+    pub fn new(pricing: PricingTable) -> Self {
+        Self {
+            pricing: Arc::new(pricing),
+            state: Arc::new(Mutex::new(UsageState::default())),
+        }
+    }
+
+    pub fn pricing(&self) -> &PricingTable {
+        &self.pricing
+    }
+
+    /// Attribute the following calls to the workflow stage
+    pub fn set_stage(&self, stage: &str) {
+        self.state.lock().unwrap().stage = stage.to_string();
+    }
+
+    pub fn record(&self, agent: &str, provider: &str, model: &str, usage: &TokenUsage) {
+        let cost = self.pricing.cost(provider, model, usage);
+        let mut state = self.state.lock().unwrap();
+        let stage = state.stage.clone();
+        let report = &mut state.report;
+        if cost.is_none() {
+            report.unpriced_models.insert(model.to_string());
+        }
+        let cost = cost.unwrap_or_default();
+
+        report.total.add(provider, usage, cost);
+        report.by_stage.entry(stage).or_default().add(provider, usage, cost);
+        report.by_agent.entry(agent.to_string()).or_default().add(provider, usage, cost);
+        report.by_model.entry(model.to_string()).or_default().add(provider, usage, cost);
+        report.by_provider.entry(provider.to_string()).or_default().add(provider, usage, cost);
+    }
+
+    pub fn report(&self) -> UsageReport {
+        self.state.lock().unwrap().report.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_tracker_aggregates_per_stage_agent_and_model() {
+        let tracker = UsageTracker::new(PricingTable::new(&crate::config::LLMConfig::default()));
+        tracker.set_stage("preprocess");
+        tracker.record("ai_code_insight", "openai", "gpt-4o-mini", &TokenUsage::new(100, 20));
+        tracker.clone().record("ai_code_insight", "openai", "gpt-4o", &TokenUsage::new(50, 10));
+        tracker.set_stage("research");
+        tracker.record("studies_research/SystemContextResearcher", "openai", "gpt-4o", &TokenUsage::new(7, 3));
+        tracker.record("studies_research/WorkflowResearcher", "openai-compatible", "in-house", &TokenUsage::new(1, 1));
+
+        let report = tracker.report();
+        assert_eq!(report.total.calls, 4);
+        assert_eq!(report.total.usage.total_tokens, 192);
+        assert_eq!(report.by_stage["preprocess"].calls, 2);
+        assert_eq!(report.by_agent["ai_code_insight"].usage.input_tokens, 150);
+        assert_eq!(report.by_model["gpt-4o"].calls, 2);
+        assert_eq!(report.by_model["gpt-4o"].usage.output_tokens, 13);
+        assert!((report.by_model["gpt-4o"].cost - (57.0 * 2.5 + 13.0 * 10.0) / 1_000_000.0).abs() < 1e-12);
+        assert_eq!(report.by_model["in-house"].cost, 0.0);
+        assert!(report.unpriced_models.contains("in-house"));
+        assert_eq!(report.by_provider["openai"].calls, 3);
+        assert_eq!(report.by_agent["studies_research/WorkflowResearcher"].providers["openai-compatible"], 1);
+
+        let usage = TokenUsage::from(rig::completion::Usage {
+            input_tokens: 12,
+            output_tokens: 8,
+            total_tokens: 0,
+        });
+        assert_eq!(usage.total_tokens, 20);
+    }
+
+    #[test]
+    fn test_cached_tokens_read_from_raw_reply() {
+        let anthropic = serde_json::json!({
+            "id": "msg_1",
+            "usage": {"input_tokens": 200, "cache_read_input_tokens": 1800, "cache_creation_input_tokens": 0, "output_tokens": 50}
+        });
+        assert_eq!(TokenUsage::new(2000, 50).with_cached_from(&anthropic).cached_input_tokens, 1800);
+
+        let deepseek = serde_json::json!({
+            "choices": [],
+            "usage": {"prompt_tokens": 900, "prompt_cache_hit_tokens": 640, "prompt_tokens_details": {"cached_tokens": 640}}
+        });
+        assert_eq!(TokenUsage::new(900, 10).with_cached_from(&deepseek).cached_input_tokens, 640);
 
-    /// Estimate cost (based on different model pricing)
-    pub fn estimate_cost(&self, _model_name: &str) -> f64 {
-        let (input_cost_per_1k, output_cost_per_1k) = (0.00025, 0.002);
+        let gemini = serde_json::json!({"usageMetadata": {"promptTokenCount": 300, "cachedContentTokenCount": 120}});
+        assert_eq!(TokenUsage::new(300, 10).with_cached_from(&gemini).cached_input_tokens, 120);
 
-        (self.input_tokens as f64 / 1000.0) * input_cost_per_1k
-            + (self.output_tokens as f64 / 1000.0) * output_cost_per_1k
+        let uncached = serde_json::json!({"usage": {"prompt_tokens": 10, "total_tokens": 12}});
+        assert_eq!(TokenUsage::new(10, 2).with_cached_from(&uncached).cached_input_tokens, 0);
     }
 }
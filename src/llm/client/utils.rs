@@ -1,16 +1,15 @@
-use crate::{
-    config::LLMConfig, llm::client::types::TokenUsage, utils::token_estimator::TokenEstimator,
-};
-
-use std::sync::LazyLock;
-
-static TOKEN_ESTIMATOR: LazyLock<TokenEstimator> = LazyLock::new(|| TokenEstimator::new());
+use crate::config::LLMConfig;
 
+/// Model of the call and its fallback: the efficient model for small prompts, falling back to the powerful one
 pub fn evaluate_befitting_model(
     llm_config: &LLMConfig,
     system_prompt: &str,
     user_prompt: &str,
 ) -> (String, Option<String>) {
+    // Routed agents have a single model, with nothing to fall back to
+    if llm_config.model_efficient == llm_config.model_powerful {
+        return (llm_config.model_powerful.clone(), None);
+    }
     if system_prompt.len() + user_prompt.len() <= 32 * 1024 {
         return (
             llm_config.model_efficient.clone(),
@@ -20,13 +19,37 @@ pub fn evaluate_befitting_model(
     return (llm_config.model_powerful.clone(), None);
 }
 
-/// Estimate token usage (based on text length)
-pub fn estimate_token_usage(input_text: &str, output_text: &str) -> TokenUsage {
-    // Rough estimate: 1 token ≈ 4 characters (English) or ~1.5 characters (Chinese)
-    let input_estimate = TOKEN_ESTIMATOR.estimate_tokens(input_text);
-    let output_estimate = TOKEN_ESTIMATOR.estimate_tokens(output_text);
-    TokenUsage::new(
-        input_estimate.estimated_tokens,
-        output_estimate.estimated_tokens,
-    )
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ModelRoute;
+
+    #[test]
+    fn test_routed_agents_use_their_model() {
+        let mut config = LLMConfig {
+            model_efficient: "small".to_string(),
+            model_powerful: "large".to_string(),
+            ..Default::default()
+        };
+        config.routes.insert(
+            "code_analysis".to_string(),
+            ModelRoute {
+                model: Some("coder".to_string()),
+                temperature: Some(0.0),
+                max_tokens: None,
+            },
+        );
+
+        assert_eq!(
+            evaluate_befitting_model(&config, "sys", "user"),
+            ("small".to_string(), Some("large".to_string()))
+        );
+        assert_eq!(evaluate_befitting_model(&config, "sys", &"x".repeat(40 * 1024)).0, "large");
+        assert!(config.routed("overview_editor").is_none());
+
+        let routed = config.routed("code_analysis").unwrap();
+        assert_eq!(evaluate_befitting_model(&routed, "sys", "user"), ("coder".to_string(), None));
+        assert_eq!(routed.temperature, Some(0.0));
+        assert_eq!(routed.max_tokens, config.max_tokens);
+    }
 }
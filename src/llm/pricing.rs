@@ -0,0 +1,182 @@
+//! Model pricing - Prices per million tokens used to turn token usage into cost
+//!
+//! Built-in list prices cover the hosted models of the supported providers, `[llm.pricing]` in litho.toml
+//! overrides them or adds models:
+//!
+//! ```toml
+//! [llm.pricing."gpt-4o"]
+//! input = 2.5
+//! output = 10.0
+//! cached_input = 1.25
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::config::{LLMConfig, LLMProvider, ModelPricing};
+use crate::llm::client::types::TokenUsage;
+
+/// Built-in list prices in USD per million tokens: model, input, output, cached input
+const BUILTIN_PRICES: &[(&str, f64, f64, Option<f64>)] = &[
+    // OpenAI
+    ("gpt-5", 1.25, 10.0, Some(0.125)),
+    ("gpt-5-mini", 0.25, 2.0, Some(0.025)),
+    ("gpt-5-nano", 0.05, 0.4, Some(0.005)),
+    ("gpt-4.1", 2.0, 8.0, Some(0.5)),
+    ("gpt-4.1-mini", 0.4, 1.6, Some(0.1)),
+    ("gpt-4.1-nano", 0.1, 0.4, Some(0.025)),
+    ("gpt-4o", 2.5, 10.0, Some(1.25)),
+    ("gpt-4o-mini", 0.15, 0.6, Some(0.075)),
+    ("o3", 2.0, 8.0, Some(0.5)),
+    ("o3-mini", 1.1, 4.4, Some(0.55)),
+    ("o4-mini", 1.1, 4.4, Some(0.275)),
+    // Anthropic
+    ("claude-opus-4", 15.0, 75.0, Some(1.5)),
+    ("claude-opus-4-1", 15.0, 75.0, Some(1.5)),
+    ("claude-opus-4-5", 5.0, 25.0, Some(0.5)),
+    ("claude-sonnet-4", 3.0, 15.0, Some(0.3)),
+    ("claude-sonnet-4-5", 3.0, 15.0, Some(0.3)),
+    ("claude-3-7-sonnet", 3.0, 15.0, Some(0.3)),
+    ("claude-haiku-4-5", 1.0, 5.0, Some(0.1)),
+    ("claude-3-5-haiku", 0.8, 4.0, Some(0.08)),
+    // Gemini
+    ("gemini-2.5-pro", 1.25, 10.0, Some(0.31)),
+    ("gemini-2.5-flash", 0.3, 2.5, Some(0.075)),
+    ("gemini-2.5-flash-lite", 0.1, 0.4, Some(0.025)),
+    ("gemini-2.0-flash", 0.1, 0.4, Some(0.025)),
+    // DeepSeek
+    ("deepseek-chat", 0.27, 1.1, Some(0.07)),
+    ("deepseek-reasoner", 0.55, 2.19, Some(0.14)),
+    // Mistral
+    ("mistral-large-latest", 2.0, 6.0, None),
+    ("mistral-medium-latest", 0.4, 2.0, None),
+    ("mistral-small-latest", 0.1, 0.3, None),
+    ("codestral-latest", 0.3, 0.9, None),
+    // Moonshot
+    ("kimi-k2", 0.6, 2.5, Some(0.15)),
+];
+
+/// Prices of the models, looked up by model name
+#[derive(Debug, Clone)]
+pub struct PricingTable {
+    prices: BTreeMap<String, ModelPricing>,
+}
+
+impl PricingTable {
+    pub fn new(config: &LLMConfig) -> Self {
+        let mut prices: BTreeMap<String, ModelPricing> = BUILTIN_PRICES
+            .iter()
+            .map(|(model, input, output, cached_input)| {
+                (
+                    model.to_string(),
+                    ModelPricing {
+                        input: *input,
+                        output: *output,
+                        cached_input: *cached_input,
+                    },
+                )
+            })
+            .collect();
+        for (model, pricing) in &config.pricing {
+            prices.insert(model.to_lowercase(), pricing.clone());
+        }
+
+        Self { prices }
+    }
+
+    /// Price of the model: exact name, then without the `vendor/` prefix of routers, then the longest
+    /// priced name the model starts with (dated snapshots such as `gpt-4o-2024-08-06`). Unpriced models of the
+    /// local and offline providers cost nothing, `provider` being the one that served the call
+    pub fn price(&self, provider: &str, model: &str) -> Option<ModelPricing> {
+        let model = model.to_lowercase();
+        let unprefixed = model.rsplit('/').next().unwrap_or(&model);
+
+        let found = self.prices.get(&model).or_else(|| self.prices.get(unprefixed)).or_else(|| {
+            self.prices
+                .iter()
+                .filter(|(name, _)| {
+                    unprefixed
+                        .strip_prefix(name.as_str())
+                        .is_some_and(|rest| rest.starts_with(['-', ':', '@']))
+                })
+                .max_by_key(|(name, _)| name.len())
+                .map(|(_, pricing)| pricing)
+        });
+
+        match found {
+            Some(pricing) => Some(pricing.clone()),
+            None if is_local(provider) => Some(ModelPricing {
+                input: 0.0,
+                output: 0.0,
+                cached_input: None,
+            }),
+            None => None,
+        }
+    }
+
+    /// Cost of the usage in USD, `None` when the model has no price
+    pub fn cost(&self, provider: &str, model: &str, usage: &TokenUsage) -> Option<f64> {
+        let pricing = self.price(provider, model)?;
+        let cached_input = usage.cached_input_tokens.min(usage.input_tokens);
+        let uncached_input = usage.input_tokens - cached_input;
+
+        Some(
+            (uncached_input as f64 * pricing.input
+                + cached_input as f64 * pricing.cached_input.unwrap_or(pricing.input)
+                + usage.output_tokens as f64 * pricing.output)
+                / 1_000_000.0,
+        )
+    }
+}
+
+/// Whether the provider runs on the user's machine or serves recorded replies
+fn is_local(provider: &str) -> bool {
+    provider
+        .parse::<LLMProvider>()
+        .is_ok_and(|provider| matches!(provider, LLMProvider::Ollama | LLMProvider::Replay))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_lookup_and_cost() {
+        let mut config = LLMConfig::default();
+        config.pricing.insert(
+            "Qwen/Qwen3-Next-80B-A3B-Instruct".to_string(),
+            ModelPricing {
+                input: 0.5,
+                output: 2.0,
+                cached_input: None,
+            },
+        );
+        let table = PricingTable::new(&config);
+
+        assert_eq!(table.price("openai", "gpt-4o-mini-2024-07-18").unwrap().input, 0.15);
+        assert_eq!(table.price("openrouter", "openai/gpt-4o").unwrap().output, 10.0);
+        assert_eq!(table.price("openai-compatible", "qwen/qwen3-next-80b-a3b-instruct").unwrap().input, 0.5);
+        assert!(table.price("openai", "gpt-4omega").is_none());
+        assert!(table.price("openai-compatible", "my-finetune").is_none());
+
+        let mut usage = TokenUsage::new(1_000_000, 100_000);
+        usage.cached_input_tokens = 400_000;
+        let cost = table.cost("openai", "gpt-4o", &usage).unwrap();
+        assert!((cost - (0.6 * 2.5 + 0.4 * 1.25 + 0.1 * 10.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unpriced_models_are_free_only_on_local_providers() {
+        // An Ollama primary with a paid fallback: whether a call is free depends on the provider that served it
+        let config = LLMConfig {
+            provider: LLMProvider::Ollama,
+            ..Default::default()
+        };
+        let table = PricingTable::new(&config);
+        let usage = TokenUsage::new(1000, 100);
+
+        assert_eq!(table.cost("ollama", "llama3.1:8b", &usage), Some(0.0));
+        assert_eq!(table.cost("replay", "recorded", &usage), Some(0.0));
+        assert_eq!(table.cost("openai-compatible", "qwen3-235b", &usage), None);
+        assert!(table.cost("anthropic", "claude-sonnet-4-5", &usage).unwrap() > 0.0);
+    }
+}
@@ -5,6 +5,7 @@ use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 #[cfg(debug_assertions)]
 use std::time::Duration;
 use walkdir::WalkDir;
@@ -12,11 +13,13 @@ use walkdir::WalkDir;
 use crate::config::Config;
 use crate::types::FileInfo;
 use crate::utils::file_utils::is_test_file;
+use crate::utils::ignore_rules::IgnoreRules;
 
 /// File exploration tool
 #[derive(Debug, Clone)]
 pub struct AgentToolFileExplorer {
     config: Config,
+    ignore_rules: Arc<IgnoreRules>,
 }
 
 /// File exploration parameters
@@ -40,8 +43,11 @@ pub struct FileExplorerResult {
 }
 
 impl AgentToolFileExplorer {
-    pub fn new(config: Config) -> Self {
-        Self { config }
+    pub fn new(config: Config, ignore_rules: Arc<IgnoreRules>) -> Self {
+        Self {
+            config,
+            ignore_rules,
+        }
     }
 
     async fn list_directory(&self, args: &FileExplorerArgs) -> Result<FileExplorerResult> {
@@ -257,31 +263,15 @@ impl AgentToolFileExplorer {
     }
 
     fn is_ignored(&self, path: &Path) -> bool {
-        let path_str = path.to_string_lossy().to_lowercase();
         let file_name = path
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("")
             .to_lowercase();
 
-        // Check excluded directories
-        for excluded_dir in &self.config.excluded_dirs {
-            if path_str.contains(&excluded_dir.to_lowercase()) {
-                return true;
-            }
-        }
-
-        // Check excluded files
-        for excluded_file in &self.config.excluded_files {
-            if excluded_file.contains('*') {
-                // Simple wildcard matching
-                let pattern = excluded_file.replace('*', "");
-                if file_name.contains(&pattern.to_lowercase()) {
-                    return true;
-                }
-            } else if file_name == excluded_file.to_lowercase() {
-                return true;
-            }
+        // Check excluded directories and files configured in Config, .gitignore and .lithoignore
+        if self.ignore_rules.is_ignored(path, path.is_dir()) {
+            return true;
         }
 
         // Check excluded extensions
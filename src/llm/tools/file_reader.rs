@@ -1,18 +1,23 @@
 //! File reading tool
 
+use std::sync::Arc;
 #[cfg(debug_assertions)]
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
 
-use crate::{config::Config, utils::file_utils::is_binary_file_path};
+use crate::{
+    config::Config,
+    utils::{file_utils::is_binary_file_path, ignore_rules::IgnoreRules},
+};
 
 /// File reading tool
 #[derive(Debug, Clone)]
 pub struct AgentToolFileReader {
     config: Config,
+    ignore_rules: Arc<IgnoreRules>,
 }
 
 /// File reading parameters
@@ -36,14 +41,22 @@ pub struct FileReaderResult {
 }
 
 impl AgentToolFileReader {
-    pub fn new(config: Config) -> Self {
-        Self { config }
+    pub fn new(config: Config, ignore_rules: Arc<IgnoreRules>) -> Self {
+        Self {
+            config,
+            ignore_rules,
+        }
     }
 
     async fn read_file_content(&self, args: &FileReaderArgs) -> Result<FileReaderResult> {
         let project_root = &self.config.project_path;
         let file_path = project_root.join(&args.file_path);
 
+        // Excluded and gitignored files stay out of the model's reach, as in the file explorer
+        if self.ignore_rules.is_ignored(&file_path, false) {
+            bail!("{} is excluded from the analysis", args.file_path);
+        }
+
         if !file_path.exists() {
             return Ok(FileReaderResult {
                 file_path: args.file_path.clone(),
@@ -109,8 +122,8 @@ impl AgentToolFileReader {
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error("file reader tool error")]
-pub struct FileReaderToolError;
+#[error("file reader tool error: {0}")]
+pub struct FileReaderToolError(String);
 
 impl Tool for AgentToolFileReader {
     const NAME: &'static str = "file_reader";
@@ -157,6 +170,6 @@ impl Tool for AgentToolFileReader {
 
         self.read_file_content(&args)
             .await
-            .map_err(|_e| FileReaderToolError)
+            .map_err(|e| FileReaderToolError(e.to_string()))
     }
 }
@@ -0,0 +1,138 @@
+//! Symbol lookup tool
+
+#[cfg(debug_assertions)]
+use std::time::Duration;
+
+use rig::tool::Tool;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::types::symbol_index::{SymbolIndex, SymbolReference};
+
+/// Default number of references returned per symbol
+const DEFAULT_MAX_REFERENCES: usize = 10;
+
+/// Symbol lookup tool, answers from the symbol index built during preprocessing
+#[derive(Debug, Clone)]
+pub struct AgentToolSymbolLookup {
+    config: Config,
+}
+
+/// Symbol lookup parameters
+#[derive(Debug, Deserialize)]
+pub struct SymbolLookupArgs {
+    pub name: String,
+    pub kind: Option<String>,
+    pub max_references: Option<usize>,
+}
+
+/// Definition of a looked up symbol
+#[derive(Debug, Serialize)]
+pub struct SymbolLookupMatch {
+    pub name: String,
+    pub kind: String,
+    pub file_path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub visibility: String,
+    pub reference_count: usize,
+    pub references: Vec<SymbolReference>,
+}
+
+/// Symbol lookup result
+#[derive(Debug, Serialize, Default)]
+pub struct SymbolLookupResult {
+    pub matches: Vec<SymbolLookupMatch>,
+    pub insights: Vec<String>,
+}
+
+impl AgentToolSymbolLookup {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    fn lookup(&self, args: &SymbolLookupArgs) -> SymbolLookupResult {
+        let index_path = self.config.internal_path.join(SymbolIndex::FILE_NAME);
+        let Ok(index) = SymbolIndex::load(&index_path) else {
+            return SymbolLookupResult {
+                insights: vec!["Symbol index is not available, use file_explorer and file_reader instead".to_string()],
+                ..Default::default()
+            };
+        };
+
+        let max_references = args.max_references.unwrap_or(DEFAULT_MAX_REFERENCES);
+        let matches: Vec<SymbolLookupMatch> = index
+            .lookup(&args.name)
+            .into_iter()
+            .filter(|symbol| {
+                args.kind
+                    .as_ref()
+                    .is_none_or(|kind| symbol.kind.eq_ignore_ascii_case(kind))
+            })
+            .map(|symbol| SymbolLookupMatch {
+                name: symbol.name.clone(),
+                kind: symbol.kind.clone(),
+                file_path: symbol.file.clone(),
+                start_line: symbol.start_line,
+                end_line: symbol.end_line,
+                visibility: symbol.visibility.clone(),
+                reference_count: symbol.reference_count,
+                references: symbol.references.iter().take(max_references).cloned().collect(),
+            })
+            .collect();
+
+        let insights = if matches.is_empty() {
+            vec![format!("No definition of `{}` found in the symbol index", args.name)]
+        } else {
+            vec!["Read a definition with file_reader using its file_path, start_line and end_line".to_string()]
+        };
+        SymbolLookupResult { matches, insights }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("symbol lookup tool error")]
+pub struct SymbolLookupToolError;
+
+impl Tool for AgentToolSymbolLookup {
+    const NAME: &'static str = "symbol_lookup";
+
+    type Error = SymbolLookupToolError;
+    type Args = SymbolLookupArgs;
+    type Output = SymbolLookupResult;
+
+    async fn definition(&self, _prompt: String) -> rig::completion::ToolDefinition {
+        rig::completion::ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Look up where a symbol (function, type, class, trait, interface...) is defined in the project and where it is referenced, without scanning files."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Symbol name to look up, e.g. `OrderService`"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "description": "Only return definitions of this kind, e.g. `struct`, `class`, `function`"
+                    },
+                    "max_references": {
+                        "type": "integer",
+                        "description": "Maximum number of references returned per definition (default 10)"
+                    }
+                },
+                "required": ["name"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        println!("   🔧 tool called...symbol_lookup@{:?}", args);
+
+        #[cfg(debug_assertions)]
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        Ok(self.lookup(&args))
+    }
+}
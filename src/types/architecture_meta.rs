@@ -0,0 +1,151 @@
+//! Architecture meta description - Ground truth declared by the project's architects
+//!
+//! Loaded from the file configured by `architecture_meta_path` (`--architecture-meta`), written in YAML
+//! (`.yaml`/`.yml`), TOML (`.toml`) or JSON (`.json`). Every section is optional, unknown keys are rejected:
+//!
+//! ```yaml
+//! system_name: Order Platform
+//! description: Takes orders from the web shop and settles payments
+//! containers:
+//!   - name: api
+//!     technology: Rust, axum
+//!     description: Public REST API
+//!     paths: [services/api]
+//! external_systems:
+//!   - name: Stripe
+//!     description: Payment processing
+//!     interaction: Outbound HTTPS, webhooks back to the api
+//! domains:
+//!   - name: Ordering
+//!     description: Cart, checkout and order lifecycle
+//!     paths: [services/api/src/orders]
+//! layers:
+//!   - name: Presentation
+//!     paths: [services/api/src/http]
+//!   - name: Domain
+//! glossary:
+//!   SKU: Stock keeping unit, the sellable variant of a product
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::simple_yaml;
+
+/// Deployable or runnable unit of the system (C4 container)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContainerDeclaration {
+    pub name: String,
+    pub technology: Option<String>,
+    pub description: Option<String>,
+    /// Project paths implementing the container
+    pub paths: Vec<String>,
+}
+
+/// System outside the project that it interacts with
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExternalSystemDeclaration {
+    pub name: String,
+    pub description: Option<String>,
+    /// How the project talks to the system
+    pub interaction: Option<String>,
+}
+
+/// Named boundary such as a domain or a layer, optionally mapped to project paths
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct BoundaryDeclaration {
+    pub name: String,
+    pub description: Option<String>,
+    pub paths: Vec<String>,
+}
+
+/// Architecture facts declared by the architects, injected into research prompts as authoritative data
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ArchitectureMeta {
+    pub system_name: Option<String>,
+    pub description: Option<String>,
+    pub containers: Vec<ContainerDeclaration>,
+    pub external_systems: Vec<ExternalSystemDeclaration>,
+    /// Domain boundaries
+    pub domains: Vec<BoundaryDeclaration>,
+    /// Layers from the outermost to the innermost
+    pub layers: Vec<BoundaryDeclaration>,
+    /// Term to definition
+    pub glossary: BTreeMap<String, String>,
+}
+
+impl ArchitectureMeta {
+    /// Load the description file, the format being chosen by its extension
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .context(format!("Failed to read architecture meta file: {:?}", path))?;
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let meta = match extension.as_str() {
+            "toml" => toml::from_str(&content).map_err(anyhow::Error::from),
+            "json" => serde_json::from_str(&content).map_err(anyhow::Error::from),
+            "yaml" | "yml" => simple_yaml::parse(&content)
+                .and_then(|value| Ok(serde_json::from_value(value)?)),
+            _ => bail!(
+                "Unsupported architecture meta file format {:?}, expected .yaml, .yml, .toml or .json",
+                path
+            ),
+        };
+        meta.context(format!("Failed to parse architecture meta file: {:?}", path))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.system_name.is_none()
+            && self.description.is_none()
+            && self.containers.is_empty()
+            && self.external_systems.is_empty()
+            && self.domains.is_empty()
+            && self.layers.is_empty()
+            && self.glossary.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_support::TempProject;
+
+    #[test]
+    fn test_load_yaml_and_toml() {
+        let dir = TempProject::new("architecture_meta");
+
+        let yaml_path = dir.join("architecture.yaml");
+        std::fs::write(
+            &yaml_path,
+            "system_name: Order Platform\ncontainers:\n  - name: api\n    paths: [services/api]\nlayers:\n  - name: Domain\nglossary:\n  SKU: Stock keeping unit\n",
+        )
+        .unwrap();
+        let meta = ArchitectureMeta::load(&yaml_path).unwrap();
+        assert_eq!(meta.system_name.as_deref(), Some("Order Platform"));
+        assert_eq!(meta.containers[0].paths, vec!["services/api"]);
+        assert_eq!(meta.layers[0].name, "Domain");
+        assert_eq!(meta.glossary["SKU"], "Stock keeping unit");
+
+        let toml_path = dir.join("architecture.toml");
+        std::fs::write(
+            &toml_path,
+            "system_name = \"Order Platform\"\n[[external_systems]]\nname = \"Stripe\"\n",
+        )
+        .unwrap();
+        let meta = ArchitectureMeta::load(&toml_path).unwrap();
+        assert_eq!(meta.external_systems[0].name, "Stripe");
+
+        std::fs::write(&yaml_path, "system_nmae: Typo\n").unwrap();
+        assert!(ArchitectureMeta::load(&yaml_path).is_err());
+    }
+}
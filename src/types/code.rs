@@ -89,6 +89,76 @@ impl Display for Dependency {
     }
 }
 
+/// HTTP route declared in source code through a web framework
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RouteDefinition {
+    /// Upper-case HTTP method, `ANY` when the route accepts every method
+    pub method: String,
+    /// URL path as declared, including the framework's parameter syntax
+    pub path: String,
+    /// Handler function, method or controller action
+    pub handler: Option<String>,
+    /// Web framework the route is declared with
+    pub framework: String,
+    /// Whether this is method-agnostic page routing (e.g. Django `path()`) rather than an API endpoint
+    pub is_page: bool,
+    pub line_number: usize,
+}
+
+impl RouteDefinition {
+    pub fn new(method: &str, path: &str, handler: Option<String>, framework: &str, line_number: usize) -> Self {
+        Self {
+            method: method.to_uppercase(),
+            path: path.to_string(),
+            handler,
+            framework: framework.to_string(),
+            is_page: false,
+            line_number,
+        }
+    }
+
+    /// Path parameters as (name, type), for `{id}`, `{id:int}`, `{id?}`, `<name>`, `<int:year>` and `:id` syntaxes
+    pub fn path_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        for segment in self.path.split('/') {
+            let mut rest = segment;
+            while let Some(start) = rest.find(['{', '<']) {
+                let close = if rest[start..].starts_with('{') { '}' } else { '>' };
+                let Some(end) = rest[start..].find(close) else {
+                    break;
+                };
+                let inner = &rest[start + 1..start + end];
+                let (name, value_type) = match inner.split_once(':') {
+                    // Flask/Django put the converter first: `<int:year>`
+                    Some((converter, name)) if close == '>' => (name, converter),
+                    Some((name, constraint)) => (name, constraint),
+                    None => (inner, "string"),
+                };
+                let name = name.trim_matches(['?', '*', '.', ' ']);
+                // Skip regex quantifiers such as `{4}` in Django `re_path` patterns
+                if !name.is_empty() && !name.chars().all(|c| c.is_ascii_digit() || c == ',') {
+                    params.push((name.to_string(), value_type.to_string()));
+                }
+                rest = &rest[start + end + 1..];
+            }
+            if let Some(name) = segment.strip_prefix(':') {
+                params.push((name.trim_end_matches('?').to_string(), "string".to_string()));
+            }
+        }
+        params
+    }
+
+    /// Join a controller/router prefix and a route path
+    pub fn join_path(prefix: &str, path: &str) -> String {
+        let parts: Vec<&str> = [prefix, path]
+            .iter()
+            .map(|p| p.trim_matches('/'))
+            .filter(|p| !p.is_empty())
+            .collect();
+        format!("/{}", parts.join("/"))
+    }
+}
+
 /// Component complexity metrics
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 pub struct CodeComplexity {
@@ -75,3 +75,80 @@ impl DependencyType {
         }
     }
 }
+
+/// Static architecture health check result based on the resolved import graph
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ArchitectureHealthReport {
+    /// Number of files included in the import graph
+    pub analyzed_files: usize,
+
+    /// Number of imports resolved to project files
+    pub resolved_imports: usize,
+
+    /// Circular dependencies between files
+    pub cycles: Vec<DependencyCycle>,
+
+    /// Imports that break configured layer rules
+    pub layer_violations: Vec<LayerViolation>,
+}
+
+/// Circular dependency (a strongly connected component of the import graph)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DependencyCycle {
+    /// Files participating in the cycle
+    pub members: Vec<String>,
+
+    /// One concrete import path through the cycle, first and last element are the same file
+    pub example_path: Vec<String>,
+}
+
+/// Import that violates a layer rule
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LayerViolation {
+    /// Layer of the importing file
+    pub from_layer: String,
+
+    /// Forbidden layer of the imported file
+    pub to_layer: String,
+
+    /// Importing file
+    pub from_file: String,
+
+    /// Imported file
+    pub to_file: String,
+
+    /// Line of the import statement
+    pub line_number: Option<usize>,
+}
+
+impl ArchitectureHealthReport {
+    pub fn has_violations(&self) -> bool {
+        !self.cycles.is_empty() || !self.layer_violations.is_empty()
+    }
+
+    /// Convert findings into key insights for the relationship analysis
+    pub fn to_insights(&self) -> Vec<String> {
+        let mut insights = Vec::new();
+
+        for cycle in &self.cycles {
+            insights.push(format!(
+                "Circular dependency detected between {} files: {}",
+                cycle.members.len(),
+                cycle.example_path.join(" -> ")
+            ));
+        }
+
+        for violation in &self.layer_violations {
+            let location = match violation.line_number {
+                Some(line) => format!("{}:{}", violation.from_file, line),
+                None => violation.from_file.clone(),
+            };
+            insights.push(format!(
+                "Layer violation: `{}` must not depend on `{}`, but {} imports {}",
+                violation.from_layer, violation.to_layer, location, violation.to_file
+            ));
+        }
+
+        insights
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// Location in project source where a configuration value is read
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReadSite {
+    /// Relative file path
+    pub file: String,
+    pub line_number: usize,
+}
+
+impl std::fmt::Display for ReadSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file, self.line_number)
+    }
+}
+
+/// Environment variable read by the project
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnvVariable {
+    pub name: String,
+    /// Type the value is converted to, `string` when used as-is
+    pub value_type: String,
+    /// Fallback used when the variable is unset, or the value given in an example env file
+    pub default_value: Option<String>,
+    /// Whether the project fails when the variable is unset (e.g. `os.environ["X"]`, `.expect()`)
+    pub required: bool,
+    pub read_sites: Vec<ReadSite>,
+    /// Env files (e.g. `.env.example`) declaring the variable
+    pub declared_in: Vec<String>,
+}
+
+/// Field of a typed configuration structure (serde config struct, pydantic settings, Spring properties)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigKey {
+    /// Dotted key as written in configuration files
+    pub key: String,
+    pub value_type: String,
+    pub default_value: Option<String>,
+    pub description: Option<String>,
+    /// Mechanism the key is bound through, e.g. `serde struct Config`
+    pub binding: String,
+    /// Environment variable overriding the key, when the binding derives one
+    pub env_name: Option<String>,
+    pub source_location: ReadSite,
+}
+
+/// Configuration file shipped with the project
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigFile {
+    /// Relative file path
+    pub path: String,
+    pub format: String,
+    /// Dotted keys defined in the file (nested keys up to a limited depth)
+    pub keys: Vec<String>,
+}
+
+/// Configuration surface of the project: environment variables, typed config keys and config files
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ConfigurationInventory {
+    pub env_variables: Vec<EnvVariable>,
+    pub config_keys: Vec<ConfigKey>,
+    pub config_files: Vec<ConfigFile>,
+}
+
+impl ConfigurationInventory {
+    pub fn is_empty(&self) -> bool {
+        self.env_variables.is_empty() && self.config_keys.is_empty() && self.config_files.is_empty()
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::types::code::CodeInsight;
+
+/// Package ecosystem of a third-party dependency
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Ecosystem {
+    #[serde(rename = "cargo")]
+    Cargo,
+    #[serde(rename = "npm")]
+    Npm,
+    #[serde(rename = "pypi")]
+    PyPI,
+    #[serde(rename = "maven")]
+    Maven,
+    #[serde(rename = "go")]
+    Go,
+    #[serde(rename = "nuget")]
+    NuGet,
+    #[serde(rename = "swiftpm")]
+    SwiftPM,
+}
+
+impl Ecosystem {
+    /// Ecosystem whose packages can be imported from a source file with the given extension
+    pub fn for_source_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "rs" => Some(Self::Cargo),
+            "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" | "vue" | "svelte" => Some(Self::Npm),
+            "py" => Some(Self::PyPI),
+            "java" | "kt" => Some(Self::Maven),
+            "go" => Some(Self::Go),
+            "cs" => Some(Self::NuGet),
+            "swift" => Some(Self::SwiftPM),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Ecosystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Ecosystem::Cargo => "Rust (Cargo)",
+            Ecosystem::Npm => "JavaScript/TypeScript (npm)",
+            Ecosystem::PyPI => "Python (PyPI)",
+            Ecosystem::Maven => "JVM (Maven/Gradle)",
+            Ecosystem::Go => "Go (modules)",
+            Ecosystem::NuGet => ".NET (NuGet)",
+            Ecosystem::SwiftPM => "Swift (SwiftPM)",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// Location in project source where a dependency is imported
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ImportSite {
+    /// Relative file path
+    pub file: String,
+    pub line_number: usize,
+}
+
+/// Third-party dependency declared in a package manifest
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExternalDependency {
+    /// Package name as declared in the manifest (`group:artifact` for Maven)
+    pub name: String,
+    /// Declared version requirement
+    pub version: Option<String>,
+    /// Exact version pinned by a lock file
+    pub resolved_version: Option<String>,
+    pub ecosystem: Ecosystem,
+    /// Whether the dependency is only used for development, tests or builds
+    pub is_dev: bool,
+    /// Manifests declaring this dependency
+    pub declared_in: Vec<String>,
+    /// Where the dependency is imported in project source
+    pub import_sites: Vec<ImportSite>,
+}
+
+impl ExternalDependency {
+    /// Best known version, preferring the locked one
+    pub fn effective_version(&self) -> Option<&str> {
+        self.resolved_version
+            .as_deref()
+            .or(self.version.as_deref())
+    }
+
+    /// Check whether an import specifier as written in source refers to this package
+    pub fn matches_import(&self, specifier: &str) -> bool {
+        match self.ecosystem {
+            Ecosystem::Cargo => {
+                let crate_name = self.name.replace('-', "_");
+                specifier.split("::").next() == Some(crate_name.as_str())
+            }
+            Ecosystem::Npm => {
+                specifier == self.name || specifier.starts_with(&format!("{}/", self.name))
+            }
+            Ecosystem::PyPI => {
+                let module = self.name.to_lowercase().replace(['-', '.'], "_");
+                specifier.split('.').next().map(|s| s.to_lowercase()) == Some(module)
+            }
+            Ecosystem::Maven => {
+                let group = self.name.split(':').next().unwrap_or(&self.name);
+                specifier.starts_with(&format!("{}.", group))
+            }
+            Ecosystem::Go | Ecosystem::NuGet => {
+                let separator = if self.ecosystem == Ecosystem::Go { "/" } else { "." };
+                specifier == self.name || specifier.starts_with(&format!("{}{}", self.name, separator))
+            }
+            Ecosystem::SwiftPM => specifier == self.name,
+        }
+    }
+}
+
+/// Normalized third-party dependency inventory of the project
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DependencyInventory {
+    /// Parsed manifest and lock files
+    pub manifests: Vec<String>,
+    pub dependencies: Vec<ExternalDependency>,
+}
+
+impl DependencyInventory {
+    /// Find the dependency imported at the given source location
+    pub fn find_by_import_site(&self, file: &str, line_number: usize) -> Option<&ExternalDependency> {
+        self.dependencies.iter().find(|dep| {
+            dep.import_sites
+                .iter()
+                .any(|site| site.file == file && site.line_number == line_number)
+        })
+    }
+
+    /// Fill `Dependency.version` of code insights from the inventory
+    pub fn annotate_versions(&self, code_insights: &mut [CodeInsight]) {
+        for insight in code_insights.iter_mut() {
+            let file = insight
+                .code_dossier
+                .file_path
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            for dependency in insight.dependencies.iter_mut() {
+                if dependency.version.is_some() {
+                    continue;
+                }
+
+                let matched = dependency
+                    .line_number
+                    .and_then(|line| self.find_by_import_site(&file, line))
+                    .or_else(|| {
+                        if !dependency.is_external {
+                            return None;
+                        }
+                        self.dependencies
+                            .iter()
+                            .find(|dep| dep.name == dependency.name || dep.matches_import(&dependency.name))
+                    });
+
+                if let Some(matched) = matched {
+                    dependency.version = matched.effective_version().map(|v| v.to_string());
+                }
+            }
+        }
+    }
+}
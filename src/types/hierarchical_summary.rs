@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Summary produced by the LLM for a group of files or directories
+#[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
+pub struct SummaryOutline {
+    /// What the group is for and how its parts work together, in a few sentences
+    pub summary: String,
+    /// Main responsibilities of the group
+    pub responsibilities: Vec<String>,
+    /// Most important components (files, types or sub-directories) of the group
+    pub key_components: Vec<String>,
+}
+
+/// Roll-up of the code insights of one directory
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DirectorySummary {
+    /// Directory relative to project root, `/` separated (`.` for the root)
+    pub path: String,
+    /// Analyzed files of the directory (not recursive)
+    pub files: Vec<String>,
+    /// Highest importance score among the files
+    pub max_importance: f64,
+    pub outline: SummaryOutline,
+}
+
+/// Roll-up of the directory summaries of one package (workspace member or top-level directory)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PackageSummary {
+    pub name: String,
+    /// Package directory relative to project root, `/` separated (`.` for the root)
+    pub path: String,
+    pub directories: Vec<String>,
+    pub file_count: usize,
+    pub outline: SummaryOutline,
+}
+
+/// Map-reduce summary of all code insights: files roll up into directories, directories into packages
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct HierarchicalSummary {
+    pub packages: Vec<PackageSummary>,
+    pub directories: Vec<DirectorySummary>,
+}
+
+impl HierarchicalSummary {
+    pub fn is_empty(&self) -> bool {
+        self.directories.is_empty()
+    }
+
+    /// Directory summaries covering at least one of the given files
+    pub fn directories_covering<'a>(&'a self, files: &[String]) -> Vec<&'a DirectorySummary> {
+        let files: HashSet<&str> = files.iter().map(String::as_str).collect();
+        self.directories
+            .iter()
+            .filter(|directory| directory.files.iter().any(|file| files.contains(file.as_str())))
+            .collect()
+    }
+}
+
+impl SummaryOutline {
+    /// Single markdown bullet block used when feeding summaries back into prompts
+    pub fn to_markdown(&self, title: &str) -> String {
+        let mut content = format!("- **{}**: {}\n", title, self.summary);
+        if !self.responsibilities.is_empty() {
+            content.push_str(&format!("  - Responsibilities: {}\n", self.responsibilities.join("; ")));
+        }
+        if !self.key_components.is_empty() {
+            content.push_str(&format!("  - Key components: {}\n", self.key_components.join(", ")));
+        }
+        content
+    }
+}
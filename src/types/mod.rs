@@ -1,7 +1,15 @@
+pub mod architecture_meta;
 pub mod code;
 pub mod code_releationship;
+pub mod configuration;
+pub mod dependency_inventory;
+pub mod hierarchical_summary;
 pub mod original_document;
 pub mod project_structure;
+pub mod symbol_index;
+pub mod test_mapping;
+pub mod vector_index;
+pub mod workspace;
 
 use std::path::PathBuf;
 
@@ -1,7 +1,72 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
+/// Kind of a documentation source found in the project
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DocumentKind {
+    Readme,
+    /// Documents of a `docs/`-like tree
+    Guide,
+    /// Architecture decision record
+    Adr,
+    Contributing,
+    Changelog,
+    /// Module-level doc comments (`//!`, package docs, module docstrings)
+    DocComment,
+}
+
+impl Display for DocumentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            DocumentKind::Readme => "README",
+            DocumentKind::Guide => "Guide",
+            DocumentKind::Adr => "Architecture Decision Record",
+            DocumentKind::Contributing => "Contributing Guide",
+            DocumentKind::Changelog => "Changelog",
+            DocumentKind::DocComment => "Module Documentation",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// One documentation source with its structure (headings, lists, code blocks) preserved
 #[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DocumentSource {
+    pub kind: DocumentKind,
+    /// Relative file path (`/` separated)
+    pub path: String,
+    /// First heading of the document
+    pub title: Option<String>,
+    /// Language of a localized README, e.g. `zh-CN` for `README.zh-CN.md`
+    pub language: Option<String>,
+    pub content: String,
+    /// Whether the content was cut to the size limit
+    pub truncated: bool,
+}
+
+/// Documentation written by the project authors, not necessarily accurate and for reference only
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct OriginalDocument {
-    /// README file content in the project, not necessarily accurate and for reference only
-    pub readme: Option<String>,
-}
\ No newline at end of file
+    /// Documentation sources ordered by kind, the main README first
+    #[serde(default)]
+    pub documents: Vec<DocumentSource>,
+}
+
+impl OriginalDocument {
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Main README of the project root, the non-localized one when present
+    pub fn readme(&self) -> Option<&DocumentSource> {
+        let root_readmes = || {
+            self.documents
+                .iter()
+                .filter(|d| d.kind == DocumentKind::Readme && !d.path.contains('/'))
+        };
+        root_readmes()
+            .find(|d| d.language.is_none())
+            .or_else(|| root_readmes().next())
+    }
+}
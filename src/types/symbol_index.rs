@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Location where a symbol is referenced
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SymbolReference {
+    /// Relative file path (`/` separated)
+    pub file: String,
+    pub line: usize,
+}
+
+/// Symbol definition found by the language processors
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolDefinition {
+    pub name: String,
+    /// Kind reported by the language processor, e.g. `function`, `struct`, `class`
+    pub kind: String,
+    /// Relative file path (`/` separated)
+    pub file: String,
+    /// First line of the definition (1-based)
+    pub start_line: usize,
+    /// Last line of the definition (1-based, inclusive)
+    pub end_line: usize,
+    pub visibility: String,
+    /// References from other places of the project, capped to keep the index small
+    pub references: Vec<SymbolReference>,
+    /// Total number of references, including the ones not listed
+    pub reference_count: usize,
+}
+
+/// Modification stamp of an indexed file, used to reuse its definitions while it is unchanged
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FileStamp {
+    pub size: u64,
+    /// Modification time in seconds since the Unix epoch
+    pub modified: u64,
+}
+
+/// Cross-reference database of the project's symbols, persisted in the internal working directory
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SymbolIndex {
+    /// Indexed files with their modification stamps
+    pub files: BTreeMap<String, FileStamp>,
+    pub symbols: Vec<SymbolDefinition>,
+}
+
+impl SymbolIndex {
+    /// File name of the persisted index inside the internal working directory
+    pub const FILE_NAME: &'static str = "symbol_index.json";
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .context(format!("Failed to read symbol index: {:?}", path))?;
+        serde_json::from_str(&content).context(format!("Failed to parse symbol index: {:?}", path))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)
+            .context(format!("Failed to write symbol index: {:?}", path))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Definitions with the given name, case-insensitively when no exact match exists
+    pub fn lookup(&self, name: &str) -> Vec<&SymbolDefinition> {
+        let exact: Vec<&SymbolDefinition> = self.symbols.iter().filter(|s| s.name == name).collect();
+        if !exact.is_empty() {
+            return exact;
+        }
+        self.symbols
+            .iter()
+            .filter(|s| s.name.eq_ignore_ascii_case(name))
+            .collect()
+    }
+
+    /// Definitions whose name is unique in the project, keyed by name
+    pub fn unique_symbols(&self) -> BTreeMap<&str, &SymbolDefinition> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for symbol in &self.symbols {
+            *counts.entry(symbol.name.as_str()).or_default() += 1;
+        }
+        self.symbols
+            .iter()
+            .filter(|s| counts[s.name.as_str()] == 1)
+            .map(|s| (s.name.as_str(), s))
+            .collect()
+    }
+}
+
+impl SymbolDefinition {
+    /// Source location in `path#Lstart-Lend` form, as understood by GitHub/GitLab file links
+    pub fn location(&self) -> String {
+        if self.end_line > self.start_line {
+            format!("{}#L{}-L{}", self.file, self.start_line, self.end_line)
+        } else {
+            format!("{}#L{}", self.file, self.start_line)
+        }
+    }
+}
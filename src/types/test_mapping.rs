@@ -0,0 +1,110 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Test file and the project files it exercises
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TestFile {
+    /// Relative file path (`/` separated)
+    pub path: String,
+    /// Number of test cases declared in the file
+    pub test_count: usize,
+    /// Project source files exercised by the tests, resolved through imports and naming conventions
+    pub targets: Vec<String>,
+}
+
+/// Mapping between the project's tests and the source files they exercise
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TestMapping {
+    /// Non-test source files of the project
+    pub source_files: Vec<String>,
+    pub test_files: Vec<TestFile>,
+    /// Source files containing their own tests (e.g. Rust `#[cfg(test)]` modules), with their test counts
+    pub inline_tests: BTreeMap<String, usize>,
+}
+
+/// Test presence of a group of source files, such as a domain module
+#[derive(Debug, Clone, Default)]
+pub struct TestCoverage {
+    pub source_files: usize,
+    pub tested_files: usize,
+    /// Test files exercising the group, inline-tested source files included
+    pub test_files: BTreeSet<String>,
+    pub untested_files: Vec<String>,
+}
+
+impl TestMapping {
+    pub fn is_empty(&self) -> bool {
+        self.test_files.is_empty() && self.inline_tests.is_empty()
+    }
+
+    pub fn total_test_count(&self) -> usize {
+        self.test_files.iter().map(|t| t.test_count).sum::<usize>() + self.inline_tests.values().sum::<usize>()
+    }
+
+    /// Test files exercising each source file
+    pub fn tests_by_source(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut tests: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for test_file in &self.test_files {
+            for target in &test_file.targets {
+                tests.entry(target.as_str()).or_default().push(test_file.path.as_str());
+            }
+        }
+        for source in self.inline_tests.keys() {
+            tests.entry(source.as_str()).or_default().push(source.as_str());
+        }
+        tests
+    }
+
+    /// Test presence of the given source files
+    pub fn coverage<'a>(&self, source_files: impl IntoIterator<Item = &'a str>) -> TestCoverage {
+        let tests_by_source = self.tests_by_source();
+        let mut coverage = TestCoverage::default();
+        for source in source_files {
+            coverage.source_files += 1;
+            match tests_by_source.get(source) {
+                Some(tests) => {
+                    coverage.tested_files += 1;
+                    coverage.test_files.extend(tests.iter().map(|t| t.to_string()));
+                }
+                None => coverage.untested_files.push(source.to_string()),
+            }
+        }
+        coverage
+    }
+
+    /// Test presence of the source files under the given code paths (files or directories)
+    pub fn coverage_of_paths(&self, code_paths: &[String]) -> TestCoverage {
+        let code_paths: Vec<String> = code_paths
+            .iter()
+            .map(|p| p.replace('\\', "/").trim_start_matches("./").trim_end_matches('/').to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        self.coverage(self.source_files.iter().map(String::as_str).filter(|file| {
+            code_paths
+                .iter()
+                .any(|p| file == p || file.strip_prefix(p.as_str()).is_some_and(|rest| rest.starts_with('/')))
+        }))
+    }
+}
+
+impl TestCoverage {
+    /// Share of source files exercised by at least one test (0.0 - 1.0)
+    pub fn ratio(&self) -> f64 {
+        if self.source_files == 0 {
+            0.0
+        } else {
+            self.tested_files as f64 / self.source_files as f64
+        }
+    }
+
+    /// Qualitative test presence level
+    pub fn level(&self) -> &'static str {
+        match self.ratio() {
+            r if r >= 0.6 => "Well tested",
+            r if r >= 0.2 => "Partially tested",
+            r if r > 0.0 => "Sparsely tested",
+            _ => "Untested",
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Origin of an indexed chunk
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkKind {
+    /// Lines of a source file
+    Code,
+    /// Description of a file produced by code analysis
+    Insight,
+}
+
+impl Display for ChunkKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ChunkKind::Code => "code",
+            ChunkKind::Insight => "insight",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// Unit of text embedded in the vector index
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CodeChunk {
+    pub kind: ChunkKind,
+    /// Relative file path (`/` separated)
+    pub file: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub text: String,
+    /// MD5 of the embedded text, used to reuse vectors of unchanged chunks
+    pub digest: String,
+}
+
+/// Vector index of code chunks and code insight descriptions, persisted in the internal working directory
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct VectorIndex {
+    /// Embedding model the vectors were produced with
+    pub model: String,
+    pub chunks: Vec<CodeChunk>,
+    /// One L2-normalized vector per chunk
+    pub vectors: Vec<Vec<f32>>,
+}
+
+impl VectorIndex {
+    /// File name of the persisted index inside the internal working directory
+    pub const FILE_NAME: &'static str = "vector_index.json";
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .context(format!("Failed to read vector index: {:?}", path))?;
+        serde_json::from_str(&content).context(format!("Failed to parse vector index: {:?}", path))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)
+            .context(format!("Failed to write vector index: {:?}", path))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Vectors by chunk digest, empty when the index was built with another embedding model
+    pub fn vectors_by_digest(&self, model: &str) -> HashMap<&str, &Vec<f32>> {
+        if self.model != model {
+            return HashMap::new();
+        }
+        self.chunks
+            .iter()
+            .zip(&self.vectors)
+            .map(|(chunk, vector)| (chunk.digest.as_str(), vector))
+            .collect()
+    }
+
+    /// The `top_k` chunks most similar to the query vector, most similar first
+    pub fn search(&self, query: &[f32], top_k: usize) -> Vec<(&CodeChunk, f32)> {
+        let query_norm = query.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if query_norm == 0.0 {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(&CodeChunk, f32)> = self
+            .chunks
+            .iter()
+            .zip(&self.vectors)
+            .map(|(chunk, vector)| {
+                let dot: f32 = vector.iter().zip(query).map(|(a, b)| a * b).sum();
+                let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+                let score = if norm == 0.0 { 0.0 } else { dot / (norm * query_norm) };
+                (chunk, score)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        scored
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Build tool that declares the workspace
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceTool {
+    Cargo,
+    Npm,
+    Pnpm,
+    Maven,
+    Gradle,
+    Go,
+}
+
+impl Display for WorkspaceTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            WorkspaceTool::Cargo => "Cargo workspace",
+            WorkspaceTool::Npm => "npm/yarn workspaces",
+            WorkspaceTool::Pnpm => "pnpm workspace",
+            WorkspaceTool::Maven => "Maven multi-module",
+            WorkspaceTool::Gradle => "Gradle multi-project",
+            WorkspaceTool::Go => "Go workspace",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// A member package/crate/module of a monorepo
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorkspaceMember {
+    /// Package name as declared in the member manifest
+    pub name: String,
+    /// Member directory relative to project root, `/` separated
+    pub path: String,
+    /// Member manifest relative to project root
+    pub manifest: String,
+    pub tool: WorkspaceTool,
+    /// Description from the member manifest
+    pub description: Option<String>,
+    /// Names of other workspace members this member depends on
+    pub depends_on: Vec<String>,
+    /// Number of analyzed files inside the member directory
+    pub file_count: usize,
+}
+
+/// Workspace/monorepo layout of the project
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct WorkspaceInfo {
+    /// Root manifests declaring the workspace
+    pub root_manifests: Vec<String>,
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl WorkspaceInfo {
+    /// Member containing the given relative file path (the most nested one wins)
+    pub fn member_of(&self, file: &str) -> Option<&WorkspaceMember> {
+        let file = file.replace('\\', "/");
+        self.members
+            .iter()
+            .filter(|member| member.path.is_empty() || file.starts_with(&format!("{}/", member.path)))
+            .max_by_key(|member| member.path.len())
+    }
+
+    /// Members depending on the given member
+    pub fn dependents_of(&self, name: &str) -> Vec<&WorkspaceMember> {
+        self.members
+            .iter()
+            .filter(|member| member.depends_on.iter().any(|dep| dep == name))
+            .collect()
+    }
+}
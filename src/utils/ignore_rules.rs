@@ -0,0 +1,283 @@
+//! Gitignore-style path filtering shared by the structure extractor and the LLM file tools
+
+use std::path::{Path, PathBuf};
+
+use glob::{MatchOptions, Pattern};
+
+use crate::config::Config;
+
+/// Dedicated ignore file for Litho, evaluated after `.gitignore` so it can re-include (`!`) paths
+pub const LITHO_IGNORE_FILE: &str = ".lithoignore";
+const GIT_IGNORE_FILE: &str = ".gitignore";
+
+/// A single compiled ignore pattern
+#[derive(Debug, Clone)]
+struct IgnoreRule {
+    /// Directory (relative to project root, `/` separated) of the ignore file declaring the rule
+    base: String,
+    pattern: Pattern,
+    negated: bool,
+    dir_only: bool,
+    /// Patterns containing a `/` are matched against the path relative to `base`, others against the name only
+    anchored: bool,
+    case_sensitive: bool,
+}
+
+impl IgnoreRule {
+    fn parse(base: &str, line: &str, case_sensitive: bool) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let line = line.strip_prefix('/').unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+
+        let pattern = Pattern::new(line).ok()?;
+        Some(Self {
+            base: base.to_string(),
+            pattern,
+            negated,
+            dir_only,
+            anchored,
+            case_sensitive,
+        })
+    }
+
+    fn matches(&self, relative_path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+
+        let sub_path = if self.base.is_empty() {
+            relative_path
+        } else {
+            match relative_path
+                .strip_prefix(self.base.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+            {
+                Some(rest) => rest,
+                None => return false,
+            }
+        };
+
+        let options = MatchOptions {
+            case_sensitive: self.case_sensitive,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        if self.anchored {
+            self.pattern.matches_with(sub_path, options)
+        } else {
+            let name = sub_path.rsplit('/').next().unwrap_or(sub_path);
+            self.pattern.matches_with(name, options)
+        }
+    }
+}
+
+/// Ignore rules of a project: configured exclusions, nested `.gitignore` files and `.lithoignore` files.
+///
+/// Rules follow gitignore semantics: the last matching rule wins, `!` re-includes, a leading or inner `/`
+/// anchors the pattern to the declaring directory, a trailing `/` matches directories only and `**`
+/// spans directories. As in git, a path inside an ignored directory cannot be re-included.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreRules {
+    root: PathBuf,
+    config_rules: Vec<IgnoreRule>,
+    git_rules: Vec<IgnoreRule>,
+    litho_rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Build the rules for the configured project, reading every ignore file that is not itself ignored
+    pub fn load(config: &Config) -> Self {
+        let mut rules = Self::from_config(config);
+        let root = rules.root.clone();
+        rules.collect_ignore_files(&root, "", 0, config.max_depth.into());
+        rules
+    }
+
+    /// Rules derived from `excluded_dirs` and `excluded_files` only.
+    /// Entries match whole path segments (case-insensitive), so `lib` no longer hides `library/`.
+    pub fn from_config(config: &Config) -> Self {
+        let mut rules = Self {
+            root: config.project_path.clone(),
+            ..Default::default()
+        };
+
+        for excluded_dir in &config.excluded_dirs {
+            let pattern = format!("{}/", excluded_dir.trim_end_matches('/'));
+            rules
+                .config_rules
+                .extend(IgnoreRule::parse("", &pattern, false));
+        }
+        for excluded_file in &config.excluded_files {
+            rules
+                .config_rules
+                .extend(IgnoreRule::parse("", excluded_file, false));
+        }
+
+        rules
+    }
+
+    /// Whether the path (absolute or relative to the project root) is ignored
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let relative_path = path
+            .strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let relative_path = relative_path.trim_start_matches("./").trim_matches('/');
+        if relative_path.is_empty() {
+            return false;
+        }
+
+        // A path inside an ignored directory is always ignored
+        let mut end = 0;
+        while let Some(offset) = relative_path[end..].find('/') {
+            end += offset;
+            if self.decide(&relative_path[..end], true) == Some(true) {
+                return true;
+            }
+            end += 1;
+        }
+
+        self.decide(relative_path, is_dir).unwrap_or(false)
+    }
+
+    /// Result of the last matching rule, `None` if no rule matches
+    fn decide(&self, relative_path: &str, is_dir: bool) -> Option<bool> {
+        self.config_rules
+            .iter()
+            .chain(&self.git_rules)
+            .chain(&self.litho_rules)
+            .rev()
+            .find(|rule| rule.matches(relative_path, is_dir))
+            .map(|rule| !rule.negated)
+    }
+
+    fn add_rules_from_file(&mut self, dir: &Path, base: &str) {
+        for (file_name, is_litho) in [(GIT_IGNORE_FILE, false), (LITHO_IGNORE_FILE, true)] {
+            let Ok(content) = std::fs::read_to_string(dir.join(file_name)) else {
+                continue;
+            };
+            let parsed = content
+                .lines()
+                .filter_map(|line| IgnoreRule::parse(base, line, true));
+            if is_litho {
+                self.litho_rules.extend(parsed);
+            } else {
+                self.git_rules.extend(parsed);
+            }
+        }
+    }
+
+    /// Walk the tree top-down so that rules of parent directories are known before pruning children
+    fn collect_ignore_files(&mut self, dir: &Path, base: &str, depth: usize, max_depth: usize) {
+        self.add_rules_from_file(dir, base);
+        if depth >= max_depth {
+            return;
+        }
+
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut sub_dirs: Vec<(PathBuf, String)> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name == ".git" {
+                    return None;
+                }
+                let relative = if base.is_empty() {
+                    name
+                } else {
+                    format!("{}/{}", base, name)
+                };
+                Some((entry.path(), relative))
+            })
+            .collect();
+        sub_dirs.sort();
+
+        for (path, relative) in sub_dirs {
+            if !self.is_ignored(Path::new(&relative), true) {
+                self.collect_ignore_files(&path, &relative, depth + 1, max_depth);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules_from(patterns: &[(&str, &str)]) -> IgnoreRules {
+        let mut rules = IgnoreRules::default();
+        for (base, pattern) in patterns {
+            rules.git_rules.extend(IgnoreRule::parse(base, pattern, true));
+        }
+        rules
+    }
+
+    #[test]
+    fn test_config_dirs_match_whole_segments() {
+        let config = Config {
+            project_path: PathBuf::from("/project"),
+            excluded_dirs: vec!["lib".to_string(), "docs/generated".to_string()],
+            excluded_files: vec!["*.log".to_string()],
+            ..Default::default()
+        };
+        let rules = IgnoreRules::from_config(&config);
+
+        assert!(rules.is_ignored(Path::new("/project/lib"), true));
+        assert!(rules.is_ignored(Path::new("/project/src/lib/mod.rs"), false));
+        assert!(!rules.is_ignored(Path::new("/project/library/mod.rs"), false));
+        assert!(!rules.is_ignored(Path::new("/project/src/lib.rs"), false));
+        assert!(rules.is_ignored(Path::new("/project/docs/generated/a.md"), false));
+        assert!(!rules.is_ignored(Path::new("/project/src/docs/generated/a.md"), false));
+        assert!(rules.is_ignored(Path::new("/project/Server.LOG"), false));
+    }
+
+    #[test]
+    fn test_gitignore_semantics() {
+        let rules = rules_from(&[
+            ("", "*.gen.ts"),
+            ("", "!keep.gen.ts"),
+            ("", "/out"),
+            ("", "cache/"),
+            ("", "docs/**/draft-*.md"),
+            ("web", "/static/vendor"),
+        ]);
+
+        assert!(rules.is_ignored(Path::new("src/api.gen.ts"), false));
+        assert!(!rules.is_ignored(Path::new("src/keep.gen.ts"), false));
+        assert!(rules.is_ignored(Path::new("out/main.js"), false));
+        assert!(!rules.is_ignored(Path::new("src/out/main.js"), false));
+        assert!(rules.is_ignored(Path::new("src/cache/data.json"), false));
+        assert!(!rules.is_ignored(Path::new("src/cache"), false));
+        assert!(rules.is_ignored(Path::new("docs/draft-1.md"), false));
+        assert!(rules.is_ignored(Path::new("docs/a/b/draft-2.md"), false));
+        assert!(!rules.is_ignored(Path::new("docs/a/final.md"), false));
+        assert!(rules.is_ignored(Path::new("web/static/vendor/jquery.js"), false));
+        assert!(!rules.is_ignored(Path::new("static/vendor/jquery.js"), false));
+    }
+
+    #[test]
+    fn test_ignored_directory_cannot_be_reincluded() {
+        let rules = rules_from(&[("", "build/"), ("", "!build/keep.rs")]);
+        assert!(rules.is_ignored(Path::new("build/keep.rs"), false));
+    }
+}
@@ -1,6 +1,11 @@
 pub mod file_utils;
+pub mod ignore_rules;
 pub mod project_structure_formatter;
+pub mod source_scan;
+#[cfg(test)]
+pub mod test_support;
 pub mod sources;
 pub mod threads;
 pub mod token_estimator;
 pub mod prompt_compressor;
+pub mod simple_yaml;
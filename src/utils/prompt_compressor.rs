@@ -1,7 +1,7 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
-use crate::generator::agent_executor::{AgentExecuteParams, prompt};
+use crate::generator::agent_executor::{AgentExecuteParams, COMPRESSION_SCOPE_PREFIX, prompt};
 use crate::generator::context::GeneratorContext;
 use crate::utils::token_estimator::{TokenEstimation, TokenEstimator};
 
@@ -100,6 +100,34 @@ impl PromptCompressor {
             return Ok(self.create_no_compression_result(content));
         }
 
+        self.compress(context, content, content_type).await
+    }
+
+    /// Shrink content that overflowed the context window of the model, compressing it in halves so that each
+    /// compression request stays smaller than the request that overflowed
+    pub async fn shrink(
+        &self,
+        context: &GeneratorContext,
+        content: &str,
+        content_type: &str,
+    ) -> Result<String> {
+        let mut compressed_parts = Vec::new();
+        for part in split_in_halves(content) {
+            let result = self.compress(context, part, content_type).await?;
+            compressed_parts.push(result.compressed_content);
+        }
+        Ok(compressed_parts.join("\n"))
+    }
+
+    /// Compress content regardless of the threshold, reusing cached compressions
+    async fn compress(
+        &self,
+        context: &GeneratorContext,
+        content: &str,
+        content_type: &str,
+    ) -> Result<CompressionResult> {
+        let estimation = self.token_estimator.estimate_tokens(content);
+
         // Check cache
         let cache_manager = context.cache_manager.read().await;
         if let Ok(Some(cached_result)) = cache_manager
@@ -169,8 +197,9 @@ impl PromptCompressor {
                 "You are a professional content simplification expert, skilled at extracting and preserving key information while significantly reducing content length."
                     .to_string(),
             prompt_user: compression_prompt,
-            cache_scope: format!("prompt_compression_{}", content_type),
+            cache_scope: format!("{}{}", COMPRESSION_SCOPE_PREFIX, content_type),
             log_tag: format!("Context-Compression-{}", content_type),
+            model_route: "compression".to_string(),
         };
 
         let compressed_content = prompt(context, params).await?;
@@ -261,3 +290,13 @@ Please output the simplified content directly without adding any explanations or
         }
     }
 }
+
+/// Split content in two parts of similar size at a line boundary
+fn split_in_halves(content: &str) -> Vec<&str> {
+    let middle = content.len() / 2;
+    let newline = content.as_bytes()[middle..].iter().position(|byte| *byte == b'\n');
+    match newline.map(|offset| middle + offset) {
+        Some(split) if split + 1 < content.len() => vec![&content[..split], &content[split + 1..]],
+        _ => vec![content],
+    }
+}
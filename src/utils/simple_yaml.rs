@@ -0,0 +1,400 @@
+//! Simple YAML - Parser for the block-style YAML subset used by hand-written description files
+//!
+//! Supported: nested mappings and sequences, `- key: value` items, quoted and plain scalars,
+//! flow sequences/mappings of scalars (`[a, b]`, `{k: v}`), `|` and `>` block scalars and comments.
+//! Anchors, tags, multi-document streams and multi-line flow collections are not supported.
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Value};
+
+/// Parse a YAML document into a JSON value
+pub fn parse(content: &str) -> Result<Value> {
+    let mut parser = Parser {
+        lines: content
+            .lines()
+            .enumerate()
+            .map(|(i, raw)| Line::new(i + 1, raw))
+            .collect::<Result<Vec<_>>>()?,
+        pos: 0,
+    };
+    let value = match parser.peek() {
+        Some(line) => {
+            let indent = line.indent;
+            parser.parse_node(indent)?
+        }
+        None => Value::Null,
+    };
+    if let Some(line) = parser.peek() {
+        bail!("line {}: unexpected content `{}`", line.number, line.text);
+    }
+    Ok(value)
+}
+
+struct Line {
+    number: usize,
+    raw: String,
+    indent: usize,
+    /// Content without indentation and trailing comment
+    text: String,
+}
+
+impl Line {
+    fn new(number: usize, raw: &str) -> Result<Self> {
+        let content = raw.trim_start_matches(' ');
+        if content.starts_with('\t') {
+            bail!("line {}: tabs are not allowed for indentation", number);
+        }
+        Ok(Self {
+            number,
+            raw: raw.to_string(),
+            indent: raw.len() - content.len(),
+            text: strip_comment(content).trim_end().to_string(),
+        })
+    }
+
+    fn is_ignorable(&self) -> bool {
+        self.text.is_empty() || self.text == "---" || self.text == "..."
+    }
+
+    fn is_sequence_item(&self) -> bool {
+        self.text == "-" || self.text.starts_with("- ")
+    }
+}
+
+struct Parser {
+    lines: Vec<Line>,
+    pos: usize,
+}
+
+impl Parser {
+    /// Next line with content, skipping blank and comment lines
+    fn peek(&mut self) -> Option<&Line> {
+        while self.pos < self.lines.len() && self.lines[self.pos].is_ignorable() {
+            self.pos += 1;
+        }
+        self.lines.get(self.pos)
+    }
+
+    fn parse_node(&mut self, indent: usize) -> Result<Value> {
+        let Some(line) = self.peek() else {
+            return Ok(Value::Null);
+        };
+        if line.is_sequence_item() {
+            self.parse_sequence(indent)
+        } else if split_key(&line.text).is_some() {
+            self.parse_mapping(indent)
+        } else {
+            let value = parse_scalar(&line.text, line.number)?;
+            self.pos += 1;
+            Ok(value)
+        }
+    }
+
+    fn parse_mapping(&mut self, indent: usize) -> Result<Value> {
+        let mut map = Map::new();
+        while let Some(line) = self.peek() {
+            if line.indent < indent || line.is_sequence_item() && line.indent == indent {
+                break;
+            }
+            if line.indent > indent {
+                bail!("line {}: unexpected indentation", line.number);
+            }
+            let number = line.number;
+            let Some((key, rest)) = split_key(&line.text) else {
+                bail!("line {}: expected `key: value`, found `{}`", number, line.text);
+            };
+            let key = match parse_scalar(key, number)? {
+                Value::String(key) => key,
+                other => other.to_string(),
+            };
+            let rest = rest.to_string();
+            self.pos += 1;
+            let value = self.parse_value(&rest, indent, number, true)?;
+            map.insert(key, value);
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn parse_sequence(&mut self, indent: usize) -> Result<Value> {
+        let mut items = Vec::new();
+        while let Some(line) = self.peek() {
+            if line.indent != indent || !line.is_sequence_item() {
+                if line.indent > indent {
+                    bail!("line {}: unexpected indentation", line.number);
+                }
+                break;
+            }
+            let number = line.number;
+            let item = line.text[1..].trim_start().to_string();
+            let item_indent = indent + line.text.len() - item.len();
+
+            if !item.is_empty() && (item.starts_with("- ") || split_key(&item).is_some()) {
+                // Inline node such as `- name: x`, continued by lines indented like its first key
+                let current = &mut self.lines[self.pos];
+                current.indent = item_indent;
+                current.text = item;
+                items.push(self.parse_node(item_indent)?);
+            } else {
+                self.pos += 1;
+                items.push(self.parse_value(&item, indent, number, false)?);
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    /// Value following a mapping key or sequence dash: inline scalar, block scalar or nested block
+    fn parse_value(&mut self, rest: &str, indent: usize, number: usize, in_mapping: bool) -> Result<Value> {
+        if rest.starts_with('|') || rest.starts_with('>') {
+            return Ok(Value::String(self.parse_block_scalar(rest, indent)));
+        }
+        if !rest.is_empty() {
+            return parse_scalar(rest, number);
+        }
+        match self.peek() {
+            Some(next) if next.indent > indent => {
+                let next_indent = next.indent;
+                self.parse_node(next_indent)
+            }
+            // A sequence may sit at the same indentation as its mapping key
+            Some(next) if in_mapping && next.indent == indent && next.is_sequence_item() => {
+                self.parse_sequence(indent)
+            }
+            _ => Ok(Value::Null),
+        }
+    }
+
+    fn parse_block_scalar(&mut self, header: &str, indent: usize) -> String {
+        let folded = header.starts_with('>');
+        let start = self.pos;
+        while let Some(line) = self.lines.get(self.pos) {
+            if !line.raw.trim().is_empty() && line.indent <= indent {
+                break;
+            }
+            self.pos += 1;
+        }
+        let block_lines = &self.lines[start..self.pos];
+        let block_indent = block_lines
+            .iter()
+            .find(|line| !line.raw.trim().is_empty())
+            .map_or(0, |line| line.indent);
+        let mut block: Vec<&str> = block_lines
+            .iter()
+            .map(|line| {
+                if line.raw.trim().is_empty() {
+                    ""
+                } else {
+                    &line.raw[block_indent.min(line.indent)..]
+                }
+            })
+            .collect();
+        while block.last().is_some_and(|l| l.is_empty()) {
+            block.pop();
+        }
+
+        let mut text = if folded {
+            let mut folded_text = String::new();
+            for line in &block {
+                if line.is_empty() {
+                    folded_text.push('\n');
+                } else {
+                    if !folded_text.is_empty() && !folded_text.ends_with('\n') {
+                        folded_text.push(' ');
+                    }
+                    folded_text.push_str(line);
+                }
+            }
+            folded_text
+        } else {
+            block.join("\n")
+        };
+        if !header.contains('-') && !text.is_empty() {
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Remove a `#` comment that starts the content or follows whitespace, outside quotes
+fn strip_comment(content: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in content.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return &content[..i],
+            None => {}
+        }
+        previous = c;
+    }
+    content
+}
+
+/// Split `key: rest` at the first `:` followed by a space or the end, outside quotes and brackets
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    let mut depth = 0usize;
+    let bytes = text.as_bytes();
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' if i == 0 => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth = depth.saturating_sub(1),
+                ':' if depth == 0 && (i + 1 == text.len() || bytes[i + 1] == b' ') => {
+                    return Some((text[..i].trim(), text[i + 1..].trim()));
+                }
+                _ => {}
+            },
+        }
+    }
+    None
+}
+
+fn parse_scalar(text: &str, number: usize) -> Result<Value> {
+    let text = text.trim();
+    if let Some(inner) = text.strip_prefix('[') {
+        let inner = inner
+            .strip_suffix(']')
+            .ok_or_else(|| anyhow!("line {}: unterminated flow sequence", number))?;
+        return split_flow(inner)
+            .into_iter()
+            .map(|item| parse_scalar(item, number))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Array);
+    }
+    if let Some(inner) = text.strip_prefix('{') {
+        let inner = inner
+            .strip_suffix('}')
+            .ok_or_else(|| anyhow!("line {}: unterminated flow mapping", number))?;
+        let mut map = Map::new();
+        for entry in split_flow(inner) {
+            let (key, value) = split_key(entry)
+                .ok_or_else(|| anyhow!("line {}: expected `key: value` in flow mapping", number))?;
+            let key = match parse_scalar(key, number)? {
+                Value::String(key) => key,
+                other => other.to_string(),
+            };
+            map.insert(key, parse_scalar(value, number)?);
+        }
+        return Ok(Value::Object(map));
+    }
+    if let Some(inner) = text.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| anyhow!("line {}: unterminated double-quoted string", number))?;
+        return Ok(Value::String(unescape_double_quoted(inner)));
+    }
+    if let Some(inner) = text.strip_prefix('\'') {
+        let inner = inner
+            .strip_suffix('\'')
+            .ok_or_else(|| anyhow!("line {}: unterminated single-quoted string", number))?;
+        return Ok(Value::String(inner.replace("''", "'")));
+    }
+
+    Ok(match text {
+        "" | "~" | "null" | "Null" | "NULL" => Value::Null,
+        "true" | "True" | "TRUE" => Value::Bool(true),
+        "false" | "False" | "FALSE" => Value::Bool(false),
+        _ => {
+            if let Ok(integer) = text.parse::<i64>() {
+                Value::from(integer)
+            } else if let Some(float) = text.parse::<f64>().ok().filter(|f| f.is_finite()) {
+                Value::from(float)
+            } else {
+                Value::String(text.to_string())
+            }
+        }
+    })
+}
+
+/// Split the inside of a flow collection at top-level commas
+fn split_flow(inner: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    items.push(inner[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    items.push(inner[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+fn unescape_double_quoted(inner: &str) -> String {
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yaml_subset() {
+        let yaml = r#"
+# Architecture facts
+system_name: "Order Platform"
+description: >
+  Handles orders
+  and payments.
+containers:
+  - name: api   # REST gateway
+    technology: Rust
+    paths: [src/api, 'src/http']
+  - name: worker
+layers:
+- presentation
+- domain
+glossary:
+  SKU: Stock keeping unit
+  notes: |
+    line one
+    line two
+limits: {retries: 3, strict: true}
+"#;
+        let value = parse(yaml).unwrap();
+        assert_eq!(value["system_name"], "Order Platform");
+        assert_eq!(value["description"], "Handles orders and payments.\n");
+        assert_eq!(value["containers"][0]["name"], "api");
+        assert_eq!(value["containers"][0]["paths"][1], "src/http");
+        assert_eq!(value["containers"][1]["name"], "worker");
+        assert_eq!(value["layers"], serde_json::json!(["presentation", "domain"]));
+        assert_eq!(value["glossary"]["notes"], "line one\nline two\n");
+        assert_eq!(value["limits"]["retries"], 3);
+        assert_eq!(value["limits"]["strict"], true);
+
+        assert!(parse("a: 1\n   b: 2\n").is_err());
+        assert!(parse("name: \"unterminated\n").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Lightweight source scanning helpers shared by the regex-based static extractors
+
+/// Comment, quote and bracket rules of the scanned language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    /// `'a` lifetimes are not quotes
+    Rust,
+    /// JavaScript/TypeScript, Go and the other C-family languages
+    CFamily,
+    /// `#` comments, `//` being floor division, and no generics in angle brackets
+    Python,
+}
+
+impl Syntax {
+    /// Whether a line comment starts at `i`
+    fn comment_at(self, bytes: &[u8], i: usize) -> bool {
+        match self {
+            Self::Python => bytes[i] == b'#',
+            Self::Rust | Self::CFamily => bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'/'),
+        }
+    }
+
+    /// Whether the character at `i` opens a string or character literal
+    fn opens_quote(self, bytes: &[u8], i: usize) -> bool {
+        match bytes[i] {
+            b'"' | b'`' => true,
+            b'\'' => !(self == Self::Rust && is_rust_lifetime(bytes, i)),
+            _ => false,
+        }
+    }
+
+    /// Whether the `<` at `i` opens generic arguments, as in `Vec<T>` or `::<T>`, rather than being a comparison
+    /// like `x < 2` or a shift
+    fn opens_generics(self, bytes: &[u8], i: usize) -> bool {
+        self != Self::Python
+            && i > 0
+            && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_' || bytes[i - 1] == b':')
+            && bytes.get(i + 1).is_some_and(|c| !c.is_ascii_whitespace() && *c != b'=' && *c != b'<')
+    }
+}
+
+/// 1-based line number of a byte offset
+pub fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+/// Text between the bracket at `open` and its matching closing bracket, skipping string literals and comments
+pub fn balanced(content: &str, open: usize, syntax: Syntax) -> Option<&str> {
+    let bytes = content.as_bytes();
+    let (open_char, close_char) = match bytes.get(open)? {
+        b'(' => (b'(', b')'),
+        b'{' => (b'{', b'}'),
+        b'[' => (b'[', b']'),
+        _ => return None,
+    };
+
+    let mut depth = 0;
+    let mut quote: Option<u8> = None;
+    let mut i = open;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if quote.is_none() && syntax.comment_at(bytes, i) {
+            // Line comments may contain unbalanced quotes, e.g. `/// Don't`
+            i = content[i..].find('\n').map(|offset| i + offset).unwrap_or(bytes.len());
+            continue;
+        }
+        if let Some(q) = quote {
+            if c == b'\\' {
+                i += 1;
+            } else if c == q {
+                quote = None;
+            }
+        } else if syntax.opens_quote(bytes, i) {
+            quote = Some(c);
+        } else if c == open_char {
+            depth += 1;
+        } else if c == close_char {
+            depth -= 1;
+            if depth == 0 {
+                return content.get(open + 1..i);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// A quote followed by an identifier without a closing quote, as in `&'a str`, `T: 'static` or `dyn Trait + 'a`,
+/// is a lifetime rather than a character literal such as `'a'`
+fn is_rust_lifetime(bytes: &[u8], i: usize) -> bool {
+    if !bytes.get(i + 1).is_some_and(|c| c.is_ascii_alphabetic() || *c == b'_') {
+        return false;
+    }
+    let end = bytes[i + 1..]
+        .iter()
+        .position(|c| !(c.is_ascii_alphanumeric() || *c == b'_'))
+        .map_or(bytes.len(), |offset| i + 1 + offset);
+    bytes.get(end) != Some(&b'\'')
+}
+
+/// Split call arguments or object members at top-level commas
+pub fn split_top_level(text: &str, syntax: Syntax) -> Vec<String> {
+    let bytes = text.as_bytes();
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut generics = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut in_comment = false;
+    let mut previous = '\0';
+
+    for (i, c) in text.char_indices() {
+        let before = std::mem::replace(&mut previous, c);
+        if quote.is_none() && !in_comment && syntax.comment_at(bytes, i) {
+            in_comment = true;
+        }
+        if in_comment {
+            current.push(c);
+            in_comment = c != '\n';
+            continue;
+        }
+        if let Some(q) = quote {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' if syntax.opens_quote(bytes, i) => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            '<' if syntax.opens_generics(bytes, i) => generics += 1,
+            // `=>` and `->` are arrows, not closing generics
+            '>' if generics > 0 && before != '=' && before != '-' => generics -= 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 && generics == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// Content of a string literal (`"x"`, `'x'`, `` `x` ``, Python `r"x"`/`f"x"`), `None` for other expressions
+pub fn unquote(text: &str) -> Option<String> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix(['r', 'f', 'b', 'u'])
+        .filter(|rest| rest.starts_with(['"', '\'']))
+        .unwrap_or(text);
+    let text = text
+        .strip_prefix("\"\"\"")
+        .and_then(|t| t.strip_suffix("\"\"\""))
+        .map(str::to_string)
+        .or_else(|| {
+            let quote = text.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+            let inner = text.strip_prefix(quote)?.strip_suffix(quote)?;
+            Some(inner.to_string())
+        })?;
+    Some(text.replace("\\\"", "\"").replace("\\'", "'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_rust_skips_lifetimes() {
+        let content = r#"route(Foo<'a, 'b>, |x: &'static str| x, T: 'static, Box<dyn Trait + 'a>, ')', b'(', '\'') rest"#;
+        let open = content.find('(').unwrap();
+        let inner = balanced(content, open, Syntax::Rust).unwrap();
+        assert!(inner.starts_with("Foo<'a, 'b>"));
+        assert!(inner.ends_with(r"b'(', '\''"), "{}", inner);
+
+        let python = "add_argument('a (b', help='c)') rest";
+        assert_eq!(balanced(python, 12, Syntax::Python), Some("'a (b', help='c)'"));
+    }
+
+    #[test]
+    fn test_line_comments_per_language() {
+        let python = "add_argument('--jobs', default=10 // 2)  # (unbalanced\nrest)";
+        assert_eq!(balanced(python, 12, Syntax::Python), Some("'--jobs', default=10 // 2"));
+        assert_eq!(
+            split_top_level("'--jobs', default=10 // 2, # a, b\nhelp='n'", Syntax::Python),
+            ["'--jobs'", "default=10 // 2", "# a, b\nhelp='n'"]
+        );
+
+        let go = "StringVar(&name, \"name\", \"\", // don't (\n\"Name\") rest";
+        assert_eq!(balanced(go, 9, Syntax::CFamily), Some("&name, \"name\", \"\", // don't (\n\"Name\""));
+    }
+
+    #[test]
+    fn test_split_top_level_generics_and_lifetimes() {
+        assert_eq!(
+            split_top_level("name: Cow<'static, str>, tags: HashMap<String, Vec<u8>>, f: fn() -> u8", Syntax::Rust),
+            ["name: Cow<'static, str>", "tags: HashMap<String, Vec<u8>>", "f: fn() -> u8"]
+        );
+        assert_eq!(
+            split_top_level("'--jobs', default=x < 2, help='a, b'", Syntax::Python),
+            ["'--jobs'", "default=x < 2", "help='a, b'"]
+        );
+        assert_eq!(
+            split_top_level("limit > 0 && n < 2, items as Array<string>, 'x'", Syntax::CFamily),
+            ["limit > 0 && n < 2", "items as Array<string>", "'x'"]
+        );
+    }
+}
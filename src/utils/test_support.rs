@@ -0,0 +1,59 @@
+//! Fixtures shared by the tests
+
+use std::path::{Path, PathBuf};
+
+use crate::types::FileInfo;
+
+/// Temporary project directory of a test, removed when dropped so that a failing test cleans up too
+pub struct TempProject {
+    root: PathBuf,
+}
+
+impl TempProject {
+    /// Empty directory named after the test, unique per test process
+    pub fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("litho_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        Self { root }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// Write a file at a path relative to the root, creating its directories
+    pub fn write(&self, path: &str, content: &str) -> FileInfo {
+        let full_path = self.root.join(path);
+        std::fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        std::fs::write(&full_path, content).unwrap();
+        FileInfo {
+            size: content.len() as u64,
+            ..file_info(path)
+        }
+    }
+}
+
+impl Drop for TempProject {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Project structure entry of a file, by its path relative to the project root
+pub fn file_info(path: &str) -> FileInfo {
+    FileInfo {
+        path: PathBuf::from(path),
+        name: path.rsplit('/').next().unwrap_or(path).to_string(),
+        size: 0,
+        extension: path.rsplit_once('.').map(|(_, e)| e.to_string()),
+        is_core: false,
+        importance_score: 0.0,
+        complexity_score: 0.0,
+        last_modified: None,
+    }
+}